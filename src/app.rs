@@ -1,23 +1,34 @@
 use eframe::egui::{self};
-use ewebsock::{WsEvent, WsMessage, WsReceiver, WsSender};
 use std::rc::Rc;
+use std::time::Duration;
 use std::{cell::RefCell, collections::BTreeSet};
 
+use crate::connection::{Connection, ConnectionOptions, ConnectionState};
 /// We derive Deserialize/Serialize so we can persist app state on shutdown.
 use crate::panels::{
-    AboutPanel, FileHandler, LogicalChannels, MessageBox, PanelController, SocketManager,
+    AboutPanel, Dashboard, FileHandler, HexViewer, LogTable, LogicalChannels, MessageBox,
+    PanelController, ProceduresPanel, ProfileManager, ProtocolPanel, SequenceChart, SessionInfo,
+    SocketManager, Timeline, UePanel,
 };
-use crate::{Data, WebSocketLog};
+use crate::profiles::ConnectionProfile;
+use crate::protocol::LogProfile;
+use crate::sources::{FileSource, LogSource, WebSocketSource};
+use crate::Data;
 
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)] // if we add new fields, give them default values when deserializing old state
 pub struct ExampleApp {
     pub url: String,
     #[serde(skip)]
+    pub password: String,
+    #[serde(skip)]
     pub error: String,
     #[serde(skip)]
     frontend: Option<FrontEnd>,
     file_upload: Option<FileHandler>,
+    log_profile: LogProfile,
+    connection: ConnectionOptions,
+    profiles: ProfileManager,
 }
 
 impl ExampleApp {
@@ -39,14 +50,25 @@ impl Default for ExampleApp {
     fn default() -> Self {
         Self {
             url: "ws://127.0.0.1:9001".to_owned(),
+            password: Default::default(),
             error: Default::default(),
             frontend: None,
             file_upload: None,
+            log_profile: LogProfile::default(),
+            connection: ConnectionOptions::default(),
+            profiles: ProfileManager::default(),
         }
     }
 }
 
 impl eframe::App for ExampleApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        if let Some(frontend) = &self.frontend {
+            self.log_profile = frontend.data.borrow().profile.clone();
+        }
+        eframe::set_value(storage, eframe::APP_KEY, self);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.horizontal_wrapped(|ui| {
@@ -71,8 +93,9 @@ impl eframe::App for ExampleApp {
                         }
                     }
                 });
+                ui.menu_button("Profiles", |ui| self.profiles.menu_ui(ui));
                 if let Some(current_frontend) = &mut self.frontend {
-                    if current_frontend.connected {
+                    if current_frontend.shows_windows() {
                         ui.menu_button("Windows", |ui| {
                             for one_window in current_frontend.windows.iter_mut() {
                                 let mut is_open: bool = current_frontend
@@ -94,25 +117,58 @@ impl eframe::App for ExampleApp {
         });
 
         egui::TopBottomPanel::top("server").show(ctx, |ui| {
+            if let Some(frontend) = &self.frontend {
+                let now = ui.input(|i| i.time);
+                for connection in frontend.data.borrow_mut().connections.iter_mut() {
+                    ui.horizontal(|ui| connection_ui(ui, connection, now));
+                }
+            }
             ui.horizontal(|ui| {
                 ui.label("URL:");
-                if self.frontend.is_some() {
-                    ui.label(&self.url);
-                    if ui.button("Close").clicked() {
-                        // TODO close connection
-                        self.frontend = None;
-                    }
+                let url_entered = ui.text_edit_singleline(&mut self.url).lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                ui.label("Password:");
+                let password_entered = ui
+                    .add(egui::TextEdit::singleline(&mut self.password).password(true))
+                    .lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                ui.label("Max frame:");
+                let mut kib = self.connection.max_frame_size / 1024;
+                let frame_size = egui::DragValue::new(&mut kib)
+                    .clamp_range(1..=1024 * 1024)
+                    .suffix(" KiB");
+                if ui.add(frame_size).changed() {
+                    self.connection.max_frame_size = kib * 1024;
+                }
+                let connect = if self.frontend.is_some() {
+                    "Add connection"
                 } else {
-                    if (ui.text_edit_singleline(&mut self.url).lost_focus()
-                        && ui.input(|i| i.key_pressed(egui::Key::Enter)))
-                        || ui.button("Connect").clicked()
-                    {
-                        self.connect(ctx.clone());
+                    "Connect"
+                };
+                if url_entered || password_entered || ui.button(connect).clicked() {
+                    self.connect(ctx.clone());
+                }
+                if ui.button("Save as profile").clicked() {
+                    self.save_profile();
+                }
+                if let Some(frontend) = &mut self.frontend {
+                    if ui.button("Close all").clicked() {
+                        frontend.close(ui.input(|i| i.time));
+                        self.log_profile = frontend.data.borrow().profile.clone();
                     }
                 }
             });
         });
 
+        if self.profiles.is_open {
+            let mut is_open = true;
+            self.profiles.show(ctx, &mut is_open);
+            self.profiles.is_open = is_open;
+        }
+        if let Some(profile) = self.profiles.take_connect() {
+            self.connect_profile(profile, ctx.clone());
+        }
+
         if !self.error.is_empty() {
             egui::TopBottomPanel::top("error").show(ctx, |ui| {
                 ui.horizontal(|ui| {
@@ -124,31 +180,83 @@ impl eframe::App for ExampleApp {
 
         if let Some(frontend) = &mut self.frontend {
             frontend.ui(ctx);
+            if frontend.is_closed() {
+                self.frontend = None;
+            }
         } else {
             egui::CentralPanel::default().show(ctx, |ui| ui.horizontal(|ui| ui.vertical(|_ui| {})));
         }
         if let Some(fu) = &mut self.file_upload {
             fu.show(ctx, &mut true);
-            if let Ok(result) = fu.get_result() {
-                log::info!("File upload result: {:?}", result);
-                // create fake websocket handler
-                // self.frontend = Some(FrontEnd::new(ws_sender, ws_receiver));
-                self.file_upload = None;
+            if let Some((content, file_name)) = fu.get_result() {
+                match FileSource::open(&content, file_name.clone()) {
+                    Ok(source) => {
+                        if let Some(frontend) = &mut self.frontend {
+                            frontend.close(ctx.input(|i| i.time));
+                        }
+                        let mut frontend = FrontEnd::new(self.log_profile.clone());
+                        frontend.add_connection(Box::new(source), None);
+                        self.frontend = Some(frontend);
+                        self.error.clear();
+                        self.file_upload = None;
+                    }
+                    Err(error) => {
+                        log::error!("Failed to load {:?}: {}", file_name, error);
+                        fu.set_error(error);
+                    }
+                }
             }
         }
     }
 }
 
 impl ExampleApp {
-    fn connect(&mut self, ctx: egui::Context) {
-        let wakeup = move || ctx.request_repaint(); // wake up UI thread on new message
-        let options = ewebsock::Options {
-            max_incoming_frame_size: 500,
+    /// Connect with the settings of a profile, those of the top bar are left
+    /// alone. Its layers and follow mode only apply to its connection
+    fn connect_profile(&mut self, profile: ConnectionProfile, ctx: egui::Context) {
+        let url = profile.url();
+        match WebSocketSource::connect(&url, ctx, profile.options) {
+            Ok(source) => {
+                let password = Some(profile.password).filter(|p| !p.is_empty());
+                let mut connection = Connection::new(Box::new(source), password);
+                connection.layers = Some(profile.layers);
+                connection.follow = profile.follow;
+                self.add_connection(connection);
+            }
+            Err(error) => {
+                log::error!("Failed to connect to {:?}: {}", url, error);
+                self.error = error;
+            }
+        }
+    }
+
+    /// Add a profile with the current settings and open the profiles window
+    fn save_profile(&mut self) {
+        let (layers, follow) = match &self.frontend {
+            Some(frontend) => {
+                let data = frontend.data.borrow();
+                (data.profile.layers.clone(), data.follow)
+            }
+            None => (self.log_profile.layers.clone(), false),
         };
-        match ewebsock::connect_with_wakeup(&self.url, options, wakeup) {
-            Ok((ws_sender, ws_receiver)) => {
-                self.frontend = Some(FrontEnd::new(ws_sender, ws_receiver));
-                self.error.clear();
+        self.profiles.add(ConnectionProfile {
+            name: self.url.clone(),
+            url: self.url.clone(),
+            password: self.password.clone(),
+            secure: self.url.starts_with("wss://"),
+            layers,
+            options: self.connection.clone(),
+            follow,
+        });
+        self.profiles.is_open = true;
+    }
+
+    /// Connect with the settings of the top bar
+    fn connect(&mut self, ctx: egui::Context) {
+        match WebSocketSource::connect(&self.url, ctx, self.connection.clone()) {
+            Ok(source) => {
+                let password = Some(self.password.clone()).filter(|p| !p.is_empty());
+                self.add_connection(Connection::new(Box::new(source), password));
             }
             Err(error) => {
                 log::error!("Failed to connect to {:?}: {}", &self.url, error);
@@ -156,15 +264,54 @@ impl ExampleApp {
             }
         }
     }
+
+    fn add_connection(&mut self, connection: Connection) {
+        let profile = self.log_profile.clone();
+        let frontend = self.frontend.get_or_insert_with(|| FrontEnd::new(profile));
+        frontend.data.borrow_mut().connections.push(connection);
+        self.error.clear();
+    }
 }
 
 struct FrontEnd {
-    ws_receiver: Rc<RefCell<WsReceiver>>,
     pub windows: Vec<Box<dyn PanelController>>,
     pub data: Rc<RefCell<Data>>,
-    pub connected: bool,
-    pub error: bool,
-    pub error_str: String,
+}
+
+/// Source of a connection and its state for the top bar
+fn connection_ui(ui: &mut egui::Ui, connection: &mut Connection, now: f64) {
+    ui.label(format!("{}:", connection.source.kind()));
+    ui.label(connection.source.name());
+    let color = match connection.state {
+        ConnectionState::Open => egui::Color32::GREEN,
+        ConnectionState::Failed(_) => ui.visuals().error_fg_color,
+        ConnectionState::Connecting | ConnectionState::Closing => ui.visuals().warn_fg_color,
+        ConnectionState::Closed => ui.visuals().weak_text_color(),
+    };
+    let status = ui.colored_label(color, format!("● {}", connection.state.name()));
+    if let ConnectionState::Failed(reason) = &connection.state {
+        status.on_hover_text(reason);
+        if let Some(remaining) = connection.backoff.remaining(now) {
+            ui.label(format!(
+                "reconnecting in {:.0} s (attempt {})",
+                remaining.ceil(),
+                connection.backoff.attempts
+            ));
+            if ui.button("Reconnect now").clicked() {
+                connection.backoff.hurry(now);
+            }
+        }
+    }
+    let closed = matches!(
+        connection.state,
+        ConnectionState::Closing | ConnectionState::Closed
+    );
+    if ui
+        .add_enabled(!closed, egui::Button::new("Close"))
+        .clicked()
+    {
+        connection.close(now);
+    }
 }
 
 fn set_open(open: &mut BTreeSet<String>, key: &'static str, is_open: bool) {
@@ -178,23 +325,34 @@ fn set_open(open: &mut BTreeSet<String>, key: &'static str, is_open: bool) {
 }
 
 impl FrontEnd {
-    fn new(ws_sender: WsSender, ws_receiver: WsReceiver) -> Self {
-        let ref_ws_receiver = Rc::new(RefCell::new(ws_receiver));
-
-        let data = Data {
-            ws_sender: ws_sender,
-            events: Vec::new(),
-            current_index: 0,
-            open_windows: BTreeSet::new(),
-        };
+    fn new(profile: LogProfile) -> Self {
+        let data = Data::new(profile);
         let ref_data = Rc::new(RefCell::new(data));
+        let lt = LogTable::new(Rc::clone(&ref_data));
         let mb = MessageBox::new(Rc::clone(&ref_data));
+        let hv = HexViewer::new(Rc::clone(&ref_data));
         let sm = SocketManager::new(Rc::clone(&ref_data));
         let lc = LogicalChannels::new(Rc::clone(&ref_data));
+        let tl = Timeline::new(Rc::clone(&ref_data));
+        let sc = SequenceChart::new(Rc::clone(&ref_data));
+        let ue = UePanel::new(Rc::clone(&ref_data));
+        let pr = ProceduresPanel::new(Rc::clone(&ref_data));
+        let db = Dashboard::new(Rc::clone(&ref_data));
+        let si = SessionInfo::new(Rc::clone(&ref_data));
+        let pp = ProtocolPanel::new(Rc::clone(&ref_data));
         let wins: Vec<Box<dyn PanelController>> = vec![
             Box::<AboutPanel>::default(),
+            Box::<LogTable>::new(lt),
             Box::<MessageBox>::new(mb),
+            Box::<HexViewer>::new(hv),
             Box::<LogicalChannels>::new(lc),
+            Box::<Timeline>::new(tl),
+            Box::<SequenceChart>::new(sc),
+            Box::<UePanel>::new(ue),
+            Box::<ProceduresPanel>::new(pr),
+            Box::<Dashboard>::new(db),
+            Box::<SessionInfo>::new(si),
+            Box::<ProtocolPanel>::new(pp),
             Box::<SocketManager>::new(sm),
         ];
         for one_box in wins.iter() {
@@ -205,54 +363,67 @@ impl FrontEnd {
         }
         Self {
             data: ref_data,
-            ws_receiver: ref_ws_receiver,
             windows: wins,
-            connected: false,
-            error: false,
-            error_str: "".to_string(),
         }
     }
 
+    fn add_connection(&mut self, source: Box<dyn LogSource>, password: Option<String>) {
+        let connection = Connection::new(source, password);
+        self.data.borrow_mut().connections.push(connection);
+    }
+
+    /// Close every source, the frontend is dropped once they are `Closed`
+    fn close(&mut self, now: f64) {
+        for connection in self.data.borrow_mut().connections.iter_mut() {
+            if !matches!(
+                connection.state,
+                ConnectionState::Closing | ConnectionState::Closed
+            ) {
+                connection.close(now);
+            }
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        let data = self.data.borrow();
+        data.connections
+            .iter()
+            .all(|connection| connection.state == ConnectionState::Closed)
+    }
+
+    /// Whether the panels are shown, the logs stay on screen while reconnecting
+    fn shows_windows(&self) -> bool {
+        let data = self.data.borrow();
+        !data.events.is_empty()
+            || data
+                .connections
+                .iter()
+                .any(|connection| connection.state == ConnectionState::Open)
+    }
+
     fn ui(&mut self, ctx: &egui::Context) {
-        while let Some(event) = self.ws_receiver.borrow_mut().try_recv() {
-            match event {
-                WsEvent::Message(msg) => match msg {
-                    WsMessage::Text(event_text) => {
-                        let decoded: Result<WebSocketLog, serde_json::Error> =
-                            serde_json::from_str(&event_text);
-                        if let Ok(decoded) = decoded {
-                            self.data.borrow_mut().events.extend(decoded.logs);
-                        }
-                    }
-                    WsMessage::Unknown(str_error) => {
-                        self.error = true;
-                        log::error!("Unknown message: {:?}", str_error);
-                        self.error_str = str_error;
-                    }
-                    WsMessage::Binary(bin) => {
-                        self.error = true;
-                        self.error_str = format!("{:?}", bin);
-                    }
-                    _ => {
-                        self.error = true;
-                        self.error_str = "Received Ping-Pong".to_string();
-                    }
-                },
-                WsEvent::Opened => {
-                    self.connected = true;
-                }
-                WsEvent::Closed => {
-                    self.connected = false;
+        let now = ctx.input(|i| i.time);
+        {
+            let mut data = self.data.borrow_mut();
+            for source_id in 0..data.connections.len() {
+                let logs = data.connections[source_id].poll(now);
+                if !logs.is_empty() {
+                    data.received_at = now;
                 }
-                WsEvent::Error(str_err) => {
-                    self.connected = false;
-                    self.error = true;
-                    log::error!("Unknown message: {:?}", str_err);
-                    self.error_str = str_err;
+                data.add_logs(source_id, logs);
+            }
+            let Data {
+                connections,
+                profile,
+                ..
+            } = &mut *data;
+            for connection in connections.iter_mut() {
+                if let Some(delay) = connection.update(now, profile) {
+                    ctx.request_repaint_after(Duration::from_secs_f64(delay));
                 }
             }
         }
-        if self.connected {
+        if self.shows_windows() {
             for one_window in self.windows.iter_mut() {
                 let mut is_open: bool = self.data.borrow().open_windows.contains(one_window.name());
                 one_window.show(ctx, &mut is_open);
@@ -265,8 +436,118 @@ impl FrontEnd {
             egui::CentralPanel::default().show(ctx, |_ui| {});
         } else {
             egui::CentralPanel::default().show(ctx, |ui| {
-                ui.label("Not connected");
+                for connection in self.data.borrow().connections.iter() {
+                    let name = connection.source.name();
+                    match &connection.state {
+                        ConnectionState::Failed(reason) => {
+                            ui.label(format!("{}: not connected: {}", name, reason))
+                        }
+                        state => ui.label(format!("{}: {}...", name, state.name())),
+                    };
+                }
             });
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::MemorySource;
+    use crate::text_log::parse_text_logs;
+
+    const LOG: &str = "\
+# Started on 2024-03-01 10:27:22
+10:27:22.358 [RRC] UL 0001 01 CCCH: rrcConnectionRequest
+    0000:  41 23 45 67 89 a6                                 A#Eg..
+10:27:22.360 [RRC] DL 0001 01 CCCH: rrcConnectionSetup
+10:27:22.371 [RRC] UL 0001 01 DCCH: rrcConnectionSetupComplete
+10:27:22.372 [S1AP] TO 127.0.1.100:36412 UE context release request
+    0000:  00 12 00 0f 00 00 02 00  00 00 02 00 01 00 08 40  ...............@
+    0010:  02 00 02                                          ...
+10:27:23.001 [RRC] UL 0002 01 CCCH: rrcConnectionRequest
+    0000:  ff ff ff ff ff ff                                 ......
+10:27:23.005 [NAS] UL 0002 Attach request
+10:27:23.010 [PHY] DL 0002 01 4601 120.3 PDSCH: harq=0 prb=0:5
+";
+
+    /// Run one frame of the frontend without any window
+    fn frame(ctx: &egui::Context, frontend: &mut FrontEnd) {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| frontend.ui(ctx));
+    }
+
+    #[test]
+    fn procedures_shared_by_the_panels() {
+        let logs: Vec<_> = parse_text_logs(LOG.as_bytes(), "enb0")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let mut frontend = FrontEnd::new(LogProfile::default());
+        frontend.add_connection(Box::new(MemorySource::new("memory".to_owned(), logs)), None);
+        let ctx = egui::Context::default();
+        frame(&ctx, &mut frontend);
+        let _ = frontend.data.borrow_mut().request_logs();
+        frame(&ctx, &mut frontend);
+        frame(&ctx, &mut frontend);
+
+        let data = frontend.data.borrow();
+        // the RRC logs with a hex dump are decoded once, when added
+        assert!(data.events[0].info.is_some());
+        assert!(data.events[1].info.is_none());
+        let setup = &data.procedures.list[0];
+        assert_eq!(setup.kind, crate::procedures::Kind::RrcSetup);
+        assert_eq!(setup.state, crate::procedures::State::Success);
+        assert_eq!((setup.first_index, setup.last_index), (0, 2));
+        // all the logs are read: the setup of UE 2 never ends
+        let pending = &data.procedures.list[1];
+        assert_eq!(pending.kind, crate::procedures::Kind::RrcSetup);
+        assert_eq!(pending.state, crate::procedures::State::Timeout);
+    }
+
+    #[test]
+    fn merged_connections() {
+        let mut frontend = FrontEnd::new(LogProfile::default());
+        for (name, text) in [
+            (
+                "enb0",
+                "10:00:00.000 [RRC] UL 0001 01 CCCH: a\n10:00:02.000 [RRC] UL 0001 01 CCCH: c",
+            ),
+            (
+                "enb1",
+                "10:00:01.000 [RRC] UL 0001 01 CCCH: b\n10:00:03.000 [RRC] UL 0001 01 CCCH: d",
+            ),
+        ] {
+            let logs = parse_text_logs(text.as_bytes(), name)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            frontend.add_connection(Box::new(MemorySource::new(name.to_owned(), logs)), None);
+        }
+        let ctx = egui::Context::default();
+        frame(&ctx, &mut frontend);
+        let _ = frontend.data.borrow_mut().request_logs();
+        frame(&ctx, &mut frontend);
+        frame(&ctx, &mut frontend);
+
+        let data = frontend.data.borrow();
+        let order: Vec<_> = data
+            .events
+            .iter()
+            .map(|log| (log.source, log.data[0].as_str()))
+            .collect();
+        assert_eq!(order, [(0, "a"), (1, "b"), (0, "c"), (1, "d")]);
+        assert!(data.order_version > 0);
+
+        // UE 0001 of each connection is a different UE
+        let mut ues = crate::ue::UeList::default();
+        data.events.iter().for_each(|log| ues.push(log));
+        let keys: Vec<_> = ues.ues.iter().map(|ue| ue.key()).collect();
+        assert_eq!(keys, [(0, 1), (1, 1)]);
+        drop(data);
+        frontend.data.borrow_mut().set_ue_id(Some((1, 1)));
+        let data = frontend.data.borrow();
+        let visible: Vec<_> = data
+            .visible_events()
+            .map(|log| log.data[0].as_str())
+            .collect();
+        assert_eq!(visible, ["b", "d"]);
+    }
+}
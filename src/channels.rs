@@ -0,0 +1,303 @@
+//! Mapping of the logical channels onto the transport and physical channels,
+//! for LTE (3GPP TS 36.300 6.1.3) and NR (3GPP TS 38.300 5.3 and 6.2)
+
+use crate::decoders::rrc::{self, Channel, Technology};
+use crate::{hexdump, OneLog};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Downlink,
+    Uplink,
+}
+
+impl Direction {
+    pub fn from_log(log: &OneLog) -> Option<Direction> {
+        match log.dir.as_deref() {
+            Some("DL") => Some(Direction::Downlink),
+            Some("UL") => Some(Direction::Uplink),
+            _ => None,
+        }
+    }
+}
+
+/// Layers from the top, logical, down to the physical one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    Logical,
+    Transport,
+    Physical,
+}
+
+pub const LAYERS: [Layer; 3] = [Layer::Logical, Layer::Transport, Layer::Physical];
+
+/// Logical channel carried by a transport channel on a physical channel,
+/// a name is empty when the path does not go through that layer
+#[derive(Debug)]
+pub struct Path {
+    pub direction: Direction,
+    pub logical: &'static str,
+    pub transport: &'static str,
+    pub physical: &'static str,
+}
+
+impl Path {
+    pub fn channel(&self, layer: Layer) -> &'static str {
+        match layer {
+            Layer::Logical => self.logical,
+            Layer::Transport => self.transport,
+            Layer::Physical => self.physical,
+        }
+    }
+}
+
+const fn dl(logical: &'static str, transport: &'static str, physical: &'static str) -> Path {
+    Path {
+        direction: Direction::Downlink,
+        logical,
+        transport,
+        physical,
+    }
+}
+
+const fn ul(logical: &'static str, transport: &'static str, physical: &'static str) -> Path {
+    Path {
+        direction: Direction::Uplink,
+        logical,
+        transport,
+        physical,
+    }
+}
+
+#[derive(Debug)]
+pub struct ChannelMap {
+    pub paths: &'static [Path],
+    /// Variants of some channels, shown on hover
+    pub notes: &'static [(&'static str, &'static str)],
+}
+
+static LTE: ChannelMap = ChannelMap {
+    paths: &[
+        dl("PCCH", "PCH", "PDSCH"),
+        dl("BCCH", "BCH", "PBCH"),
+        dl("BCCH", "DL-SCH", "PDSCH"),
+        dl("CCCH", "DL-SCH", "PDSCH"),
+        dl("DCCH", "DL-SCH", "PDSCH"),
+        dl("DTCH", "DL-SCH", "PDSCH"),
+        dl("MCCH", "MCH", "PMCH"),
+        dl("MTCH", "MCH", "PMCH"),
+        dl("", "", "PDCCH"),
+        dl("", "", "PCFICH"),
+        dl("", "", "PHICH"),
+        ul("CCCH", "UL-SCH", "PUSCH"),
+        ul("DCCH", "UL-SCH", "PUSCH"),
+        ul("DTCH", "UL-SCH", "PUSCH"),
+        ul("", "RACH", "PRACH"),
+        ul("", "", "PUCCH"),
+    ],
+    notes: &[
+        ("BCCH", "MIB on BCH, SIBs on DL-SCH"),
+        ("PRACH", "preamble formats 0 to 4"),
+        ("PUCCH", "formats 1, 1a, 1b, 2, 2a, 2b, 3, 4 and 5"),
+    ],
+};
+
+static NR: ChannelMap = ChannelMap {
+    paths: &[
+        dl("PCCH", "PCH", "PDSCH"),
+        dl("BCCH", "BCH", "PBCH"),
+        dl("BCCH", "DL-SCH", "PDSCH"),
+        dl("CCCH", "DL-SCH", "PDSCH"),
+        dl("DCCH", "DL-SCH", "PDSCH"),
+        dl("DTCH", "DL-SCH", "PDSCH"),
+        dl("", "", "PDCCH"),
+        ul("CCCH", "UL-SCH", "PUSCH"),
+        ul("DCCH", "UL-SCH", "PUSCH"),
+        ul("DTCH", "UL-SCH", "PUSCH"),
+        ul("", "RACH", "PRACH"),
+        ul("", "", "PUCCH"),
+    ],
+    notes: &[
+        ("BCCH", "MIB on BCH, SIB1 and other SIBs on DL-SCH"),
+        ("PRACH", "long preambles 0 to 3, short preambles A1 to C2"),
+        ("PUCCH", "formats 0 to 4"),
+    ],
+};
+
+pub fn channel_map(technology: Technology) -> &'static ChannelMap {
+    match technology {
+        Technology::Lte => &LTE,
+        Technology::Nr => &NR,
+    }
+}
+
+impl ChannelMap {
+    /// Channels of a layer in one direction, in the order of the paths
+    pub fn channels(&self, direction: Direction, layer: Layer) -> Vec<&'static str> {
+        let mut channels = Vec::new();
+        for path in self.paths.iter().filter(|path| path.direction == direction) {
+            let channel = path.channel(layer);
+            if !channel.is_empty() && !channels.contains(&channel) {
+                channels.push(channel);
+            }
+        }
+        channels
+    }
+
+    pub fn note(&self, channel: &str) -> Option<&'static str> {
+        self.notes
+            .iter()
+            .find(|(name, _)| *name == channel)
+            .map(|(_, note)| *note)
+    }
+
+    /// Paths used by the message of a log: the paths going through its
+    /// channel, from the layer of that channel down to the physical one
+    pub fn active(&self, log: &OneLog) -> Vec<(&'static Path, Layer)> {
+        let Some(channel) = log_channel(log) else {
+            return Vec::new();
+        };
+        let direction = Direction::from_log(log);
+        // a BCCH message goes on BCH for the MIB, on DL-SCH otherwise
+        let transport = (channel == "BCCH").then(|| match Channel::from_log(log) {
+            Some(Channel::BcchBch) => "BCH",
+            _ => "DL-SCH",
+        });
+        let mut active = Vec::new();
+        for path in self.paths {
+            if direction.is_some_and(|direction| direction != path.direction) {
+                continue;
+            }
+            if transport.is_some_and(|transport| transport != path.transport) {
+                continue;
+            }
+            if let Some(layer) = LAYERS
+                .into_iter()
+                .find(|layer| path.channel(*layer) == channel)
+            {
+                active.push((path, layer));
+            }
+        }
+        active
+    }
+}
+
+/// Name of the channel of a log as in the channel maps
+pub fn log_channel(log: &OneLog) -> Option<String> {
+    let channel = log.channel.as_deref()?.to_uppercase();
+    let channel = channel
+        .trim_end_matches("-NR")
+        .trim_end_matches("_NR")
+        .trim();
+    let name = if channel == "SRB0" {
+        "CCCH"
+    } else if channel.starts_with("SRB") {
+        "DCCH"
+    } else if channel.starts_with("DRB") {
+        "DTCH"
+    } else if channel.starts_with("BCCH") {
+        // BCCH-BCH, BCCH-DL-SCH
+        "BCCH"
+    } else {
+        channel
+    };
+    Some(name.to_owned())
+}
+
+/// Technology of a log, from its RRC or NAS message or its core network protocol
+pub fn technology(log: &OneLog) -> Option<Technology> {
+    match log.layer.to_uppercase().as_str() {
+        "NGAP" | "XNAP" | "NRPPA" => Some(Technology::Nr),
+        "S1AP" | "X2AP" | "LPPA" => Some(Technology::Lte),
+        // 5GS protocol discriminators, EPS ones otherwise
+        "NAS" => match hexdump::log_bytes(log).first() {
+            Some(0x7e | 0x2e) => Some(Technology::Nr),
+            Some(_) => Some(Technology::Lte),
+            None => None,
+        },
+        _ => rrc::technology(log),
+    }
+}
+
+/// Message count and heat of the channels over a time window, a message
+/// heats its chain less the older it is
+#[derive(Debug)]
+pub struct Activity {
+    channels: HashMap<(Direction, Layer, &'static str), (usize, f64)>,
+    max_heat: f64,
+    decay: f64,        // milliseconds for the heat of a message to be divided by e
+    last: Option<u64>, // timestamp of the most recent log, the heat is relative to it
+}
+
+impl Activity {
+    pub fn new(decay: f64) -> Self {
+        Self {
+            channels: HashMap::new(),
+            max_heat: 0.0,
+            decay: decay.max(1.0),
+            last: None,
+        }
+    }
+
+    /// Activity of the logs up to `end` included, over `window` milliseconds
+    /// before it. The heat of a message is divided by e every third of the window
+    pub fn compute(map: &ChannelMap, logs: &[OneLog], end: usize, window: u64) -> Self {
+        let mut activity = Activity::new(window as f64 / 3.0);
+        let Some(last) = logs.get(end) else {
+            return activity;
+        };
+        let start = last.timestamp.saturating_sub(window);
+        let count = logs[..=end]
+            .iter()
+            .rev()
+            .take_while(|log| log.timestamp >= start)
+            .count();
+        activity.add(map, &logs[end + 1 - count..=end]);
+        activity
+    }
+
+    /// Count more logs, the heat of the others decays up to the most recent one
+    pub fn add(&mut self, map: &ChannelMap, logs: &[OneLog]) {
+        for log in logs {
+            let last = self.last.unwrap_or(log.timestamp);
+            if log.timestamp > last {
+                let factor = (-((log.timestamp - last) as f64) / self.decay).exp();
+                for (_, heat) in self.channels.values_mut() {
+                    *heat *= factor;
+                }
+                self.max_heat *= factor;
+            }
+            let last = last.max(log.timestamp);
+            self.last = Some(last);
+            let weight = (-((last - log.timestamp) as f64) / self.decay).exp();
+            let mut seen = Vec::new();
+            for (path, from) in map.active(log) {
+                for layer in LAYERS.into_iter().filter(|layer| *layer >= from) {
+                    let key = (path.direction, layer, path.channel(layer));
+                    if key.2.is_empty() || seen.contains(&key) {
+                        continue;
+                    }
+                    seen.push(key);
+                    let (count, heat) = self.channels.entry(key).or_default();
+                    *count += 1;
+                    *heat += weight;
+                    self.max_heat = self.max_heat.max(*heat);
+                }
+            }
+        }
+    }
+
+    pub fn count(&self, direction: Direction, layer: Layer, channel: &'static str) -> usize {
+        self.channels
+            .get(&(direction, layer, channel))
+            .map_or(0, |(count, _)| *count)
+    }
+
+    /// Heat relative to the busiest channel, from 0 to 1
+    pub fn heat(&self, direction: Direction, layer: Layer, channel: &'static str) -> f32 {
+        match self.channels.get(&(direction, layer, channel)) {
+            Some((_, heat)) if self.max_heat > 0.0 => (heat / self.max_heat) as f32,
+            _ => 0.0,
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Connections to the log sources: state, reconnection and remote API session
+
+use crate::protocol::{Layers, LogProfile};
+use crate::session::Session;
+use crate::sources::{LogSource, SourceEvent};
+use crate::OneLog;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Seconds to wait for a source to confirm it is closed
+const CLOSE_TIMEOUT: f64 = 2.0;
+/// Seconds before the first reconnection attempt
+const FIRST_DELAY: f64 = 1.0;
+/// Longest delay between two reconnection attempts, in seconds
+const MAX_DELAY: f64 = 30.0;
+
+/// Settings of the connections to a server, saved between runs
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ConnectionOptions {
+    /// Largest websocket frame accepted, in bytes, a larger one fails the connection
+    pub max_frame_size: usize,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            max_frame_size: 16 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    /// Waiting for the source to open, first time or after a reconnection
+    Connecting,
+    Open,
+    /// Closed by the user, waiting for the source to confirm
+    Closing,
+    Closed,
+    /// Connection lost or refused, a reconnection may be scheduled
+    Failed(String),
+}
+
+impl ConnectionState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConnectionState::Connecting => "Connecting",
+            ConnectionState::Open => "Open",
+            ConnectionState::Closing => "Closing",
+            ConnectionState::Closed => "Closed",
+            ConnectionState::Failed(_) => "Failed",
+        }
+    }
+}
+
+/// Exponential backoff of the reconnection attempts, times are in seconds
+/// on any monotonic clock (e.g. `egui::InputState::time`)
+#[derive(Debug, Default)]
+pub struct Backoff {
+    pub attempts: u32,
+    next: Option<f64>, // time of the next attempt
+}
+
+impl Backoff {
+    /// Schedule the next attempt, each one waits twice as long as the previous
+    pub fn schedule(&mut self, now: f64) {
+        let delay = FIRST_DELAY * 2f64.powi(self.attempts.min(16) as i32);
+        self.attempts += 1;
+        self.next = Some(now + delay.min(MAX_DELAY));
+    }
+
+    /// Whether a scheduled attempt is due, it is then no longer scheduled
+    pub fn take_due(&mut self, now: f64) -> bool {
+        let due = self.next.is_some_and(|next| next <= now);
+        if due {
+            self.next = None;
+        }
+        due
+    }
+
+    /// Seconds until the next attempt, if one is scheduled
+    pub fn remaining(&self, now: f64) -> Option<f64> {
+        self.next.map(|next| (next - now).max(0.0))
+    }
+
+    /// Run the next attempt as soon as possible
+    pub fn hurry(&mut self, now: f64) {
+        self.next = Some(now);
+    }
+
+    /// The connection is open again: next failure waits `FIRST_DELAY`
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+        self.next = None;
+    }
+}
+
+/// One log source with its remote API session
+pub struct Connection {
+    pub source: Box<dyn LogSource>,
+    pub session: Session,
+    pub state: ConnectionState,
+    pub backoff: Backoff,
+    /// Layers of the profile it was opened with, the ones of `Data::profile` when `None`
+    pub layers: Option<Layers>,
+    /// A `log_get` is kept outstanding on it, whatever `Data::follow`
+    pub follow: bool,
+    closing_since: Option<f64>,   // time the user closed the source
+    last_log: Option<(u64, u64)>, // idx and timestamp of the last log received
+    resume: bool,                 // a `log_get` is due once the session is ready again
+}
+
+impl Connection {
+    pub fn new(source: Box<dyn LogSource>, password: Option<String>) -> Self {
+        let session = if source.is_remote() {
+            Session::new(password)
+        } else {
+            Session::offline()
+        };
+        Self {
+            source,
+            session,
+            state: ConnectionState::Connecting,
+            backoff: Backoff::default(),
+            layers: None,
+            follow: false,
+            closing_since: None,
+            last_log: None,
+            resume: false,
+        }
+    }
+
+    /// Parameters of its `log_get` requests: `profile` with its own layers
+    pub fn log_profile<'a>(&self, profile: &'a LogProfile) -> Cow<'a, LogProfile> {
+        match &self.layers {
+            Some(layers) => Cow::Owned(LogProfile {
+                layers: layers.clone(),
+                ..profile.clone()
+            }),
+            None => Cow::Borrowed(profile),
+        }
+    }
+
+    /// Whether logs may still come, a lost server may be reconnected
+    pub fn may_receive(&self) -> bool {
+        match self.state {
+            ConnectionState::Closing | ConnectionState::Closed => false,
+            _ => self.source.has_more(),
+        }
+    }
+
+    /// Close the source, it is `Closed` once the source confirms
+    pub fn close(&mut self, now: f64) {
+        self.source.close();
+        self.state = ConnectionState::Closing;
+        self.closing_since = Some(now);
+    }
+
+    /// The source failed or was closed by the other side
+    fn connection_lost(&mut self, reason: String, now: f64) {
+        log::warn!("Connection to {} lost: {}", self.source.name(), reason);
+        self.state = ConnectionState::Failed(reason);
+        if self.source.is_remote() {
+            self.backoff.schedule(now);
+        }
+    }
+
+    /// Open the source again, the logs are requested from where they stopped
+    fn reconnect(&mut self, now: f64) {
+        if let Err(e) = self.source.reconnect() {
+            self.connection_lost(e, now);
+            return;
+        }
+        let previous = std::mem::replace(&mut self.session, Session::offline());
+        self.session = previous.resume(self.last_log);
+        self.resume = self.last_log.is_some();
+        self.state = ConnectionState::Connecting;
+    }
+
+    /// Handle the events of the source, returns the logs received
+    pub fn poll(&mut self, now: f64) -> Vec<OneLog> {
+        let mut logs = Vec::new();
+        while let Some(event) = self.source.try_recv() {
+            match event {
+                SourceEvent::Logs(new_logs) => logs.extend(new_logs),
+                SourceEvent::Message(text) => {
+                    logs.extend(self.session.handle_message(self.source.as_mut(), &text));
+                }
+                SourceEvent::Invalid(str_error) => {
+                    log::error!("Unknown message: {:?}", str_error);
+                    self.session.diagnostics.invalid("invalid", str_error);
+                }
+                SourceEvent::Opened => {
+                    self.state = ConnectionState::Open;
+                    self.backoff.reset();
+                }
+                SourceEvent::Closed | SourceEvent::Error(_)
+                    if self.state == ConnectionState::Closing =>
+                {
+                    self.state = ConnectionState::Closed;
+                }
+                SourceEvent::Closed => {
+                    if !matches!(self.state, ConnectionState::Failed(_)) {
+                        self.connection_lost("Closed by the server".to_owned(), now);
+                    }
+                }
+                SourceEvent::Error(str_err) => {
+                    log::error!("Source error: {:?}", str_err);
+                    let diagnostics = &mut self.session.diagnostics;
+                    diagnostics.invalid("source error", str_err.clone());
+                    self.connection_lost(str_err, now);
+                }
+            }
+        }
+        // the last one and not the highest idx: a restarted server counts from 0 again
+        if let Some(last) = logs.last() {
+            self.last_log = Some((last.idx, last.timestamp));
+        }
+        logs
+    }
+
+    /// Timeouts of the state machine, called each frame. Returns the seconds
+    /// before it must be called again, if it is waiting for something
+    pub fn update(&mut self, now: f64, profile: &LogProfile) -> Option<f64> {
+        match &self.state {
+            ConnectionState::Failed(_) if self.backoff.take_due(now) => self.reconnect(now),
+            ConnectionState::Closing
+                if self
+                    .closing_since
+                    .is_some_and(|since| now - since > CLOSE_TIMEOUT) =>
+            {
+                self.state = ConnectionState::Closed;
+            }
+            _ => {}
+        }
+        if self.resume && self.state == ConnectionState::Open && self.session.is_ready() {
+            self.resume = false;
+            let profile = self.log_profile(profile);
+            if let Err(e) = self.session.request_logs(self.source.as_mut(), &profile) {
+                log::warn!("Cannot resume the logs: {}", e);
+            }
+        }
+        match self.state {
+            ConnectionState::Closing => Some(CLOSE_TIMEOUT),
+            _ => self.backoff.remaining(now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Level;
+    use crate::sources::MemorySource;
+
+    #[test]
+    fn layers_of_the_connection() {
+        let source = MemorySource::new("memory".to_owned(), Vec::new());
+        let mut connection = Connection::new(Box::new(source), None);
+        let profile = LogProfile {
+            max: 10,
+            ..LogProfile::default()
+        };
+        assert!(matches!(connection.log_profile(&profile), Cow::Borrowed(_)));
+
+        let mut layers = Layers::new();
+        layers.phy.level = Level::None;
+        connection.layers = Some(layers);
+        let own = connection.log_profile(&profile);
+        assert_eq!(own.max, 10);
+        assert_eq!(own.layers.phy.level, Level::None);
+        assert_eq!(profile.layers.phy.level, Level::Debug);
+    }
+}
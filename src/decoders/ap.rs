@@ -0,0 +1,253 @@
+//! Application protocols between the radio and the core network nodes (S1AP,
+//! NGAP): APER encoded elementary procedures carrying lists of IEs
+
+use super::per::{self, mandatory, BitReader, Size, Type};
+use super::Node;
+use crate::hexdump::to_hex;
+
+/// Elementary procedure and the names of its initiating, successful and
+/// unsuccessful messages, empty when there is none
+#[derive(Debug)]
+pub struct Procedure {
+    pub code: u8,
+    pub messages: [&'static str; 3],
+}
+
+/// Information element, its value is shown as raw octets without a type
+#[derive(Debug)]
+pub struct Ie {
+    pub id: u16,
+    pub name: &'static str,
+    pub ty: Option<&'static Type>,
+}
+
+pub const fn procedure(
+    code: u8,
+    initiating: &'static str,
+    successful: &'static str,
+    unsuccessful: &'static str,
+) -> Procedure {
+    Procedure {
+        code,
+        messages: [initiating, successful, unsuccessful],
+    }
+}
+
+pub const fn ie(id: u16, name: &'static str, ty: &'static Type) -> Ie {
+    Ie {
+        id,
+        name,
+        ty: Some(ty),
+    }
+}
+
+/// IE shown as raw octets
+pub const fn raw(id: u16, name: &'static str) -> Ie {
+    Ie { id, name, ty: None }
+}
+
+#[derive(Debug)]
+pub struct Protocol {
+    pub name: &'static str,
+    pub procedures: &'static [Procedure],
+    pub ies: &'static [Ie],
+}
+
+const MESSAGE_KINDS: [&str; 3] = [
+    "initiating message",
+    "successful outcome",
+    "unsuccessful outcome",
+];
+
+const CRITICALITIES: [&str; 3] = ["reject", "ignore", "notify"];
+
+pub static PROTOCOL_IE_ID: Type = Type::Integer(0, 65535);
+pub static CRITICALITY: Type = Type::Enumerated(&CRITICALITIES);
+
+/// ProtocolExtensionContainer, the extensions are kept as raw octets
+pub static IE_EXTENSIONS: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("id", &PROTOCOL_IE_ID),
+            mandatory!("criticality", &CRITICALITY),
+            mandatory!("extensionValue", &Type::OctetString(per::UNBOUNDED)),
+        ],
+        false,
+    ),
+    Size(1, Some(65535)),
+);
+
+/// Name of a criticality, the number when out of range
+fn criticality_name(criticality: usize) -> String {
+    match CRITICALITIES.get(criticality) {
+        Some(name) => (*name).to_owned(),
+        None => format!("criticality {}", criticality),
+    }
+}
+
+/// Decode a PDU of `protocol`
+pub fn decode(protocol: &Protocol, bytes: &[u8]) -> Node {
+    let mut node = Node::new(protocol.name);
+    if let Err(error) = pdu(protocol, bytes, &mut node) {
+        node.children.push(Node::error("PDU", &error));
+    }
+    node
+}
+
+fn pdu(protocol: &Protocol, bytes: &[u8], node: &mut Node) -> Result<(), String> {
+    let mut reader = BitReader::new(bytes, true);
+    if reader.bit()? {
+        return Err("extension of the PDU type".to_owned());
+    }
+    let kind = reader.constrained(0, 2)? as usize;
+    let kind_name = MESSAGE_KINDS
+        .get(kind)
+        .ok_or_else(|| format!("message type {} out of range", kind))?;
+    let code = reader.constrained(0, 255)? as u8;
+    let criticality = reader.constrained(0, 2)? as usize;
+    let value = reader.open_type()?;
+
+    let message = protocol
+        .procedures
+        .iter()
+        .find(|procedure| procedure.code == code)
+        .map(|procedure| procedure.messages[kind])
+        .filter(|message| !message.is_empty());
+    if let Some(message) = message {
+        node.name = format!("{} {}", protocol.name, message);
+    }
+    node.children.extend([
+        Node::leaf("message", (*kind_name).to_owned()),
+        Node::leaf(
+            "procedure code",
+            match message {
+                Some(message) => format!("{} ({})", code, message),
+                None => format!("{} (unknown)", code),
+            },
+        ),
+        Node::leaf("criticality", criticality_name(criticality)),
+    ]);
+    let mut ies = Node::new("protocolIEs");
+    let result = container(protocol.ies, &value, &mut ies.children);
+    ies.value = Some(format!("{} IEs", ies.children.len()));
+    node.children.push(ies);
+    result
+}
+
+/// Decode the IEs of a message or of a container in another IE
+pub fn protocol_ies(ies: &[Ie], bytes: &[u8]) -> Vec<Node> {
+    let mut out = Vec::new();
+    if let Err(error) = container(ies, bytes, &mut out) {
+        out.push(Node::error("protocolIEs", &error));
+    }
+    out
+}
+
+/// SEQUENCE { protocolIEs ProtocolIE-Container, ... }
+fn container(ies: &[Ie], bytes: &[u8], out: &mut Vec<Node>) -> Result<(), String> {
+    let mut reader = BitReader::new(bytes, true);
+    let extended = reader.bit()?;
+    let count = reader.length(Size(0, Some(65535)))?;
+    for _ in 0..count {
+        let id = reader.constrained(0, 65535)? as u16;
+        let criticality = reader.constrained(0, 2)? as usize;
+        let value = reader.open_type()?;
+        let known = ies.iter().find(|ie| ie.id == id);
+        let mut node = match known {
+            Some(Ie { ty: Some(ty), .. }) => per::decode(&value, true, "", ty),
+            _ => Node::leaf("", to_hex(&value)),
+        };
+        node.name = match known {
+            Some(ie) => format!("{} [{}, {}]", ie.name, id, criticality_name(criticality)),
+            None => format!("IE {} [{}]", id, criticality_name(criticality)),
+        };
+        out.push(node);
+    }
+    if extended {
+        out.push(Node::leaf("extension", "not decoded".to_owned()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ngap, s1ap};
+    use super::*;
+
+    /// UEContextReleaseRequest, MME-UE-S1AP-ID 1 and eNB-UE-S1AP-ID 2
+    const RELEASE_REQUEST: [u8; 19] = [
+        0x00, 0x12, 0x00, 0x0f, // initiating message, procedure 18
+        0x00, 0x00, 0x02, // 2 IEs
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x01, // id 0, reject
+        0x00, 0x08, 0x40, 0x02, 0x00, 0x02, // id 8, ignore
+    ];
+
+    #[test]
+    fn known_message() {
+        let node = decode(&s1ap::PROTOCOL, &RELEASE_REQUEST);
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(node.name, "S1AP UEContextReleaseRequest");
+        assert_eq!(
+            node.children[0].value.as_deref(),
+            Some("initiating message")
+        );
+        assert_eq!(node.children[2].value.as_deref(), Some("reject"));
+        let ies = &node.children[3];
+        assert_eq!(ies.value.as_deref(), Some("2 IEs"));
+        assert_eq!(ies.children[0].name, "MME-UE-S1AP-ID [0, reject]");
+        assert_eq!(ies.children[0].value.as_deref(), Some("1"));
+        assert_eq!(ies.children[1].name, "eNB-UE-S1AP-ID [8, ignore]");
+        assert_eq!(ies.children[1].value.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn message_type_out_of_range() {
+        let mut bytes = RELEASE_REQUEST;
+        bytes[0] = 0x60;
+        let node = decode(&s1ap::PROTOCOL, &bytes);
+        assert!(node.has_error());
+        assert_eq!(
+            node.children[0].value.as_deref(),
+            Some("message type 3 out of range")
+        );
+    }
+
+    #[test]
+    fn criticality_out_of_range() {
+        let mut bytes = RELEASE_REQUEST;
+        bytes[2] = 0xc0;
+        bytes[15] = 0xc0;
+        let node = decode(&s1ap::PROTOCOL, &bytes);
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(node.children[2].value.as_deref(), Some("criticality 3"));
+        let ies = &node.children[3];
+        assert_eq!(ies.children[1].name, "eNB-UE-S1AP-ID [8, criticality 3]");
+    }
+
+    #[test]
+    fn truncated() {
+        for len in 0..RELEASE_REQUEST.len() {
+            let node = decode(&s1ap::PROTOCOL, &RELEASE_REQUEST[..len]);
+            assert!(node.has_error(), "{} octets: {:?}", len, node);
+        }
+    }
+
+    #[test]
+    fn random_payloads() {
+        // xorshift, the same payloads on each run
+        let mut state = 0x9e3779b97f4a7c15u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for protocol in [&s1ap::PROTOCOL, &ngap::PROTOCOL] {
+            for _ in 0..2000 {
+                let len = next() as usize % 48 + 1;
+                let bytes: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+                decode(protocol, &bytes);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Decoding of the protocol messages found in the hex dumps of the logs
+
+pub mod ap;
+pub mod nas;
+mod nas_5gs;
+mod nas_eps;
+mod ngap;
+pub mod per;
+pub mod rrc;
+mod rrc_lte;
+mod rrc_nr;
+mod s1ap;
+
+use crate::hexdump::log_bytes;
+use crate::OneLog;
+use std::borrow::Cow;
+
+/// Identity of a UE carried by a NAS mobile identity
+#[derive(Debug, Clone, PartialEq)]
+pub enum MobileIdentity {
+    /// Digits of the IMSI, also of a SUCI without protection
+    Imsi(String),
+    /// Hex of the TMSI, M-TMSI of a GUTI or 5G-TMSI
+    Tmsi(String),
+}
+
+/// Decoded value, with its components as children
+#[derive(Debug, Clone, Default)]
+pub struct Node {
+    pub name: String,
+    pub value: Option<String>,
+    pub error: bool,                      // decoding failed here
+    pub warning: bool,                    // decoded but something could not be shown
+    pub identity: Option<MobileIdentity>, // of a mobile identity, whatever its text
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn leaf(name: &str, value: String) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn error(name: &str, message: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: Some(message.to_owned()),
+            error: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn warning(name: &str, message: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: Some(message.to_owned()),
+            warning: true,
+            ..Default::default()
+        }
+    }
+
+    /// Whether decoding failed somewhere in the tree
+    pub fn has_error(&self) -> bool {
+        self.error || self.children.iter().any(Node::has_error)
+    }
+}
+
+/// Decode the message carried by a log, `None` when it is not supported
+pub fn decode_log(log: &OneLog) -> Option<Node> {
+    let bytes = log_bytes(log);
+    if bytes.is_empty() {
+        return None;
+    }
+    match log.layer.to_uppercase().as_str() {
+        "RRC" => rrc::decode(log, &bytes),
+        "NAS" => Some(nas::decode(&bytes)),
+        "S1AP" => Some(ap::decode(&s1ap::PROTOCOL, &bytes)),
+        "NGAP" => Some(ap::decode(&ngap::PROTOCOL, &bytes)),
+        _ => None,
+    }
+}
+
+/// What the panels use of the message of an RRC or NAS log, decoded once
+/// when the log is added
+#[derive(Debug, Clone, Default)]
+pub struct MessageInfo {
+    /// Values of the `message type` fields: of the NAS messages it carries
+    pub message_types: Vec<String>,
+    pub identities: Vec<MobileIdentity>,
+}
+
+impl MessageInfo {
+    /// Info of an RRC or NAS log, `None` for the other layers or without a message
+    pub fn decode(log: &OneLog) -> Option<Self> {
+        if !log.layer.eq_ignore_ascii_case("RRC") && !log.layer.eq_ignore_ascii_case("NAS") {
+            return None;
+        }
+        let mut info = MessageInfo::default();
+        info.collect(&decode_log(log)?);
+        Some(info)
+    }
+
+    fn collect(&mut self, node: &Node) {
+        if node.name == "message type" {
+            self.message_types.extend(node.value.clone());
+        }
+        self.identities.extend(node.identity.clone());
+        for child in &node.children {
+            self.collect(child);
+        }
+    }
+}
+
+/// Info of a log: the one decoded when it was added, or decoded now
+pub fn message_info(log: &OneLog) -> Option<Cow<'_, MessageInfo>> {
+    match &log.info {
+        Some(info) => Some(Cow::Borrowed(info)),
+        None => MessageInfo::decode(log).map(Cow::Owned),
+    }
+}
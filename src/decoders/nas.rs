@@ -0,0 +1,592 @@
+//! NAS messages of EPS (3GPP TS 24.301) and 5GS (3GPP TS 24.501): security
+//! header, message type and information elements
+
+use super::{nas_5gs, nas_eps, MobileIdentity, Node};
+use crate::hexdump::to_hex;
+
+/// Format of an information element (3GPP TS 24.007 11.2.1.1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// Mandatory half octet, the first one of an octet is in the low bits
+    Half,
+    /// Mandatory value of fixed length
+    V(usize),
+    Lv,
+    LvE,
+    /// Optional half octet, the IEI is the high nibble
+    T1,
+    /// Optional value of fixed length, IEI included
+    Tv(usize),
+    Tlv,
+    TlvE,
+}
+
+/// How the value of an information element is shown
+#[derive(Debug, Clone, Copy)]
+pub enum Kind {
+    Raw,
+    Number,
+    Spare,
+    Names(&'static [(u8, &'static str)]),
+    /// Another NAS message
+    Nas,
+    EpsIdentity,
+    FiveGsIdentity,
+    /// Access point or data network name
+    Apn,
+    EpsAlgorithms,
+    FiveGsAlgorithms,
+}
+
+#[derive(Debug)]
+pub struct Ie {
+    // 0 for mandatory ones, or an optional LV present when the next octet is
+    // no known IEI
+    pub iei: u8,
+    pub name: &'static str,
+    pub format: Format,
+    pub kind: Kind,
+}
+
+pub const fn ie(iei: u8, name: &'static str, format: Format, kind: Kind) -> Ie {
+    Ie {
+        iei,
+        name,
+        format,
+        kind,
+    }
+}
+
+#[derive(Debug)]
+pub struct Message {
+    pub message_type: u8,
+    pub name: &'static str,
+    pub mandatory: &'static [Ie],
+    pub optional: &'static [Ie],
+}
+
+const SECURITY_HEADER_TYPES: [&str; 5] = [
+    "plain NAS message",
+    "integrity protected",
+    "integrity protected and ciphered",
+    "integrity protected with new security context",
+    "integrity protected and ciphered with new security context",
+];
+
+fn security_header_type(value: u8) -> Node {
+    let name = SECURITY_HEADER_TYPES
+        .get(value as usize)
+        .copied()
+        .unwrap_or("reserved");
+    Node::leaf("security header type", format!("{} ({})", name, value))
+}
+
+/// Decode a NAS message, plain or security protected
+pub fn decode(bytes: &[u8]) -> Node {
+    match bytes.first() {
+        None => Node::error("NAS", "empty message"),
+        Some(0x7e) => decode_5gmm(bytes),
+        Some(0x2e) => decode_5gsm(bytes),
+        Some(first) if first & 0x0f == 0x07 => decode_emm(bytes),
+        Some(first) if first & 0x0f == 0x02 => decode_esm(bytes),
+        Some(first) => Node::error(
+            "NAS",
+            &format!("unknown protocol discriminator {}", first & 0x0f),
+        ),
+    }
+}
+
+/// Decode a NAS container of another protocol
+pub fn decode_container(bytes: &[u8]) -> Vec<Node> {
+    vec![decode(bytes)]
+}
+
+/// Whether the bytes start like a NAS message
+fn is_nas(bytes: &[u8]) -> bool {
+    match bytes.first() {
+        Some(0x7e | 0x2e) => true,
+        Some(first) => matches!(first & 0x0f, 0x02 | 0x07),
+        None => false,
+    }
+}
+
+/// Security protected message: MAC, sequence number and the inner message
+fn protected(name: &str, header: Vec<Node>, ciphered: bool, rest: &[u8]) -> Node {
+    let mut node = Node::new(name);
+    node.children = header;
+    if rest.len() < 5 {
+        node.children
+            .push(Node::error("security header", "message too short"));
+        return node;
+    }
+    node.children.push(Node::leaf(
+        "message authentication code",
+        to_hex(&rest[..4]),
+    ));
+    node.children
+        .push(Node::leaf("sequence number", rest[4].to_string()));
+    let inner = &rest[5..];
+    if ciphered {
+        node.children.push(Node::warning(
+            "ciphered payload",
+            &format!("{} bytes, cannot be decoded", inner.len()),
+        ));
+    } else {
+        node.children.push(decode(inner));
+    }
+    node
+}
+
+fn decode_emm(bytes: &[u8]) -> Node {
+    let header_type = bytes[0] >> 4;
+    let header = vec![
+        Node::leaf(
+            "protocol discriminator",
+            "EPS mobility management (7)".to_owned(),
+        ),
+        security_header_type(header_type),
+    ];
+    match header_type {
+        0 => plain("EMM", header, nas_eps::EMM_MESSAGES, bytes.get(1..)),
+        12 => {
+            let mut node = Node::new("EMM Service request");
+            node.children = header;
+            if bytes.len() < 4 {
+                node.children
+                    .push(Node::error("service request", "message too short"));
+                return node;
+            }
+            node.children.extend([
+                Node::leaf("KSI", (bytes[1] >> 5).to_string()),
+                Node::leaf("sequence number", (bytes[1] & 0x1f).to_string()),
+                Node::leaf("short MAC", to_hex(&bytes[2..4])),
+            ]);
+            node
+        }
+        1..=4 => protected(
+            "EMM security protected message",
+            header,
+            header_type % 2 == 0,
+            &bytes[1..],
+        ),
+        _ => {
+            let mut node = Node::new("EMM");
+            node.children = header;
+            node.children
+                .push(Node::error("security header type", "not supported"));
+            node
+        }
+    }
+}
+
+fn decode_esm(bytes: &[u8]) -> Node {
+    let header = vec![
+        Node::leaf(
+            "protocol discriminator",
+            "EPS session management (2)".to_owned(),
+        ),
+        Node::leaf("EPS bearer identity", (bytes[0] >> 4).to_string()),
+    ];
+    let mut node = plain("ESM", header, nas_eps::ESM_MESSAGES, bytes.get(2..));
+    if let Some(pti) = bytes.get(1) {
+        node.children.insert(
+            2,
+            Node::leaf("procedure transaction identity", pti.to_string()),
+        );
+    }
+    node
+}
+
+fn decode_5gmm(bytes: &[u8]) -> Node {
+    let mut header = vec![Node::leaf(
+        "extended protocol discriminator",
+        "5GS mobility management (0x7e)".to_owned(),
+    )];
+    let Some(second) = bytes.get(1) else {
+        let mut node = Node::new("5GMM");
+        node.children = header;
+        node.children
+            .push(Node::error("security header type", "missing"));
+        return node;
+    };
+    let header_type = second & 0x0f;
+    header.push(security_header_type(header_type));
+    match header_type {
+        0 => plain("5GMM", header, nas_5gs::FIVEGMM_MESSAGES, bytes.get(2..)),
+        1..=4 => protected(
+            "5GMM security protected message",
+            header,
+            header_type % 2 == 0,
+            &bytes[2..],
+        ),
+        _ => {
+            let mut node = Node::new("5GMM");
+            node.children = header;
+            node.children
+                .push(Node::error("security header type", "not supported"));
+            node
+        }
+    }
+}
+
+fn decode_5gsm(bytes: &[u8]) -> Node {
+    let mut header = vec![Node::leaf(
+        "extended protocol discriminator",
+        "5GS session management (0x2e)".to_owned(),
+    )];
+    if let Some(psi) = bytes.get(1) {
+        header.push(Node::leaf("PDU session identity", psi.to_string()));
+    }
+    if let Some(pti) = bytes.get(2) {
+        header.push(Node::leaf(
+            "procedure transaction identity",
+            pti.to_string(),
+        ));
+    }
+    plain("5GSM", header, nas_5gs::FIVEGSM_MESSAGES, bytes.get(3..))
+}
+
+/// Plain message, `rest` starts with the message type
+fn plain(
+    protocol_name: &str,
+    header: Vec<Node>,
+    messages: &[Message],
+    rest: Option<&[u8]>,
+) -> Node {
+    let Some((&message_type, body)) = rest.and_then(<[u8]>::split_first) else {
+        let mut node = Node::new(protocol_name);
+        node.children = header;
+        node.children.push(Node::error("message type", "missing"));
+        return node;
+    };
+    let Some(message) = messages
+        .iter()
+        .find(|message| message.message_type == message_type)
+    else {
+        let mut node = Node::new(&format!("{} message 0x{:02x}", protocol_name, message_type));
+        node.children = header;
+        node.children.push(Node::leaf(
+            "message type",
+            format!("unknown (0x{:02x})", message_type),
+        ));
+        if !body.is_empty() {
+            node.children.push(Node::leaf("content", to_hex(body)));
+        }
+        return node;
+    };
+    let mut node = Node::new(&format!("{} {}", protocol_name, message.name));
+    node.children = header;
+    node.children.push(Node::leaf(
+        "message type",
+        format!("{} (0x{:02x})", message.name, message_type),
+    ));
+    let mut reader = Reader {
+        data: body,
+        position: 0,
+        high_nibble: false,
+    };
+    if let Err(error) = reader.elements(message, &mut node.children) {
+        node.children
+            .push(Node::error("information elements", &error));
+    }
+    node
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+    high_nibble: bool, // the low half of the current octet was read
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let end = self.position + count;
+        let bytes = self
+            .data
+            .get(self.position..end)
+            .ok_or_else(|| format!("{} bytes missing", end - self.data.len()))?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn half(&mut self) -> Result<u8, String> {
+        let octet = *self.data.get(self.position).ok_or("half octet missing")?;
+        if self.high_nibble {
+            self.high_nibble = false;
+            self.position += 1;
+            Ok(octet >> 4)
+        } else {
+            self.high_nibble = true;
+            Ok(octet & 0x0f)
+        }
+    }
+
+    fn length(&mut self, extended: bool) -> Result<usize, String> {
+        let bytes = self.take(if extended { 2 } else { 1 })?;
+        Ok(bytes.iter().fold(0, |len, byte| len << 8 | *byte as usize))
+    }
+
+    fn elements(&mut self, message: &Message, out: &mut Vec<Node>) -> Result<(), String> {
+        for ie in message.mandatory {
+            if ie.format != Format::Half && self.high_nibble {
+                // a lone half octet is followed by a spare one
+                self.high_nibble = false;
+                self.position += 1;
+            }
+            let value = match ie.format {
+                Format::Half => Value::Nibble(self.half()?),
+                Format::V(len) => Value::Bytes(self.take(len)?),
+                Format::Lv => {
+                    let len = self.length(false)?;
+                    Value::Bytes(self.take(len)?)
+                }
+                Format::LvE => {
+                    let len = self.length(true)?;
+                    Value::Bytes(self.take(len)?)
+                }
+                _ => return Err(format!("{}: not a mandatory format", ie.name)),
+            };
+            if !matches!(ie.kind, Kind::Spare) {
+                out.push(element(ie.name, ie.kind, value));
+            }
+        }
+        let mut conditional = message.optional.iter().find(|ie| ie.iei == 0);
+        while let Some(&iei) = self.data.get(self.position) {
+            let known = message.optional.iter().find(|ie| match ie.format {
+                Format::T1 => iei & 0xf0 == ie.iei,
+                _ => ie.iei != 0 && iei == ie.iei,
+            });
+            if let (None, Some(ie)) = (known, conditional.take()) {
+                let len = self.length(false)?;
+                out.push(element(ie.name, ie.kind, Value::Bytes(self.take(len)?)));
+                continue;
+            }
+            // unknown IEs are skipped from the IEI ranges of 3GPP TS 24.007
+            let format = match known {
+                Some(ie) => ie.format,
+                None if iei & 0x80 != 0 => Format::T1,
+                None if iei & 0xf0 == 0x70 => Format::TlvE,
+                None => Format::Tlv,
+            };
+            let value = match format {
+                Format::T1 => Value::Nibble(self.take(1)?[0] & 0x0f),
+                Format::Tv(len) => Value::Bytes(&self.take(len)?[1..]),
+                Format::Tlv | Format::TlvE => {
+                    self.position += 1;
+                    let len = self.length(format == Format::TlvE)?;
+                    Value::Bytes(self.take(len)?)
+                }
+                _ => return Err(format!("IEI 0x{:02x}: not an optional format", iei)),
+            };
+            out.push(match known {
+                Some(ie) => element(ie.name, ie.kind, value),
+                None => element(&format!("IEI 0x{:02x}", iei), Kind::Raw, value),
+            });
+        }
+        Ok(())
+    }
+}
+
+enum Value<'a> {
+    Nibble(u8),
+    Bytes(&'a [u8]),
+}
+
+fn element(name: &str, kind: Kind, value: Value<'_>) -> Node {
+    let bytes = match value {
+        Value::Nibble(nibble) => {
+            let text = match kind {
+                Kind::Names(names) => named(names, nibble),
+                _ => nibble.to_string(),
+            };
+            return Node::leaf(name, text);
+        }
+        Value::Bytes(bytes) => bytes,
+    };
+    match kind {
+        Kind::Number if bytes.len() == 1 => Node::leaf(name, bytes[0].to_string()),
+        Kind::Names(names) if bytes.len() == 1 => Node::leaf(name, named(names, bytes[0])),
+        Kind::Nas if is_nas(bytes) => {
+            let mut node = Node::leaf(name, format!("{} bytes", bytes.len()));
+            node.children.push(decode(bytes));
+            node
+        }
+        Kind::EpsIdentity => {
+            let (text, identity) = eps_identity(bytes);
+            Node {
+                identity,
+                ..Node::leaf(name, text)
+            }
+        }
+        Kind::FiveGsIdentity => {
+            let (text, identity) = fivegs_identity(bytes);
+            Node {
+                identity,
+                ..Node::leaf(name, text)
+            }
+        }
+        Kind::Apn => Node::leaf(name, apn(bytes)),
+        Kind::EpsAlgorithms if bytes.len() == 1 => Node::leaf(
+            name,
+            format!("EEA{} / EIA{}", bytes[0] >> 4 & 0x07, bytes[0] & 0x07),
+        ),
+        Kind::FiveGsAlgorithms if bytes.len() == 1 => Node::leaf(
+            name,
+            format!("NEA{} / NIA{}", bytes[0] >> 4, bytes[0] & 0x0f),
+        ),
+        _ if bytes.is_empty() => Node::leaf(name, "empty".to_owned()),
+        _ => Node::leaf(name, to_hex(bytes)),
+    }
+}
+
+fn named(names: &[(u8, &str)], value: u8) -> String {
+    match names.iter().find(|(number, _)| *number == value) {
+        Some((_, name)) => format!("{} ({})", name, value),
+        None => value.to_string(),
+    }
+}
+
+/// Decimal digits of BCD octets, low nibble first, stopping at a filler
+fn bcd(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .flat_map(|byte| [byte & 0x0f, byte >> 4])
+        .take_while(|digit| *digit < 10)
+        .map(|digit| char::from(b'0' + digit))
+        .collect()
+}
+
+/// `MCC-MNC` of a PLMN identity (3GPP TS 24.008 10.5.1.3)
+pub fn plmn(bytes: &[u8]) -> String {
+    if bytes.len() < 3 {
+        return to_hex(bytes);
+    }
+    let digit = |nibble: u8| match nibble {
+        0..=9 => char::from(b'0' + nibble).to_string(),
+        _ => String::new(),
+    };
+    format!(
+        "{}{}{}-{}{}{}",
+        digit(bytes[0] & 0x0f),
+        digit(bytes[0] >> 4),
+        digit(bytes[1] & 0x0f),
+        digit(bytes[2] & 0x0f),
+        digit(bytes[2] >> 4),
+        digit(bytes[1] >> 4),
+    )
+}
+
+/// Digits of an IMSI or IMEI, the first one is in the high nibble of the
+/// first octet and a filler ends an even number of digits
+fn identity_digits(bytes: &[u8]) -> String {
+    let mut digits = String::new();
+    if bytes[0] >> 4 < 10 {
+        digits.push(char::from(b'0' + (bytes[0] >> 4)));
+    }
+    digits += &bcd(&bytes[1..]);
+    digits
+}
+
+/// EPS mobile identity (3GPP TS 24.301 9.9.3.12) or mobile identity, with
+/// the IMSI or TMSI it holds
+fn eps_identity(bytes: &[u8]) -> (String, Option<MobileIdentity>) {
+    let Some(first) = bytes.first() else {
+        return ("empty".to_owned(), None);
+    };
+    match first & 0x07 {
+        1 => {
+            let imsi = identity_digits(bytes);
+            (format!("IMSI {}", imsi), Some(MobileIdentity::Imsi(imsi)))
+        }
+        2 => (format!("IMEI {}", identity_digits(bytes)), None),
+        3 => (format!("IMEISV {}", identity_digits(bytes)), None),
+        4 if bytes.len() == 5 => {
+            let tmsi = to_hex(&bytes[1..]);
+            (format!("TMSI {}", tmsi), Some(MobileIdentity::Tmsi(tmsi)))
+        }
+        6 if bytes.len() == 11 => {
+            let tmsi = to_hex(&bytes[7..11]);
+            let text = format!(
+                "GUTI {} MMEGI {} MMEC {} M-TMSI {}",
+                plmn(&bytes[1..4]),
+                to_hex(&bytes[4..6]),
+                to_hex(&bytes[6..7]),
+                tmsi
+            );
+            (text, Some(MobileIdentity::Tmsi(tmsi)))
+        }
+        _ => (to_hex(bytes), None),
+    }
+}
+
+/// 5GS mobile identity (3GPP TS 24.501 9.11.3.4), with the IMSI or TMSI it
+/// holds: the IMSI of a SUCI is known with the null protection scheme only
+fn fivegs_identity(bytes: &[u8]) -> (String, Option<MobileIdentity>) {
+    let Some(first) = bytes.first() else {
+        return ("empty".to_owned(), None);
+    };
+    match first & 0x07 {
+        0 => ("no identity".to_owned(), None),
+        1 if first >> 4 & 0x07 == 0 && bytes.len() >= 8 => {
+            let plmn = plmn(&bytes[1..4]);
+            let scheme = bytes[6] & 0x0f;
+            let (output, imsi) = match scheme {
+                0 => {
+                    let msin = bcd(&bytes[8..]);
+                    let imsi = format!("{}{}", plmn.replace('-', ""), msin);
+                    (msin, Some(MobileIdentity::Imsi(imsi)))
+                }
+                _ => (to_hex(&bytes[8..]), None),
+            };
+            let text = format!(
+                "SUCI {} routing indicator {} protection scheme {} key {} output {}",
+                plmn,
+                bcd(&bytes[4..6]),
+                scheme,
+                bytes[7],
+                output
+            );
+            (text, imsi)
+        }
+        1 => (format!("SUCI {}", to_hex(&bytes[1..])), None),
+        2 if bytes.len() == 11 => {
+            let tmsi = to_hex(&bytes[7..11]);
+            let text = format!(
+                "5G-GUTI {} AMF region {} AMF set {} AMF pointer {} 5G-TMSI {}",
+                plmn(&bytes[1..4]),
+                bytes[4],
+                (bytes[5] as u16) << 2 | (bytes[6] >> 6) as u16,
+                bytes[6] & 0x3f,
+                tmsi
+            );
+            (text, Some(MobileIdentity::Tmsi(tmsi)))
+        }
+        3 => (format!("IMEI {}", identity_digits(bytes)), None),
+        4 if bytes.len() == 7 => {
+            let tmsi = to_hex(&bytes[3..7]);
+            let text = format!(
+                "5G-S-TMSI AMF set {} AMF pointer {} 5G-TMSI {}",
+                (bytes[1] as u16) << 2 | (bytes[2] >> 6) as u16,
+                bytes[2] & 0x3f,
+                tmsi
+            );
+            (text, Some(MobileIdentity::Tmsi(tmsi)))
+        }
+        5 => (format!("IMEISV {}", identity_digits(bytes)), None),
+        _ => (to_hex(bytes), None),
+    }
+}
+
+/// Labels of an APN or DNN, each prefixed by its length
+fn apn(bytes: &[u8]) -> String {
+    let mut labels = Vec::new();
+    let mut rest = bytes;
+    while let Some((&len, tail)) = rest.split_first() {
+        let Some(label) = tail.get(..len as usize) else {
+            return to_hex(bytes);
+        };
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        rest = &tail[len as usize..];
+    }
+    labels.join(".")
+}
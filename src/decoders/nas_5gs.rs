@@ -0,0 +1,690 @@
+//! 5GMM and 5GSM messages (3GPP TS 24.501 chapter 8)
+
+use super::nas::Format::{Half, Lv, LvE, Tlv, TlvE, Tv, T1, V};
+use super::nas::Kind::{Apn, FiveGsAlgorithms, FiveGsIdentity, Names, Nas, Number, Raw, Spare};
+use super::nas::{ie, Ie, Message};
+
+const FIVEGMM_CAUSES: &[(u8, &str)] = &[
+    (3, "illegal UE"),
+    (5, "PEI not accepted"),
+    (6, "illegal ME"),
+    (7, "5GS services not allowed"),
+    (9, "UE identity cannot be derived by the network"),
+    (10, "implicitly de-registered"),
+    (11, "PLMN not allowed"),
+    (12, "tracking area not allowed"),
+    (13, "roaming not allowed in this tracking area"),
+    (15, "no suitable cells in tracking area"),
+    (20, "MAC failure"),
+    (21, "synch failure"),
+    (22, "congestion"),
+    (23, "UE security capabilities mismatch"),
+    (24, "security mode rejected, unspecified"),
+    (26, "non-5G authentication unacceptable"),
+    (27, "N1 mode not allowed"),
+    (28, "restricted service area"),
+    (31, "redirection to EPC required"),
+    (62, "no network slices available"),
+    (65, "maximum number of PDU sessions reached"),
+    (67, "insufficient resources for specific slice and DNN"),
+    (69, "insufficient resources for specific slice"),
+    (71, "ngKSI already in use"),
+    (72, "non-3GPP access to 5GCN not allowed"),
+    (73, "serving network not authorized"),
+    (90, "payload was not forwarded"),
+    (91, "DNN not supported or not subscribed in the slice"),
+    (92, "insufficient user-plane resources for the PDU session"),
+    (95, "semantically incorrect message"),
+    (96, "invalid mandatory information"),
+    (97, "message type non-existent or not implemented"),
+    (99, "information element non-existent or not implemented"),
+    (111, "protocol error, unspecified"),
+];
+
+const FIVEGSM_CAUSES: &[(u8, &str)] = &[
+    (8, "operator determined barring"),
+    (26, "insufficient resources"),
+    (27, "missing or unknown DNN"),
+    (28, "unknown PDU session type"),
+    (29, "user authentication or authorization failed"),
+    (31, "request rejected, unspecified"),
+    (32, "service option not supported"),
+    (33, "requested service option not subscribed"),
+    (35, "PTI already in use"),
+    (36, "regular deactivation"),
+    (38, "network failure"),
+    (39, "reactivation requested"),
+    (43, "invalid PDU session identity"),
+    (46, "out of LADN service area"),
+    (47, "PTI mismatch"),
+    (50, "PDU session type IPv4 only allowed"),
+    (51, "PDU session type IPv6 only allowed"),
+    (54, "PDU session does not exist"),
+    (67, "insufficient resources for specific slice and DNN"),
+    (68, "not supported SSC mode"),
+    (69, "insufficient resources for specific slice"),
+    (70, "missing or unknown DNN in a slice"),
+    (81, "invalid PTI value"),
+    (83, "semantic error in the QoS operation"),
+    (84, "syntactical error in the QoS operation"),
+    (95, "semantically incorrect message"),
+    (96, "invalid mandatory information"),
+    (111, "protocol error, unspecified"),
+];
+
+const REGISTRATION_TYPES: &[(u8, &str)] = &[
+    (1, "initial registration"),
+    (2, "mobility registration updating"),
+    (3, "periodic registration updating"),
+    (4, "emergency registration"),
+    (9, "initial registration, follow-on request pending"),
+    (
+        10,
+        "mobility registration updating, follow-on request pending",
+    ),
+    (
+        11,
+        "periodic registration updating, follow-on request pending",
+    ),
+    (12, "emergency registration, follow-on request pending"),
+];
+
+const IDENTITY_TYPES: &[(u8, &str)] = &[
+    (1, "SUCI"),
+    (2, "5G-GUTI"),
+    (3, "IMEI"),
+    (4, "5G-S-TMSI"),
+    (5, "IMEISV"),
+];
+
+const SERVICE_TYPES: &[(u8, &str)] = &[
+    (0, "signalling"),
+    (1, "data"),
+    (2, "mobile terminated services"),
+    (3, "emergency services"),
+    (4, "emergency services fallback"),
+    (5, "high priority access"),
+    (6, "elevated signalling"),
+];
+
+const PAYLOAD_CONTAINER_TYPES: &[(u8, &str)] = &[
+    (1, "N1 SM information"),
+    (2, "SMS"),
+    (3, "LPP message container"),
+    (4, "SOR transparent container"),
+    (5, "UE policy container"),
+    (6, "UE parameters update transparent container"),
+    (7, "location services message container"),
+    (8, "CIoT user data container"),
+    (15, "multiple payloads"),
+];
+
+const PDU_SESSION_TYPES: &[(u8, &str)] = &[
+    (1, "IPv4"),
+    (2, "IPv6"),
+    (3, "IPv4v6"),
+    (4, "unstructured"),
+    (5, "ethernet"),
+];
+
+const REQUEST_TYPES: &[(u8, &str)] = &[
+    (1, "initial request"),
+    (2, "existing PDU session"),
+    (3, "initial emergency request"),
+    (4, "existing emergency PDU session"),
+    (5, "modification request"),
+    (6, "MA PDU request"),
+];
+
+const NGKSI: Ie = ie(0, "ngKSI", Half, Number);
+const SPARE: Ie = ie(0, "spare", Half, Spare);
+const FIVEGMM_CAUSE: Ie = ie(0, "5GMM cause", V(1), Names(FIVEGMM_CAUSES));
+const FIVEGSM_CAUSE: Ie = ie(0, "5GSM cause", V(1), Names(FIVEGSM_CAUSES));
+const OPTIONAL_FIVEGSM_CAUSE: Ie = ie(0x59, "5GSM cause", Tv(2), Names(FIVEGSM_CAUSES));
+const EAP_MESSAGE: Ie = ie(0x78, "EAP message", TlvE, Raw);
+const PDU_SESSION_STATUS: Ie = ie(0x50, "PDU session status", Tlv, Raw);
+const T3346: Ie = ie(0x5f, "T3346 value", Tlv, Raw);
+const T3448: Ie = ie(0x6b, "T3448 value", Tlv, Raw);
+const BACK_OFF_TIMER: Ie = ie(0x37, "back-off timer value", Tlv, Raw);
+const EPCO: Ie = ie(0x7b, "extended protocol configuration options", TlvE, Raw);
+const NAS_MESSAGE_CONTAINER: Ie = ie(0x71, "NAS message container", TlvE, Nas);
+
+pub static FIVEGMM_MESSAGES: &[Message] = &[
+    Message {
+        message_type: 0x41,
+        name: "Registration request",
+        mandatory: &[
+            ie(0, "5GS registration type", Half, Names(REGISTRATION_TYPES)),
+            NGKSI,
+            ie(0, "5GS mobile identity", LvE, FiveGsIdentity),
+        ],
+        optional: &[
+            ie(
+                0xc0,
+                "non-current native NAS key set identifier",
+                T1,
+                Number,
+            ),
+            ie(0x10, "5GMM capability", Tlv, Raw),
+            ie(0x2e, "UE security capability", Tlv, Raw),
+            ie(0x2f, "requested NSSAI", Tlv, Raw),
+            ie(0x52, "last visited registered TAI", Tv(7), Raw),
+            ie(0x17, "S1 UE network capability", Tlv, Raw),
+            ie(0x40, "uplink data status", Tlv, Raw),
+            PDU_SESSION_STATUS,
+            ie(0xb0, "MICO indication", T1, Number),
+            ie(0x2b, "UE status", Tlv, Raw),
+            ie(0x77, "additional GUTI", TlvE, FiveGsIdentity),
+            ie(0x25, "allowed PDU session status", Tlv, Raw),
+            ie(0x18, "UE's usage setting", Tlv, Raw),
+            ie(0x51, "requested DRX parameters", Tlv, Raw),
+            ie(0x70, "EPS NAS message container", TlvE, Nas),
+            ie(0x74, "LADN indication", TlvE, Raw),
+            ie(
+                0x80,
+                "payload container type",
+                T1,
+                Names(PAYLOAD_CONTAINER_TYPES),
+            ),
+            ie(0x7b, "payload container", TlvE, Raw),
+            ie(0x90, "network slicing indication", T1, Number),
+            ie(0x53, "5GS update type", Tlv, Raw),
+            ie(0x41, "mobile station classmark 2", Tlv, Raw),
+            ie(0x42, "supported codecs", Tlv, Raw),
+            NAS_MESSAGE_CONTAINER,
+            ie(0x60, "EPS bearer context status", Tlv, Raw),
+            ie(0x6e, "requested extended DRX parameters", Tlv, Raw),
+            ie(0x6a, "T3324 value", Tlv, Raw),
+            ie(0x67, "UE radio capability ID", Tlv, Raw),
+            ie(0x35, "requested mapped NSSAI", Tlv, Raw),
+            ie(0x48, "additional information requested", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x42,
+        name: "Registration accept",
+        mandatory: &[ie(0, "5GS registration result", Lv, Raw)],
+        optional: &[
+            ie(0x77, "5G-GUTI", TlvE, FiveGsIdentity),
+            ie(0x4a, "equivalent PLMNs", Tlv, Raw),
+            ie(0x54, "TAI list", Tlv, Raw),
+            ie(0x15, "allowed NSSAI", Tlv, Raw),
+            ie(0x11, "rejected NSSAI", Tlv, Raw),
+            ie(0x31, "configured NSSAI", Tlv, Raw),
+            ie(0x21, "5GS network feature support", Tlv, Raw),
+            PDU_SESSION_STATUS,
+            ie(0x26, "PDU session reactivation result", Tlv, Raw),
+            ie(
+                0x72,
+                "PDU session reactivation result error cause",
+                TlvE,
+                Raw,
+            ),
+            ie(0x79, "LADN information", TlvE, Raw),
+            ie(0xb0, "MICO indication", T1, Number),
+            ie(0x90, "network slicing indication", T1, Number),
+            ie(0x27, "service area list", Tlv, Raw),
+            ie(0x5e, "T3512 value", Tlv, Raw),
+            ie(0x5d, "non-3GPP de-registration timer value", Tlv, Raw),
+            ie(0x16, "T3502 value", Tlv, Raw),
+            ie(0x34, "emergency number list", Tlv, Raw),
+            ie(0x7a, "extended emergency number list", TlvE, Raw),
+            ie(0x73, "SOR transparent container", TlvE, Raw),
+            EAP_MESSAGE,
+            ie(0xa0, "NSSAI inclusion mode", T1, Number),
+            ie(
+                0x76,
+                "operator-defined access category definitions",
+                TlvE,
+                Raw,
+            ),
+            ie(0x51, "negotiated DRX parameters", Tlv, Raw),
+            ie(0xd0, "non-3GPP NW provided policies", T1, Number),
+            ie(0x60, "EPS bearer context status", Tlv, Raw),
+            ie(0x6e, "negotiated extended DRX parameters", Tlv, Raw),
+            ie(0x6c, "T3447 value", Tlv, Raw),
+            T3448,
+            ie(0x6a, "T3324 value", Tlv, Raw),
+            ie(0x67, "UE radio capability ID", Tlv, Raw),
+            ie(
+                0xe0,
+                "UE radio capability ID deletion indication",
+                T1,
+                Number,
+            ),
+            ie(0x39, "pending NSSAI", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x43,
+        name: "Registration complete",
+        mandatory: &[],
+        optional: &[ie(0x73, "SOR transparent container", TlvE, Raw)],
+    },
+    Message {
+        message_type: 0x44,
+        name: "Registration reject",
+        mandatory: &[FIVEGMM_CAUSE],
+        optional: &[
+            T3346,
+            ie(0x16, "T3502 value", Tlv, Raw),
+            EAP_MESSAGE,
+            ie(0x69, "rejected NSSAI", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x45,
+        name: "Deregistration request (UE originating)",
+        mandatory: &[
+            ie(0, "de-registration type", Half, Number),
+            NGKSI,
+            ie(0, "5GS mobile identity", LvE, FiveGsIdentity),
+        ],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x46,
+        name: "Deregistration accept (UE originating)",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x47,
+        name: "Deregistration request (UE terminated)",
+        mandatory: &[ie(0, "de-registration type", Half, Number), SPARE],
+        optional: &[
+            ie(0x58, "5GMM cause", Tv(2), Names(FIVEGMM_CAUSES)),
+            T3346,
+            ie(0x6d, "rejected NSSAI", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x48,
+        name: "Deregistration accept (UE terminated)",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x4c,
+        name: "Service request",
+        mandatory: &[
+            NGKSI,
+            ie(0, "service type", Half, Names(SERVICE_TYPES)),
+            ie(0, "5G-S-TMSI", LvE, FiveGsIdentity),
+        ],
+        optional: &[
+            ie(0x40, "uplink data status", Tlv, Raw),
+            PDU_SESSION_STATUS,
+            ie(0x25, "allowed PDU session status", Tlv, Raw),
+            NAS_MESSAGE_CONTAINER,
+        ],
+    },
+    Message {
+        message_type: 0x4d,
+        name: "Service reject",
+        mandatory: &[FIVEGMM_CAUSE],
+        optional: &[PDU_SESSION_STATUS, T3346, EAP_MESSAGE, T3448],
+    },
+    Message {
+        message_type: 0x4e,
+        name: "Service accept",
+        mandatory: &[],
+        optional: &[
+            PDU_SESSION_STATUS,
+            ie(0x26, "PDU session reactivation result", Tlv, Raw),
+            ie(
+                0x72,
+                "PDU session reactivation result error cause",
+                TlvE,
+                Raw,
+            ),
+            EAP_MESSAGE,
+            T3448,
+        ],
+    },
+    Message {
+        message_type: 0x54,
+        name: "Configuration update command",
+        mandatory: &[],
+        optional: &[
+            ie(0xd0, "configuration update indication", T1, Number),
+            ie(0x77, "5G-GUTI", TlvE, FiveGsIdentity),
+            ie(0x54, "TAI list", Tlv, Raw),
+            ie(0x15, "allowed NSSAI", Tlv, Raw),
+            ie(0x27, "service area list", Tlv, Raw),
+            ie(0x43, "full name for network", Tlv, Raw),
+            ie(0x45, "short name for network", Tlv, Raw),
+            ie(0x46, "local time zone", Tv(2), Raw),
+            ie(0x47, "universal time and local time zone", Tv(8), Raw),
+            ie(0x49, "network daylight saving time", Tlv, Raw),
+            ie(0x79, "LADN information", TlvE, Raw),
+            ie(0xb0, "MICO indication", T1, Number),
+            ie(0x90, "network slicing indication", T1, Number),
+            ie(0x31, "configured NSSAI", Tlv, Raw),
+            ie(0x11, "rejected NSSAI", Tlv, Raw),
+            ie(
+                0x76,
+                "operator-defined access category definitions",
+                TlvE,
+                Raw,
+            ),
+            ie(0xf0, "SMS indication", T1, Number),
+        ],
+    },
+    Message {
+        message_type: 0x55,
+        name: "Configuration update complete",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x56,
+        name: "Authentication request",
+        mandatory: &[NGKSI, SPARE, ie(0, "ABBA", Lv, Raw)],
+        optional: &[
+            ie(0x21, "RAND", Tv(17), Raw),
+            ie(0x20, "AUTN", Tlv, Raw),
+            EAP_MESSAGE,
+        ],
+    },
+    Message {
+        message_type: 0x57,
+        name: "Authentication response",
+        mandatory: &[],
+        optional: &[
+            ie(0x2d, "authentication response parameter", Tlv, Raw),
+            EAP_MESSAGE,
+        ],
+    },
+    Message {
+        message_type: 0x58,
+        name: "Authentication reject",
+        mandatory: &[],
+        optional: &[EAP_MESSAGE],
+    },
+    Message {
+        message_type: 0x59,
+        name: "Authentication failure",
+        mandatory: &[FIVEGMM_CAUSE],
+        optional: &[ie(0x30, "authentication failure parameter", Tlv, Raw)],
+    },
+    Message {
+        message_type: 0x5a,
+        name: "Authentication result",
+        mandatory: &[NGKSI, SPARE, ie(0, "EAP message", LvE, Raw)],
+        optional: &[ie(0x38, "ABBA", Tlv, Raw)],
+    },
+    Message {
+        message_type: 0x5b,
+        name: "Identity request",
+        mandatory: &[ie(0, "identity type", Half, Names(IDENTITY_TYPES)), SPARE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x5c,
+        name: "Identity response",
+        mandatory: &[ie(0, "mobile identity", LvE, FiveGsIdentity)],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x5d,
+        name: "Security mode command",
+        mandatory: &[
+            ie(
+                0,
+                "selected NAS security algorithms",
+                V(1),
+                FiveGsAlgorithms,
+            ),
+            NGKSI,
+            SPARE,
+            ie(0, "replayed UE security capabilities", Lv, Raw),
+        ],
+        optional: &[
+            ie(0xe0, "IMEISV request", T1, Number),
+            ie(0x57, "selected EPS NAS security algorithms", Tv(2), Raw),
+            ie(0x36, "additional 5G security information", Tlv, Raw),
+            EAP_MESSAGE,
+            ie(0x38, "ABBA", Tlv, Raw),
+            ie(0x19, "replayed S1 UE security capabilities", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x5e,
+        name: "Security mode complete",
+        mandatory: &[],
+        optional: &[
+            ie(0x77, "IMEISV", TlvE, FiveGsIdentity),
+            NAS_MESSAGE_CONTAINER,
+            ie(0x78, "non-IMEISV PEI", TlvE, FiveGsIdentity),
+        ],
+    },
+    Message {
+        message_type: 0x5f,
+        name: "Security mode reject",
+        mandatory: &[FIVEGMM_CAUSE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x64,
+        name: "5GMM status",
+        mandatory: &[FIVEGMM_CAUSE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x65,
+        name: "Notification",
+        mandatory: &[ie(0, "access type", Half, Number), SPARE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x66,
+        name: "Notification response",
+        mandatory: &[],
+        optional: &[PDU_SESSION_STATUS],
+    },
+    Message {
+        message_type: 0x67,
+        name: "UL NAS transport",
+        mandatory: &[
+            ie(
+                0,
+                "payload container type",
+                Half,
+                Names(PAYLOAD_CONTAINER_TYPES),
+            ),
+            SPARE,
+            ie(0, "payload container", LvE, Nas),
+        ],
+        optional: &[
+            ie(0x12, "PDU session ID", Tv(2), Number),
+            ie(0x59, "old PDU session ID", Tv(2), Number),
+            ie(0x80, "request type", T1, Names(REQUEST_TYPES)),
+            ie(0x22, "S-NSSAI", Tlv, Raw),
+            ie(0x25, "DNN", Tlv, Apn),
+            ie(0x24, "additional information", Tlv, Raw),
+            ie(0xa0, "MA PDU session information", T1, Number),
+            ie(0xf0, "release assistance indication", T1, Number),
+        ],
+    },
+    Message {
+        message_type: 0x68,
+        name: "DL NAS transport",
+        mandatory: &[
+            ie(
+                0,
+                "payload container type",
+                Half,
+                Names(PAYLOAD_CONTAINER_TYPES),
+            ),
+            SPARE,
+            ie(0, "payload container", LvE, Nas),
+        ],
+        optional: &[
+            ie(0x12, "PDU session ID", Tv(2), Number),
+            ie(0x24, "additional information", Tlv, Raw),
+            ie(0x58, "5GMM cause", Tv(2), Names(FIVEGMM_CAUSES)),
+            BACK_OFF_TIMER,
+        ],
+    },
+];
+
+pub static FIVEGSM_MESSAGES: &[Message] = &[
+    Message {
+        message_type: 0xc1,
+        name: "PDU session establishment request",
+        mandatory: &[ie(0, "integrity protection maximum data rate", V(2), Raw)],
+        optional: &[
+            ie(0x90, "PDU session type", T1, Names(PDU_SESSION_TYPES)),
+            ie(0xa0, "SSC mode", T1, Number),
+            ie(0x28, "5GSM capability", Tlv, Raw),
+            ie(
+                0x55,
+                "maximum number of supported packet filters",
+                Tv(3),
+                Raw,
+            ),
+            ie(0xb0, "always-on PDU session requested", T1, Number),
+            ie(0x39, "SM PDU DN request container", Tlv, Raw),
+            EPCO,
+            ie(0x66, "IP header compression configuration", Tlv, Raw),
+            ie(0x1f, "ethernet header compression configuration", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0xc2,
+        name: "PDU session establishment accept",
+        mandatory: &[
+            ie(
+                0,
+                "selected PDU session type",
+                Half,
+                Names(PDU_SESSION_TYPES),
+            ),
+            ie(0, "selected SSC mode", Half, Number),
+            ie(0, "authorized QoS rules", LvE, Raw),
+            ie(0, "session AMBR", Lv, Raw),
+        ],
+        optional: &[
+            OPTIONAL_FIVEGSM_CAUSE,
+            ie(0x29, "PDU address", Tlv, Raw),
+            ie(0x56, "RQ timer value", Tv(2), Raw),
+            ie(0x22, "S-NSSAI", Tlv, Raw),
+            ie(0x80, "always-on PDU session indication", T1, Number),
+            ie(0x75, "mapped EPS bearer contexts", TlvE, Raw),
+            EAP_MESSAGE,
+            ie(0x79, "authorized QoS flow descriptions", TlvE, Raw),
+            EPCO,
+            ie(0x25, "DNN", Tlv, Apn),
+            ie(0x17, "5GSM network feature support", Tlv, Raw),
+            ie(0x18, "serving PLMN rate control", Tlv, Raw),
+            ie(0x77, "ATSSS container", TlvE, Raw),
+            ie(0xc0, "control plane only indication", T1, Number),
+            ie(0x66, "IP header compression configuration", Tlv, Raw),
+            ie(0x1f, "ethernet header compression configuration", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0xc3,
+        name: "PDU session establishment reject",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[
+            BACK_OFF_TIMER,
+            ie(0xf0, "allowed SSC mode", T1, Number),
+            EAP_MESSAGE,
+            ie(0x61, "5GSM congestion re-attempt indicator", Tlv, Raw),
+            EPCO,
+            ie(0x1d, "re-attempt indicator", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0xc9,
+        name: "PDU session modification request",
+        mandatory: &[],
+        optional: &[
+            ie(0x28, "5GSM capability", Tlv, Raw),
+            OPTIONAL_FIVEGSM_CAUSE,
+            ie(
+                0x55,
+                "maximum number of supported packet filters",
+                Tv(3),
+                Raw,
+            ),
+            ie(0xb0, "always-on PDU session requested", T1, Number),
+            ie(0x13, "integrity protection maximum data rate", Tv(3), Raw),
+            ie(0x7a, "requested QoS rules", TlvE, Raw),
+            ie(0x79, "requested QoS flow descriptions", TlvE, Raw),
+            ie(0x75, "mapped EPS bearer contexts", TlvE, Raw),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xca,
+        name: "PDU session modification reject",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[BACK_OFF_TIMER, EPCO],
+    },
+    Message {
+        message_type: 0xcb,
+        name: "PDU session modification command",
+        mandatory: &[],
+        optional: &[
+            OPTIONAL_FIVEGSM_CAUSE,
+            ie(0x2a, "session AMBR", Tlv, Raw),
+            ie(0x56, "RQ timer value", Tv(2), Raw),
+            ie(0x80, "always-on PDU session indication", T1, Number),
+            ie(0x7a, "authorized QoS rules", TlvE, Raw),
+            ie(0x75, "mapped EPS bearer contexts", TlvE, Raw),
+            ie(0x79, "authorized QoS flow descriptions", TlvE, Raw),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xcc,
+        name: "PDU session modification complete",
+        mandatory: &[],
+        optional: &[EPCO],
+    },
+    Message {
+        message_type: 0xcd,
+        name: "PDU session modification command reject",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[EPCO],
+    },
+    Message {
+        message_type: 0xd1,
+        name: "PDU session release request",
+        mandatory: &[],
+        optional: &[OPTIONAL_FIVEGSM_CAUSE, EPCO],
+    },
+    Message {
+        message_type: 0xd2,
+        name: "PDU session release reject",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[EPCO],
+    },
+    Message {
+        message_type: 0xd3,
+        name: "PDU session release command",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[
+            BACK_OFF_TIMER,
+            EAP_MESSAGE,
+            ie(0x61, "5GSM congestion re-attempt indicator", Tlv, Raw),
+            EPCO,
+            ie(0xd0, "access type", T1, Number),
+        ],
+    },
+    Message {
+        message_type: 0xd4,
+        name: "PDU session release complete",
+        mandatory: &[],
+        optional: &[OPTIONAL_FIVEGSM_CAUSE, EPCO],
+    },
+    Message {
+        message_type: 0xd6,
+        name: "5GSM status",
+        mandatory: &[FIVEGSM_CAUSE],
+        optional: &[],
+    },
+];
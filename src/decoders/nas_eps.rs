@@ -0,0 +1,573 @@
+//! EMM and ESM messages (3GPP TS 24.301 chapter 8)
+
+use super::nas::Format::{Half, Lv, LvE, Tlv, TlvE, Tv, T1, V};
+use super::nas::Kind::{Apn, EpsAlgorithms, EpsIdentity, Names, Nas, Number, Raw, Spare};
+use super::nas::{ie, Ie, Message};
+
+const EMM_CAUSES: &[(u8, &str)] = &[
+    (2, "IMSI unknown in HSS"),
+    (3, "illegal UE"),
+    (5, "IMEI not accepted"),
+    (6, "illegal ME"),
+    (7, "EPS services not allowed"),
+    (8, "EPS services and non-EPS services not allowed"),
+    (9, "UE identity cannot be derived by the network"),
+    (10, "implicitly detached"),
+    (11, "PLMN not allowed"),
+    (12, "tracking area not allowed"),
+    (13, "roaming not allowed in this tracking area"),
+    (14, "EPS services not allowed in this PLMN"),
+    (15, "no suitable cells in tracking area"),
+    (17, "network failure"),
+    (18, "CS domain not available"),
+    (19, "ESM failure"),
+    (20, "MAC failure"),
+    (21, "synch failure"),
+    (22, "congestion"),
+    (23, "UE security capabilities mismatch"),
+    (24, "security mode rejected, unspecified"),
+    (26, "non-EPS authentication unacceptable"),
+    (35, "requested service option not authorized in this PLMN"),
+    (40, "no EPS bearer context activated"),
+    (42, "severe network failure"),
+    (95, "semantically incorrect message"),
+    (96, "invalid mandatory information"),
+    (97, "message type non-existent or not implemented"),
+    (99, "information element non-existent or not implemented"),
+    (111, "protocol error, unspecified"),
+];
+
+const ESM_CAUSES: &[(u8, &str)] = &[
+    (8, "operator determined barring"),
+    (26, "insufficient resources"),
+    (27, "missing or unknown APN"),
+    (28, "unknown PDN type"),
+    (29, "user authentication failed"),
+    (30, "request rejected by serving GW or PDN GW"),
+    (31, "request rejected, unspecified"),
+    (32, "service option not supported"),
+    (33, "requested service option not subscribed"),
+    (35, "PTI already in use"),
+    (36, "regular deactivation"),
+    (37, "EPS QoS not accepted"),
+    (38, "network failure"),
+    (39, "reactivation requested"),
+    (43, "invalid EPS bearer identity"),
+    (50, "PDN type IPv4 only allowed"),
+    (51, "PDN type IPv6 only allowed"),
+    (53, "ESM information not received"),
+    (54, "PDN connection does not exist"),
+    (55, "multiple PDN connections for a given APN not allowed"),
+    (65, "maximum number of EPS bearers reached"),
+    (81, "invalid PTI value"),
+    (95, "semantically incorrect message"),
+    (96, "invalid mandatory information"),
+    (111, "protocol error, unspecified"),
+];
+
+const ATTACH_TYPES: &[(u8, &str)] = &[
+    (1, "EPS attach"),
+    (2, "combined EPS/IMSI attach"),
+    (6, "EPS emergency attach"),
+];
+
+const ATTACH_RESULTS: &[(u8, &str)] = &[(1, "EPS only"), (2, "combined EPS/IMSI attach")];
+
+const UPDATE_TYPES: &[(u8, &str)] = &[
+    (0, "TA updating"),
+    (1, "combined TA/LA updating"),
+    (2, "combined TA/LA updating with IMSI attach"),
+    (3, "periodic updating"),
+];
+
+const IDENTITY_TYPES: &[(u8, &str)] = &[(1, "IMSI"), (2, "IMEI"), (3, "IMEISV"), (4, "TMSI")];
+
+const PDN_TYPES: &[(u8, &str)] = &[(1, "IPv4"), (2, "IPv6"), (3, "IPv4v6"), (5, "non IP")];
+
+const REQUEST_TYPES: &[(u8, &str)] = &[(1, "initial request"), (2, "handover"), (4, "emergency")];
+
+const SERVICE_TYPES: &[(u8, &str)] = &[
+    (0, "mobile originating CS fallback"),
+    (1, "mobile terminating CS fallback"),
+    (2, "mobile originating CS fallback emergency call"),
+    (8, "packet services via S1"),
+];
+
+const NAS_KSI: Ie = ie(0, "NAS key set identifier", Half, Number);
+const SPARE: Ie = ie(0, "spare", Half, Spare);
+const EMM_CAUSE: Ie = ie(0, "EMM cause", V(1), Names(EMM_CAUSES));
+const ESM_CAUSE: Ie = ie(0, "ESM cause", V(1), Names(ESM_CAUSES));
+const PCO: Ie = ie(0x27, "protocol configuration options", Tlv, Raw);
+const EPCO: Ie = ie(0x7b, "extended protocol configuration options", TlvE, Raw);
+const T3346: Ie = ie(0x5f, "T3346 value", Tlv, Raw);
+const T3412_EXTENDED: Ie = ie(0x5e, "T3412 extended value", Tlv, Raw);
+const T3324: Ie = ie(0x6a, "T3324 value", Tlv, Raw);
+const EXTENDED_DRX: Ie = ie(0x6e, "extended DRX parameters", Tlv, Raw);
+
+pub static EMM_MESSAGES: &[Message] = &[
+    Message {
+        message_type: 0x41,
+        name: "Attach request",
+        mandatory: &[
+            ie(0, "EPS attach type", Half, Names(ATTACH_TYPES)),
+            NAS_KSI,
+            ie(0, "EPS mobile identity", Lv, EpsIdentity),
+            ie(0, "UE network capability", Lv, Raw),
+            ie(0, "ESM message container", LvE, Nas),
+        ],
+        optional: &[
+            ie(0x19, "old P-TMSI signature", Tv(4), Raw),
+            ie(0x50, "additional GUTI", Tlv, EpsIdentity),
+            ie(0x52, "last visited registered TAI", Tv(6), Raw),
+            ie(0x5c, "DRX parameter", Tv(3), Raw),
+            ie(0x31, "MS network capability", Tlv, Raw),
+            ie(0x13, "old location area identification", Tv(6), Raw),
+            ie(0x90, "TMSI status", T1, Number),
+            ie(0x11, "mobile station classmark 2", Tlv, Raw),
+            ie(0x20, "mobile station classmark 3", Tlv, Raw),
+            ie(0x40, "supported codecs", Tlv, Raw),
+            ie(0xf0, "additional update type", T1, Number),
+            ie(
+                0x5d,
+                "voice domain preference and UE's usage setting",
+                Tlv,
+                Raw,
+            ),
+            ie(0xd0, "device properties", T1, Number),
+            ie(0xe0, "old GUTI type", T1, Number),
+            ie(0xc0, "MS network feature support", T1, Number),
+            ie(0x10, "TMSI based NRI container", Tlv, Raw),
+            T3324,
+            T3412_EXTENDED,
+            EXTENDED_DRX,
+            ie(0x6f, "UE additional security capability", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x42,
+        name: "Attach accept",
+        mandatory: &[
+            ie(0, "EPS attach result", Half, Names(ATTACH_RESULTS)),
+            SPARE,
+            ie(0, "T3412 value", V(1), Raw),
+            ie(0, "TAI list", Lv, Raw),
+            ie(0, "ESM message container", LvE, Nas),
+        ],
+        optional: &[
+            ie(0x50, "GUTI", Tlv, EpsIdentity),
+            ie(0x13, "location area identification", Tv(6), Raw),
+            ie(0x23, "MS identity", Tlv, EpsIdentity),
+            ie(0x53, "EMM cause", Tv(2), Names(EMM_CAUSES)),
+            ie(0x17, "T3402 value", Tv(2), Raw),
+            ie(0x59, "T3423 value", Tv(2), Raw),
+            ie(0x4a, "equivalent PLMNs", Tlv, Raw),
+            ie(0x34, "emergency number list", Tlv, Raw),
+            ie(0x64, "EPS network feature support", Tlv, Raw),
+            ie(0xf0, "additional update result", T1, Number),
+            T3412_EXTENDED,
+            T3324,
+            EXTENDED_DRX,
+        ],
+    },
+    Message {
+        message_type: 0x43,
+        name: "Attach complete",
+        mandatory: &[ie(0, "ESM message container", LvE, Nas)],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x44,
+        name: "Attach reject",
+        mandatory: &[EMM_CAUSE],
+        optional: &[
+            ie(0x78, "ESM message container", TlvE, Nas),
+            T3346,
+            ie(0x16, "T3402 value", Tlv, Raw),
+            ie(0xa0, "extended EMM cause", T1, Number),
+        ],
+    },
+    Message {
+        message_type: 0x45,
+        name: "Detach request",
+        mandatory: &[ie(0, "detach type", Half, Number), NAS_KSI],
+        optional: &[
+            // only sent by the UE
+            ie(0, "EPS mobile identity", Lv, EpsIdentity),
+            ie(0x53, "EMM cause", Tv(2), Names(EMM_CAUSES)),
+        ],
+    },
+    Message {
+        message_type: 0x46,
+        name: "Detach accept",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x48,
+        name: "Tracking area update request",
+        mandatory: &[
+            ie(0, "EPS update type", Half, Names(UPDATE_TYPES)),
+            NAS_KSI,
+            ie(0, "old GUTI", Lv, EpsIdentity),
+        ],
+        optional: &[
+            ie(
+                0xb0,
+                "non-current native NAS key set identifier",
+                T1,
+                Number,
+            ),
+            ie(0x80, "GPRS ciphering key sequence number", T1, Number),
+            ie(0x19, "old P-TMSI signature", Tv(4), Raw),
+            ie(0x50, "additional GUTI", Tlv, EpsIdentity),
+            ie(0x55, "NonceUE", Tv(5), Raw),
+            ie(0x58, "UE network capability", Tlv, Raw),
+            ie(0x52, "last visited registered TAI", Tv(6), Raw),
+            ie(0x5c, "DRX parameter", Tv(3), Raw),
+            ie(
+                0xa0,
+                "UE radio capability information update needed",
+                T1,
+                Number,
+            ),
+            ie(0x57, "EPS bearer context status", Tlv, Raw),
+            ie(0x31, "MS network capability", Tlv, Raw),
+            ie(0x13, "old location area identification", Tv(6), Raw),
+            ie(0x90, "TMSI status", T1, Number),
+            ie(0x11, "mobile station classmark 2", Tlv, Raw),
+            ie(0x20, "mobile station classmark 3", Tlv, Raw),
+            ie(0x40, "supported codecs", Tlv, Raw),
+            ie(0xf0, "additional update type", T1, Number),
+            ie(
+                0x5d,
+                "voice domain preference and UE's usage setting",
+                Tlv,
+                Raw,
+            ),
+            ie(0xe0, "old GUTI type", T1, Number),
+            ie(0xd0, "device properties", T1, Number),
+            ie(0xc0, "MS network feature support", T1, Number),
+            ie(0x10, "TMSI based NRI container", Tlv, Raw),
+            T3324,
+            T3412_EXTENDED,
+            EXTENDED_DRX,
+        ],
+    },
+    Message {
+        message_type: 0x49,
+        name: "Tracking area update accept",
+        mandatory: &[ie(0, "EPS update result", Half, Number), SPARE],
+        optional: &[
+            ie(0x5a, "T3412 value", Tv(2), Raw),
+            ie(0x50, "GUTI", Tlv, EpsIdentity),
+            ie(0x54, "TAI list", Tlv, Raw),
+            ie(0x57, "EPS bearer context status", Tlv, Raw),
+            ie(0x13, "location area identification", Tv(6), Raw),
+            ie(0x23, "MS identity", Tlv, EpsIdentity),
+            ie(0x53, "EMM cause", Tv(2), Names(EMM_CAUSES)),
+            ie(0x17, "T3402 value", Tv(2), Raw),
+            ie(0x59, "T3423 value", Tv(2), Raw),
+            ie(0x4a, "equivalent PLMNs", Tlv, Raw),
+            ie(0x34, "emergency number list", Tlv, Raw),
+            ie(0x64, "EPS network feature support", Tlv, Raw),
+            ie(0xf0, "additional update result", T1, Number),
+            T3412_EXTENDED,
+            T3324,
+            EXTENDED_DRX,
+        ],
+    },
+    Message {
+        message_type: 0x4a,
+        name: "Tracking area update complete",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x4b,
+        name: "Tracking area update reject",
+        mandatory: &[EMM_CAUSE],
+        optional: &[T3346, ie(0xa0, "extended EMM cause", T1, Number)],
+    },
+    Message {
+        message_type: 0x4c,
+        name: "Extended service request",
+        mandatory: &[
+            ie(0, "service type", Half, Names(SERVICE_TYPES)),
+            NAS_KSI,
+            ie(0, "M-TMSI", Lv, EpsIdentity),
+        ],
+        optional: &[
+            ie(0xb0, "CSFB response", T1, Number),
+            ie(0x57, "EPS bearer context status", Tlv, Raw),
+            ie(0xd0, "device properties", T1, Number),
+        ],
+    },
+    Message {
+        message_type: 0x4e,
+        name: "Service reject",
+        mandatory: &[EMM_CAUSE],
+        optional: &[ie(0x5b, "T3442 value", Tv(2), Raw), T3346],
+    },
+    Message {
+        message_type: 0x50,
+        name: "GUTI reallocation command",
+        mandatory: &[ie(0, "GUTI", Lv, EpsIdentity)],
+        optional: &[ie(0x54, "TAI list", Tlv, Raw)],
+    },
+    Message {
+        message_type: 0x51,
+        name: "GUTI reallocation complete",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x52,
+        name: "Authentication request",
+        mandatory: &[
+            NAS_KSI,
+            SPARE,
+            ie(0, "RAND", V(16), Raw),
+            ie(0, "AUTN", Lv, Raw),
+        ],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x53,
+        name: "Authentication response",
+        mandatory: &[ie(0, "RES", Lv, Raw)],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x54,
+        name: "Authentication reject",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x5c,
+        name: "Authentication failure",
+        mandatory: &[EMM_CAUSE],
+        optional: &[ie(0x30, "authentication failure parameter", Tlv, Raw)],
+    },
+    Message {
+        message_type: 0x55,
+        name: "Identity request",
+        mandatory: &[ie(0, "identity type", Half, Names(IDENTITY_TYPES)), SPARE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x56,
+        name: "Identity response",
+        mandatory: &[ie(0, "mobile identity", Lv, EpsIdentity)],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x5d,
+        name: "Security mode command",
+        mandatory: &[
+            ie(0, "selected NAS security algorithms", V(1), EpsAlgorithms),
+            NAS_KSI,
+            SPARE,
+            ie(0, "replayed UE security capabilities", Lv, Raw),
+        ],
+        optional: &[
+            ie(0xc0, "IMEISV request", T1, Number),
+            ie(0x55, "replayed NonceUE", Tv(5), Raw),
+            ie(0x56, "NonceMME", Tv(5), Raw),
+            ie(0x4f, "HashMME", Tlv, Raw),
+            ie(0x6f, "replayed UE additional security capability", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x5e,
+        name: "Security mode complete",
+        mandatory: &[],
+        optional: &[
+            ie(0x23, "IMEISV", Tlv, EpsIdentity),
+            ie(0x79, "replayed NAS message container", TlvE, Nas),
+        ],
+    },
+    Message {
+        message_type: 0x5f,
+        name: "Security mode reject",
+        mandatory: &[EMM_CAUSE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x60,
+        name: "EMM status",
+        mandatory: &[EMM_CAUSE],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x61,
+        name: "EMM information",
+        mandatory: &[],
+        optional: &[
+            ie(0x43, "full name for network", Tlv, Raw),
+            ie(0x45, "short name for network", Tlv, Raw),
+            ie(0x46, "local time zone", Tv(2), Raw),
+            ie(0x47, "universal time and local time zone", Tv(8), Raw),
+            ie(0x49, "network daylight saving time", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0x62,
+        name: "Downlink NAS transport",
+        mandatory: &[ie(0, "NAS message container", Lv, Raw)],
+        optional: &[],
+    },
+    Message {
+        message_type: 0x63,
+        name: "Uplink NAS transport",
+        mandatory: &[ie(0, "NAS message container", Lv, Raw)],
+        optional: &[],
+    },
+];
+
+pub static ESM_MESSAGES: &[Message] = &[
+    Message {
+        message_type: 0xc1,
+        name: "Activate default EPS bearer context request",
+        mandatory: &[
+            ie(0, "EPS QoS", Lv, Raw),
+            ie(0, "access point name", Lv, Apn),
+            ie(0, "PDN address", Lv, Raw),
+        ],
+        optional: &[
+            ie(0x5d, "transaction identifier", Tlv, Raw),
+            ie(0x30, "negotiated QoS", Tlv, Raw),
+            ie(0x32, "negotiated LLC SAPI", Tv(2), Raw),
+            ie(0x80, "radio priority", T1, Number),
+            ie(0x34, "packet flow identifier", Tlv, Raw),
+            ie(0x5e, "APN-AMBR", Tlv, Raw),
+            ie(0x58, "ESM cause", Tv(2), Names(ESM_CAUSES)),
+            PCO,
+            ie(0xb0, "connectivity type", T1, Number),
+            ie(0xc0, "WLAN offload indication", T1, Number),
+            ie(0x66, "header compression configuration", Tlv, Raw),
+            ie(0x90, "control plane only indication", T1, Number),
+            EPCO,
+            ie(0x6e, "serving PLMN rate control", Tlv, Raw),
+            ie(0x5f, "extended APN-AMBR", Tlv, Raw),
+        ],
+    },
+    Message {
+        message_type: 0xc2,
+        name: "Activate default EPS bearer context accept",
+        mandatory: &[],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xc3,
+        name: "Activate default EPS bearer context reject",
+        mandatory: &[ESM_CAUSE],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xc5,
+        name: "Activate dedicated EPS bearer context request",
+        mandatory: &[
+            ie(0, "linked EPS bearer identity", Half, Number),
+            SPARE,
+            ie(0, "EPS QoS", Lv, Raw),
+            ie(0, "TFT", Lv, Raw),
+        ],
+        optional: &[
+            ie(0x5d, "transaction identifier", Tlv, Raw),
+            ie(0x30, "negotiated QoS", Tlv, Raw),
+            ie(0x32, "negotiated LLC SAPI", Tv(2), Raw),
+            ie(0x80, "radio priority", T1, Number),
+            ie(0x34, "packet flow identifier", Tlv, Raw),
+            PCO,
+            ie(0xc0, "WLAN offload indication", T1, Number),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xc6,
+        name: "Activate dedicated EPS bearer context accept",
+        mandatory: &[],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xc7,
+        name: "Activate dedicated EPS bearer context reject",
+        mandatory: &[ESM_CAUSE],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xcd,
+        name: "Deactivate EPS bearer context request",
+        mandatory: &[ESM_CAUSE],
+        optional: &[
+            PCO,
+            ie(0x37, "T3396 value", Tlv, Raw),
+            ie(0xc0, "WLAN offload indication", T1, Number),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xce,
+        name: "Deactivate EPS bearer context accept",
+        mandatory: &[],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xd0,
+        name: "PDN connectivity request",
+        mandatory: &[
+            ie(0, "request type", Half, Names(REQUEST_TYPES)),
+            ie(0, "PDN type", Half, Names(PDN_TYPES)),
+        ],
+        optional: &[
+            ie(0xd0, "ESM information transfer flag", T1, Number),
+            ie(0x28, "access point name", Tlv, Apn),
+            PCO,
+            ie(0xc0, "device properties", T1, Number),
+            ie(0x66, "header compression configuration", Tlv, Raw),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xd1,
+        name: "PDN connectivity reject",
+        mandatory: &[ESM_CAUSE],
+        optional: &[
+            PCO,
+            ie(0x37, "back-off timer value", Tlv, Raw),
+            ie(0x6b, "re-attempt indicator", Tlv, Raw),
+            EPCO,
+        ],
+    },
+    Message {
+        message_type: 0xd2,
+        name: "PDN disconnect request",
+        mandatory: &[ie(0, "linked EPS bearer identity", Half, Number), SPARE],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xd3,
+        name: "PDN disconnect reject",
+        mandatory: &[ESM_CAUSE],
+        optional: &[PCO, EPCO],
+    },
+    Message {
+        message_type: 0xd9,
+        name: "ESM information request",
+        mandatory: &[],
+        optional: &[],
+    },
+    Message {
+        message_type: 0xda,
+        name: "ESM information response",
+        mandatory: &[],
+        optional: &[ie(0x28, "access point name", Tlv, Apn), PCO, EPCO],
+    },
+    Message {
+        message_type: 0xe8,
+        name: "ESM status",
+        mandatory: &[ESM_CAUSE],
+        optional: &[],
+    },
+];
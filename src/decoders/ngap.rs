@@ -0,0 +1,924 @@
+//! Subset of the NGAP IEs (3GPP TS 38.413)
+
+use super::ap::{ie, procedure, protocol_ies, raw, Ie, Procedure, Protocol, IE_EXTENSIONS};
+use super::per::{mandatory, optional, Size, Type, UNBOUNDED};
+use super::Node;
+
+pub static PROTOCOL: Protocol = Protocol {
+    name: "NGAP",
+    procedures: PROCEDURES,
+    ies: IES,
+};
+
+static PROCEDURES: &[Procedure] = &[
+    procedure(
+        0,
+        "AMFConfigurationUpdate",
+        "AMFConfigurationUpdateAcknowledge",
+        "AMFConfigurationUpdateFailure",
+    ),
+    procedure(1, "AMFStatusIndication", "", ""),
+    procedure(2, "CellTrafficTrace", "", ""),
+    procedure(3, "DeactivateTrace", "", ""),
+    procedure(4, "DownlinkNASTransport", "", ""),
+    procedure(5, "DownlinkNonUEAssociatedNRPPaTransport", "", ""),
+    procedure(6, "DownlinkRANConfigurationTransfer", "", ""),
+    procedure(7, "DownlinkRANStatusTransfer", "", ""),
+    procedure(8, "DownlinkUEAssociatedNRPPaTransport", "", ""),
+    procedure(9, "ErrorIndication", "", ""),
+    procedure(10, "HandoverCancel", "HandoverCancelAcknowledge", ""),
+    procedure(11, "HandoverNotify", "", ""),
+    procedure(
+        12,
+        "HandoverRequired",
+        "HandoverCommand",
+        "HandoverPreparationFailure",
+    ),
+    procedure(
+        13,
+        "HandoverRequest",
+        "HandoverRequestAcknowledge",
+        "HandoverFailure",
+    ),
+    procedure(
+        14,
+        "InitialContextSetupRequest",
+        "InitialContextSetupResponse",
+        "InitialContextSetupFailure",
+    ),
+    procedure(15, "InitialUEMessage", "", ""),
+    procedure(16, "LocationReportingControl", "", ""),
+    procedure(17, "LocationReportingFailureIndication", "", ""),
+    procedure(18, "LocationReport", "", ""),
+    procedure(19, "NASNonDeliveryIndication", "", ""),
+    procedure(20, "NGReset", "NGResetAcknowledge", ""),
+    procedure(21, "NGSetupRequest", "NGSetupResponse", "NGSetupFailure"),
+    procedure(22, "OverloadStart", "", ""),
+    procedure(23, "OverloadStop", "", ""),
+    procedure(24, "Paging", "", ""),
+    procedure(
+        25,
+        "PathSwitchRequest",
+        "PathSwitchRequestAcknowledge",
+        "PathSwitchRequestFailure",
+    ),
+    procedure(
+        26,
+        "PDUSessionResourceModifyRequest",
+        "PDUSessionResourceModifyResponse",
+        "",
+    ),
+    procedure(
+        27,
+        "PDUSessionResourceModifyIndication",
+        "PDUSessionResourceModifyConfirm",
+        "",
+    ),
+    procedure(
+        28,
+        "PDUSessionResourceReleaseCommand",
+        "PDUSessionResourceReleaseResponse",
+        "",
+    ),
+    procedure(
+        29,
+        "PDUSessionResourceSetupRequest",
+        "PDUSessionResourceSetupResponse",
+        "",
+    ),
+    procedure(30, "PDUSessionResourceNotify", "", ""),
+    procedure(31, "PrivateMessage", "", ""),
+    procedure(32, "PWSCancelRequest", "PWSCancelResponse", ""),
+    procedure(33, "PWSFailureIndication", "", ""),
+    procedure(34, "PWSRestartIndication", "", ""),
+    procedure(
+        35,
+        "RANConfigurationUpdate",
+        "RANConfigurationUpdateAcknowledge",
+        "RANConfigurationUpdateFailure",
+    ),
+    procedure(36, "RerouteNASRequest", "", ""),
+    procedure(37, "RRCInactiveTransitionReport", "", ""),
+    procedure(38, "TraceFailureIndication", "", ""),
+    procedure(39, "TraceStart", "", ""),
+    procedure(
+        40,
+        "UEContextModificationRequest",
+        "UEContextModificationResponse",
+        "UEContextModificationFailure",
+    ),
+    procedure(
+        41,
+        "UEContextReleaseCommand",
+        "UEContextReleaseComplete",
+        "",
+    ),
+    procedure(42, "UEContextReleaseRequest", "", ""),
+    procedure(
+        43,
+        "UERadioCapabilityCheckRequest",
+        "UERadioCapabilityCheckResponse",
+        "",
+    ),
+    procedure(44, "UERadioCapabilityInfoIndication", "", ""),
+    procedure(45, "UETNLABindingReleaseRequest", "", ""),
+    procedure(46, "UplinkNASTransport", "", ""),
+    procedure(47, "UplinkNonUEAssociatedNRPPaTransport", "", ""),
+    procedure(48, "UplinkRANConfigurationTransfer", "", ""),
+    procedure(49, "UplinkRANStatusTransfer", "", ""),
+    procedure(50, "UplinkUEAssociatedNRPPaTransport", "", ""),
+    procedure(
+        51,
+        "WriteReplaceWarningRequest",
+        "WriteReplaceWarningResponse",
+        "",
+    ),
+    procedure(52, "SecondaryRATDataUsageReport", "", ""),
+    procedure(53, "UplinkRIMInformationTransfer", "", ""),
+    procedure(54, "DownlinkRIMInformationTransfer", "", ""),
+    procedure(55, "RetrieveUEInformation", "", ""),
+    procedure(56, "UEInformationTransfer", "", ""),
+    procedure(57, "RANCPRelocationIndication", "", ""),
+    procedure(
+        58,
+        "UEContextResumeRequest",
+        "UEContextResumeResponse",
+        "UEContextResumeFailure",
+    ),
+    procedure(
+        59,
+        "UEContextSuspendRequest",
+        "UEContextSuspendResponse",
+        "UEContextSuspendFailure",
+    ),
+    procedure(
+        60,
+        "UERadioCapabilityIDMappingRequest",
+        "UERadioCapabilityIDMappingResponse",
+        "",
+    ),
+    procedure(61, "HandoverSuccess", "", ""),
+    procedure(62, "UplinkRANEarlyStatusTransfer", "", ""),
+    procedure(63, "DownlinkRANEarlyStatusTransfer", "", ""),
+    procedure(64, "AMFCPRelocationIndication", "", ""),
+    procedure(65, "ConnectionEstablishmentIndication", "", ""),
+];
+
+/// Transfer IEs of the PDU session resource lists, containers of IEs
+fn transfer(bytes: &[u8]) -> Vec<Node> {
+    protocol_ies(IES, bytes)
+}
+
+static AMF_UE_NGAP_ID: Type = Type::Integer(0, 1099511627775);
+static RAN_UE_NGAP_ID: Type = Type::Integer(0, 4294967295);
+static NAS_PDU: Type = Type::External(UNBOUNDED, super::nas::decode_container);
+static PLMN_IDENTITY: Type = Type::OctetString(Size(3, Some(3)));
+static TAC: Type = Type::OctetString(Size(3, Some(3)));
+static BIT_RATE: Type = Type::IntegerExt(0, 4000000000000);
+static PDU_SESSION_ID: Type = Type::Integer(0, 255);
+static QOS_FLOW_IDENTIFIER: Type = Type::IntegerExt(0, 63);
+static ALGORITHMS: Type = Type::SizeExt(&Type::BitString(Size(16, Some(16))));
+static AMF_SET_ID: Type = Type::BitString(Size(10, Some(10)));
+static AMF_POINTER: Type = Type::BitString(Size(6, Some(6)));
+static CHOICE_EXTENSIONS: Type = Type::Unsupported;
+
+static CAUSE: Type = Type::Choice(
+    &[
+        (
+            "radioNetwork",
+            &Type::EnumeratedExt(
+                &[
+                    "unspecified",
+                    "txnrelocoverall-expiry",
+                    "successful-handover",
+                    "release-due-to-ngran-generated-reason",
+                    "release-due-to-5gc-generated-reason",
+                    "handover-cancelled",
+                    "partial-handover",
+                    "ho-failure-in-target-5GC-ngran-node-or-target-system",
+                    "ho-target-not-allowed",
+                    "tngrelocoverall-expiry",
+                    "tngrelocprep-expiry",
+                    "cell-not-available",
+                    "unknown-targetID",
+                    "no-radio-resources-available-in-target-cell",
+                    "unknown-local-UE-NGAP-ID",
+                    "inconsistent-remote-UE-NGAP-ID",
+                    "handover-desirable-for-radio-reason",
+                    "time-critical-handover",
+                    "resource-optimisation-handover",
+                    "reduce-load-in-serving-cell",
+                    "user-inactivity",
+                    "radio-connection-with-ue-lost",
+                    "radio-resources-not-available",
+                    "invalid-qos-combination",
+                    "failure-in-radio-interface-procedure",
+                    "interaction-with-other-procedure",
+                    "unknown-PDU-session-ID",
+                    "unkown-qos-flow-ID",
+                    "multiple-PDU-session-ID-instances",
+                    "multiple-qos-flow-ID-instances",
+                    "encryption-and-or-integrity-protection-algorithms-not-supported",
+                    "ng-intra-system-handover-triggered",
+                    "ng-inter-system-handover-triggered",
+                    "xn-handover-triggered",
+                    "not-supported-5QI-value",
+                    "ue-context-transfer",
+                    "ims-voice-eps-fallback-or-rat-fallback-triggered",
+                    "up-integrity-protection-not-possible",
+                    "up-confidentiality-protection-not-possible",
+                    "slice-not-supported",
+                    "ue-in-rrc-inactive-state-not-reachable",
+                    "redirection",
+                    "resources-not-available-for-the-slice",
+                    "ue-max-integrity-protected-data-rate-reason",
+                    "release-due-to-cn-detected-mobility",
+                ],
+                &["n26-interface-not-available", "release-due-to-pre-emption"],
+            ),
+        ),
+        (
+            "transport",
+            &Type::EnumeratedExt(&["transport-resource-unavailable", "unspecified"], &[]),
+        ),
+        (
+            "nas",
+            &Type::EnumeratedExt(
+                &[
+                    "normal-release",
+                    "authentication-failure",
+                    "deregister",
+                    "unspecified",
+                ],
+                &[],
+            ),
+        ),
+        (
+            "protocol",
+            &Type::EnumeratedExt(
+                &[
+                    "transfer-syntax-error",
+                    "abstract-syntax-error-reject",
+                    "abstract-syntax-error-ignore-and-notify",
+                    "message-not-compatible-with-receiver-state",
+                    "semantic-error",
+                    "abstract-syntax-error-falsely-constructed-message",
+                    "unspecified",
+                ],
+                &[],
+            ),
+        ),
+        (
+            "misc",
+            &Type::EnumeratedExt(
+                &[
+                    "control-processing-overload",
+                    "not-enough-user-plane-processing-resources",
+                    "hardware-failure",
+                    "om-intervention",
+                    "unknown-PLMN-or-SNPN",
+                    "unspecified",
+                ],
+                &[],
+            ),
+        ),
+        ("choice-Extensions", &CHOICE_EXTENSIONS),
+    ],
+    false,
+);
+
+static TAI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+        mandatory!("tAC", &TAC),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static NR_CGI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+        mandatory!("nRCellIdentity", &Type::BitString(Size(36, Some(36)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static EUTRA_CGI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+        mandatory!("eUTRACellIdentity", &Type::BitString(Size(28, Some(28)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static USER_LOCATION_INFORMATION: Type = Type::Choice(
+    &[
+        (
+            "userLocationInformationEUTRA",
+            &Type::Sequence(
+                &[
+                    mandatory!("eUTRA-CGI", &EUTRA_CGI),
+                    mandatory!("tAI", &TAI),
+                    optional!("timeStamp", &Type::OctetString(Size(4, Some(4)))),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        (
+            "userLocationInformationNR",
+            &Type::Sequence(
+                &[
+                    mandatory!("nR-CGI", &NR_CGI),
+                    mandatory!("tAI", &TAI),
+                    optional!("timeStamp", &Type::OctetString(Size(4, Some(4)))),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        ("userLocationInformationN3IWF", &Type::Unsupported),
+        ("choice-Extensions", &CHOICE_EXTENSIONS),
+    ],
+    false,
+);
+
+static FIVEG_S_TMSI: Type = Type::Sequence(
+    &[
+        mandatory!("aMFSetID", &AMF_SET_ID),
+        mandatory!("aMFPointer", &AMF_POINTER),
+        mandatory!("fiveG-TMSI", &Type::OctetString(Size(4, Some(4)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static GUAMI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+        mandatory!("aMFRegionID", &Type::BitString(Size(8, Some(8)))),
+        mandatory!("aMFSetID", &AMF_SET_ID),
+        mandatory!("aMFPointer", &AMF_POINTER),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static S_NSSAI: Type = Type::Sequence(
+    &[
+        mandatory!("sST", &Type::OctetString(Size(1, Some(1)))),
+        optional!("sD", &Type::OctetString(Size(3, Some(3)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static SLICE_SUPPORT_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("s-NSSAI", &S_NSSAI),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(1024)),
+);
+
+static UE_SECURITY_CAPABILITIES: Type = Type::Sequence(
+    &[
+        mandatory!("nRencryptionAlgorithms", &ALGORITHMS),
+        mandatory!("nRintegrityProtectionAlgorithms", &ALGORITHMS),
+        mandatory!("eUTRAencryptionAlgorithms", &ALGORITHMS),
+        mandatory!("eUTRAintegrityProtectionAlgorithms", &ALGORITHMS),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+/// UEAggregateMaximumBitRate and PDUSessionAggregateMaximumBitRate
+static AGGREGATE_MAXIMUM_BIT_RATE: Type = Type::Sequence(
+    &[
+        mandatory!("maximumBitRateDL", &BIT_RATE),
+        mandatory!("maximumBitRateUL", &BIT_RATE),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static GLOBAL_RAN_NODE_ID: Type = Type::Choice(
+    &[
+        (
+            "globalGNB-ID",
+            &Type::Sequence(
+                &[
+                    mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+                    mandatory!(
+                        "gNB-ID",
+                        &Type::Choice(
+                            &[
+                                ("gNB-ID", &Type::BitString(Size(22, Some(32)))),
+                                ("choice-Extensions", &CHOICE_EXTENSIONS),
+                            ],
+                            false,
+                        )
+                    ),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        ("globalNgENB-ID", &Type::Unsupported),
+        ("globalN3IWF-ID", &Type::Unsupported),
+        ("choice-Extensions", &CHOICE_EXTENSIONS),
+    ],
+    false,
+);
+
+static SUPPORTED_TA_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("tAC", &TAC),
+            mandatory!(
+                "broadcastPLMNList",
+                &Type::SequenceOf(
+                    &Type::Sequence(
+                        &[
+                            mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+                            mandatory!("tAISliceSupportList", &SLICE_SUPPORT_LIST),
+                            optional!("iE-Extensions", &IE_EXTENSIONS),
+                        ],
+                        true,
+                    ),
+                    Size(1, Some(12)),
+                )
+            ),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(256)),
+);
+
+static PLMN_SUPPORT_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("pLMNIdentity", &PLMN_IDENTITY),
+            mandatory!("sliceSupportList", &SLICE_SUPPORT_LIST),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(12)),
+);
+
+static SERVED_GUAMI_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("gUAMI", &GUAMI),
+            optional!("backupAMFName", &Type::Unsupported),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(256)),
+);
+
+static PAGING_DRX: Type = Type::EnumeratedExt(&["v32", "v64", "v128", "v256"], &[]);
+
+static UE_NGAP_IDS: Type = Type::Choice(
+    &[
+        (
+            "uE-NGAP-ID-pair",
+            &Type::Sequence(
+                &[
+                    mandatory!("aMF-UE-NGAP-ID", &AMF_UE_NGAP_ID),
+                    mandatory!("rAN-UE-NGAP-ID", &RAN_UE_NGAP_ID),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        ("aMF-UE-NGAP-ID", &AMF_UE_NGAP_ID),
+        ("choice-Extensions", &CHOICE_EXTENSIONS),
+    ],
+    false,
+);
+
+static UP_TRANSPORT_LAYER_INFORMATION: Type = Type::Choice(
+    &[
+        (
+            "gTPTunnel",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "transportLayerAddress",
+                        &Type::SizeExt(&Type::BitString(Size(1, Some(160))))
+                    ),
+                    mandatory!("gTP-TEID", &Type::OctetString(Size(4, Some(4)))),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        ("choice-Extensions", &CHOICE_EXTENSIONS),
+    ],
+    false,
+);
+
+static QOS_FLOW_SETUP_REQUEST_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("qosFlowIdentifier", &QOS_FLOW_IDENTIFIER),
+            mandatory!(
+                "qosFlowLevelQosParameters",
+                &Type::Sequence(
+                    &[
+                        mandatory!(
+                            "qosCharacteristics",
+                            &Type::Choice(
+                                &[
+                                    (
+                                        "nonDynamic5QI",
+                                        &Type::Sequence(
+                                            &[
+                                                mandatory!("fiveQI", &Type::IntegerExt(0, 255)),
+                                                optional!(
+                                                    "priorityLevelQos",
+                                                    &Type::IntegerExt(1, 127)
+                                                ),
+                                                optional!(
+                                                    "averagingWindow",
+                                                    &Type::IntegerExt(0, 4095)
+                                                ),
+                                                optional!(
+                                                    "maximumDataBurstVolume",
+                                                    &Type::IntegerExt(0, 4095)
+                                                ),
+                                                optional!("iE-Extensions", &IE_EXTENSIONS),
+                                            ],
+                                            true,
+                                        ),
+                                    ),
+                                    ("dynamic5QI", &Type::Unsupported),
+                                    ("choice-Extensions", &CHOICE_EXTENSIONS),
+                                ],
+                                false,
+                            )
+                        ),
+                        mandatory!(
+                            "allocationAndRetentionPriority",
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("priorityLevelARP", &Type::Integer(1, 15)),
+                                    mandatory!(
+                                        "pre-emptionCapability",
+                                        &Type::EnumeratedExt(
+                                            &[
+                                                "shall-not-trigger-pre-emption",
+                                                "may-trigger-pre-emption",
+                                            ],
+                                            &[],
+                                        )
+                                    ),
+                                    mandatory!(
+                                        "pre-emptionVulnerability",
+                                        &Type::EnumeratedExt(
+                                            &["not-pre-emptable", "pre-emptable"],
+                                            &[],
+                                        )
+                                    ),
+                                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                                ],
+                                true,
+                            )
+                        ),
+                        optional!("gBR-QosInformation", &Type::Unsupported),
+                        optional!(
+                            "reflectiveQosAttribute",
+                            &Type::EnumeratedExt(&["subject-to"], &[])
+                        ),
+                        optional!(
+                            "additionalQosFlowInformation",
+                            &Type::EnumeratedExt(&["more-likely"], &[])
+                        ),
+                        optional!("iE-Extensions", &IE_EXTENSIONS),
+                    ],
+                    true,
+                )
+            ),
+            optional!("e-RAB-ID", &Type::IntegerExt(0, 15)),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(64)),
+);
+
+static SECURITY_INDICATION: Type = Type::Sequence(
+    &[
+        mandatory!(
+            "integrityProtectionIndication",
+            &Type::EnumeratedExt(&["required", "preferred", "not-needed"], &[])
+        ),
+        mandatory!(
+            "confidentialityProtectionIndication",
+            &Type::EnumeratedExt(&["required", "preferred", "not-needed"], &[])
+        ),
+        optional!(
+            "maximumIntegrityProtectedDataRate-UL",
+            &Type::EnumeratedExt(&["bitrate64kbs", "maximum-UE-rate"], &[])
+        ),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static SETUP_RESPONSE_TRANSFER: Type = Type::Sequence(
+    &[
+        mandatory!(
+            "dLQosFlowPerTNLInformation",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "uPTransportLayerInformation",
+                        &UP_TRANSPORT_LAYER_INFORMATION
+                    ),
+                    mandatory!(
+                        "associatedQosFlowList",
+                        &Type::SequenceOf(
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("qosFlowIdentifier", &QOS_FLOW_IDENTIFIER),
+                                    optional!(
+                                        "qosFlowMappingIndication",
+                                        &Type::EnumeratedExt(&["ul", "dl"], &[])
+                                    ),
+                                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                                ],
+                                true,
+                            ),
+                            Size(1, Some(64)),
+                        )
+                    ),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            )
+        ),
+        optional!("additionalDLQosFlowPerTNLInformation", &Type::Unsupported),
+        optional!("securityResult", &Type::Unsupported),
+        optional!("qosFlowFailedToSetupList", &Type::Unsupported),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+/// Transfers holding a cause: setup unsuccessful, release command
+static CAUSE_TRANSFER: Type = Type::Sequence(
+    &[
+        mandatory!("cause", &CAUSE),
+        optional!("criticalityDiagnostics", &Type::Unsupported),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static RELEASE_RESPONSE_TRANSFER: Type =
+    Type::Sequence(&[optional!("iE-Extensions", &IE_EXTENSIONS)], true);
+
+/// PDUSessionResourceSetupItemCxtReq and PDUSessionResourceSetupItemSUReq
+static SETUP_ITEM_REQ: Type = Type::Sequence(
+    &[
+        mandatory!("pDUSessionID", &PDU_SESSION_ID),
+        optional!("nAS-PDU", &NAS_PDU),
+        mandatory!("s-NSSAI", &S_NSSAI),
+        mandatory!(
+            "pDUSessionResourceSetupRequestTransfer",
+            &Type::External(UNBOUNDED, transfer)
+        ),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+/// List of items made of a PDU session ID and a transfer
+macro_rules! session_list {
+    ($transfer:literal, $ty:expr) => {
+        Type::SequenceOf(
+            &Type::Sequence(
+                &[
+                    mandatory!("pDUSessionID", &PDU_SESSION_ID),
+                    mandatory!($transfer, &Type::Containing($ty)),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+            Size(1, Some(256)),
+        )
+    };
+}
+
+static SESSION_ID_LIST: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("pDUSessionID", &PDU_SESSION_ID),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(256)),
+);
+
+static IES: &[Ie] = &[
+    ie(
+        0,
+        "AllowedNSSAI",
+        &Type::SequenceOf(
+            &Type::Sequence(
+                &[
+                    mandatory!("s-NSSAI", &S_NSSAI),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+            Size(1, Some(8)),
+        ),
+    ),
+    raw(1, "AMFName"),
+    ie(3, "AMFSetID", &AMF_SET_ID),
+    ie(10, "AMF-UE-NGAP-ID", &AMF_UE_NGAP_ID),
+    ie(15, "Cause", &CAUSE),
+    raw(18, "CoreNetworkAssistanceInformationForInactive"),
+    raw(19, "CriticalityDiagnostics"),
+    ie(21, "DefaultPagingDRX", &PAGING_DRX),
+    ie(26, "FiveG-S-TMSI", &FIVEG_S_TMSI),
+    ie(27, "GlobalRANNodeID", &GLOBAL_RAN_NODE_ID),
+    ie(28, "GUAMI", &GUAMI),
+    ie(
+        29,
+        "HandoverType",
+        &Type::EnumeratedExt(&["intra5gs", "fivegs-to-eps", "eps-to-5gs"], &[]),
+    ),
+    ie(31, "IndexToRFSP", &Type::IntegerExt(1, 256)),
+    ie(34, "MaskedIMEISV", &Type::BitString(Size(64, Some(64)))),
+    raw(36, "MobilityRestrictionList"),
+    ie(38, "NAS-PDU", &NAS_PDU),
+    ie(40, "NewAMF-UE-NGAP-ID", &AMF_UE_NGAP_ID),
+    raw(48, "OldAMF"),
+    ie(50, "PagingDRX", &PAGING_DRX),
+    ie(
+        55,
+        "PDUSessionResourceFailedToSetupListCxtRes",
+        &session_list!(
+            "pDUSessionResourceSetupUnsuccessfulTransfer",
+            &CAUSE_TRANSFER
+        ),
+    ),
+    ie(
+        58,
+        "PDUSessionResourceFailedToSetupListSURes",
+        &session_list!(
+            "pDUSessionResourceSetupUnsuccessfulTransfer",
+            &CAUSE_TRANSFER
+        ),
+    ),
+    ie(60, "PDUSessionResourceListCxtRelCpl", &SESSION_ID_LIST),
+    ie(
+        70,
+        "PDUSessionResourceReleasedListRelRes",
+        &session_list!(
+            "pDUSessionResourceReleaseResponseTransfer",
+            &RELEASE_RESPONSE_TRANSFER
+        ),
+    ),
+    ie(
+        71,
+        "PDUSessionResourceSetupListCxtReq",
+        &Type::SequenceOf(&SETUP_ITEM_REQ, Size(1, Some(256))),
+    ),
+    ie(
+        72,
+        "PDUSessionResourceSetupListCxtRes",
+        &session_list!(
+            "pDUSessionResourceSetupResponseTransfer",
+            &SETUP_RESPONSE_TRANSFER
+        ),
+    ),
+    ie(
+        74,
+        "PDUSessionResourceSetupListSUReq",
+        &Type::SequenceOf(&SETUP_ITEM_REQ, Size(1, Some(256))),
+    ),
+    ie(
+        75,
+        "PDUSessionResourceSetupListSURes",
+        &session_list!(
+            "pDUSessionResourceSetupResponseTransfer",
+            &SETUP_RESPONSE_TRANSFER
+        ),
+    ),
+    ie(
+        79,
+        "PDUSessionResourceToReleaseListRelCmd",
+        &session_list!("pDUSessionResourceReleaseCommandTransfer", &CAUSE_TRANSFER),
+    ),
+    ie(80, "PLMNSupportList", &PLMN_SUPPORT_LIST),
+    raw(82, "RANNodeName"),
+    ie(83, "RANPagingPriority", &Type::Integer(1, 256)),
+    ie(85, "RAN-UE-NGAP-ID", &RAN_UE_NGAP_ID),
+    ie(86, "RelativeAMFCapacity", &Type::Integer(0, 255)),
+    raw(88, "ResetType"),
+    ie(
+        90,
+        "RRCEstablishmentCause",
+        &Type::EnumeratedExt(
+            &[
+                "emergency",
+                "highPriorityAccess",
+                "mt-Access",
+                "mo-Signalling",
+                "mo-Data",
+                "mo-VoiceCall",
+                "mo-VideoCall",
+                "mo-SMS",
+                "mps-PriorityAccess",
+                "mcs-PriorityAccess",
+            ],
+            &["notAvailable", "mo-ExceptionData"],
+        ),
+    ),
+    ie(
+        91,
+        "RRCInactiveTransitionReportRequest",
+        &Type::EnumeratedExt(
+            &[
+                "subsequent-state-transition-report",
+                "single-rrc-connected-state-report",
+                "cancel-report",
+            ],
+            &[],
+        ),
+    ),
+    ie(
+        92,
+        "RRCState",
+        &Type::EnumeratedExt(&["inactive", "connected"], &[]),
+    ),
+    ie(94, "SecurityKey", &Type::BitString(Size(256, Some(256)))),
+    ie(96, "ServedGUAMIList", &SERVED_GUAMI_LIST),
+    ie(100, "SourceAMF-UE-NGAP-ID", &AMF_UE_NGAP_ID),
+    raw(101, "SourceToTarget-TransparentContainer"),
+    ie(102, "SupportedTAList", &SUPPORTED_TA_LIST),
+    raw(103, "TAIListForPaging"),
+    raw(105, "TargetID"),
+    raw(106, "TargetToSource-TransparentContainer"),
+    ie(
+        107,
+        "TimeToWait",
+        &Type::EnumeratedExt(&["v1s", "v2s", "v5s", "v10s", "v20s", "v60s"], &[]),
+    ),
+    raw(108, "TraceActivation"),
+    ie(
+        110,
+        "UEAggregateMaximumBitRate",
+        &AGGREGATE_MAXIMUM_BIT_RATE,
+    ),
+    ie(
+        112,
+        "UEContextRequest",
+        &Type::EnumeratedExt(&["requested"], &[]),
+    ),
+    ie(114, "UE-NGAP-IDs", &UE_NGAP_IDS),
+    raw(115, "UEPagingIdentity"),
+    raw(117, "UERadioCapability"),
+    ie(119, "UESecurityCapabilities", &UE_SECURITY_CAPABILITIES),
+    ie(121, "UserLocationInformation", &USER_LOCATION_INFORMATION),
+    raw(126, "AdditionalUL-NGU-UP-TNLInformation"),
+    ie(
+        128,
+        "DL-NGU-UP-TNLInformation",
+        &UP_TRANSPORT_LAYER_INFORMATION,
+    ),
+    ie(129, "NetworkInstance", &Type::IntegerExt(1, 256)),
+    ie(
+        130,
+        "PDUSessionAggregateMaximumBitRate",
+        &AGGREGATE_MAXIMUM_BIT_RATE,
+    ),
+    ie(133, "PDUSessionResourceListCxtRelReq", &SESSION_ID_LIST),
+    ie(
+        134,
+        "PDUSessionType",
+        &Type::EnumeratedExt(&["ipv4", "ipv6", "ipv4v6", "ethernet", "unstructured"], &[]),
+    ),
+    ie(136, "QosFlowSetupRequestList", &QOS_FLOW_SETUP_REQUEST_LIST),
+    ie(138, "SecurityIndication", &SECURITY_INDICATION),
+    ie(
+        139,
+        "UL-NGU-UP-TNLInformation",
+        &UP_TRANSPORT_LAYER_INFORMATION,
+    ),
+];
@@ -0,0 +1,605 @@
+//! ASN.1 Packed Encoding Rules (X.691), unaligned (UPER) and aligned (APER)
+//! variants, driven by static type descriptions.
+
+use super::Node;
+use crate::hexdump::to_hex;
+
+/// Size constraint of strings and lists, `None` when there is no upper bound
+#[derive(Debug, Clone, Copy)]
+pub struct Size(pub usize, pub Option<usize>);
+
+pub const UNBOUNDED: Size = Size(0, None);
+
+/// Component of a SEQUENCE
+#[derive(Debug)]
+pub struct Field {
+    pub name: &'static str,
+    pub ty: &'static Type,
+    pub optional: bool, // OPTIONAL or DEFAULT
+}
+
+/// Mandatory SEQUENCE component
+macro_rules! mandatory {
+    ($name:literal, $ty:expr) => {
+        $crate::decoders::per::Field {
+            name: $name,
+            ty: $ty,
+            optional: false,
+        }
+    };
+}
+
+/// OPTIONAL or DEFAULT SEQUENCE component
+macro_rules! optional {
+    ($name:literal, $ty:expr) => {
+        $crate::decoders::per::Field {
+            name: $name,
+            ty: $ty,
+            optional: true,
+        }
+    };
+}
+
+pub(crate) use mandatory;
+pub(crate) use optional;
+
+#[derive(Debug)]
+pub enum Type {
+    Null,
+    Boolean,
+    /// INTEGER (lb..ub)
+    Integer(i64, i64),
+    /// INTEGER (lb..ub, ...)
+    IntegerExt(i64, i64),
+    /// INTEGER without constraint
+    Unconstrained,
+    Enumerated(&'static [&'static str]),
+    /// ENUMERATED { root, ..., extensions }
+    EnumeratedExt(&'static [&'static str], &'static [&'static str]),
+    BitString(Size),
+    OctetString(Size),
+    /// OCTET STRING holding an encoding of another type
+    Containing(&'static Type),
+    /// Content decoded by another decoder
+    External(Size, fn(&[u8]) -> Vec<Node>),
+    /// SEQUENCE { fields }, extensible when true
+    Sequence(&'static [Field], bool),
+    /// SEQUENCE (SIZE (..)) OF item
+    SequenceOf(&'static Type, Size),
+    /// String or list with an extensible size constraint: SIZE (.., ...)
+    SizeExt(&'static Type),
+    /// CHOICE { alternatives }, extensible when true
+    Choice(&'static [(&'static str, &'static Type)], bool),
+    /// Type we do not know how to decode, decoding stops here
+    Unsupported,
+}
+
+/// Decoding cannot go further, the reason is in the tree
+pub struct Stop;
+
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize, // in bits
+    aligned: bool,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8], aligned: bool) -> Self {
+        Self {
+            data,
+            position: 0,
+            aligned,
+        }
+    }
+
+    pub fn remaining_bits(&self) -> usize {
+        (self.data.len() * 8).saturating_sub(self.position)
+    }
+
+    pub fn bit(&mut self) -> Result<bool, String> {
+        let byte = self
+            .data
+            .get(self.position / 8)
+            .ok_or("unexpected end of data")?;
+        let bit = byte >> (7 - self.position % 8) & 1;
+        self.position += 1;
+        Ok(bit == 1)
+    }
+
+    pub fn bits(&mut self, count: usize) -> Result<u64, String> {
+        if count > 64 {
+            return Err(format!("{} bits number", count));
+        }
+        if count > self.remaining_bits() {
+            return Err("unexpected end of data".to_owned());
+        }
+        let mut value = 0u64;
+        for _ in 0..count {
+            value = value << 1 | self.bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    /// Skip to the next octet in the aligned variant
+    pub fn align(&mut self) {
+        if self.aligned {
+            self.position = self.position.div_ceil(8) * 8;
+        }
+    }
+
+    pub fn octets(&mut self, count: usize) -> Result<Vec<u8>, String> {
+        if count * 8 > self.remaining_bits() {
+            return Err("unexpected end of data".to_owned());
+        }
+        (0..count).map(|_| Ok(self.bits(8)? as u8)).collect()
+    }
+
+    pub fn constrained(&mut self, lb: i64, ub: i64) -> Result<i64, String> {
+        let range = (ub - lb) as u64 + 1;
+        let value = if range == 1 {
+            0
+        } else if !self.aligned || range <= 255 {
+            self.bits(bits_for(range))?
+        } else if range == 256 {
+            self.align();
+            self.bits(8)?
+        } else if range <= 65536 {
+            self.align();
+            self.bits(16)?
+        } else {
+            let octets = bits_for(range).div_ceil(8) as u64;
+            let len = self.bits(bits_for(octets))? as usize + 1;
+            self.align();
+            self.bits(len * 8)?
+        };
+        Ok(lb + value as i64)
+    }
+
+    /// Length of an unconstrained or large list or string
+    fn unconstrained_length(&mut self) -> Result<usize, String> {
+        self.align();
+        if !self.bit()? {
+            return Ok(self.bits(7)? as usize);
+        }
+        if !self.bit()? {
+            return Ok(self.bits(14)? as usize);
+        }
+        Err("fragmented encoding is not supported".to_owned())
+    }
+
+    pub fn length(&mut self, size: Size) -> Result<usize, String> {
+        match size {
+            Size(lb, Some(ub)) if lb == ub => Ok(lb),
+            Size(lb, Some(ub)) if ub < 65536 => {
+                Ok(self.constrained(lb as i64, ub as i64)? as usize)
+            }
+            _ => self.unconstrained_length(),
+        }
+    }
+
+    pub fn normally_small(&mut self) -> Result<u64, String> {
+        if !self.bit()? {
+            return self.bits(6);
+        }
+        let len = self.unconstrained_length()?;
+        self.align();
+        self.bits(len * 8)
+    }
+
+    /// Content of an open type: extensions, containers, ...
+    pub fn open_type(&mut self) -> Result<Vec<u8>, String> {
+        let len = self.unconstrained_length()?;
+        self.align();
+        self.octets(len)
+    }
+
+    fn unconstrained_integer(&mut self) -> Result<i64, String> {
+        let len = self.unconstrained_length()?;
+        self.align();
+        if len == 0 || len > 8 {
+            return Err(format!("{} octets integer", len));
+        }
+        let value = self.bits(len * 8)?;
+        // two's complement
+        let shift = 64 - len * 8;
+        Ok(((value << shift) as i64) >> shift)
+    }
+
+    fn bit_string(&mut self, size: Size) -> Result<(Vec<u8>, usize), String> {
+        let len = self.length(size)?;
+        let fixed = matches!(size, Size(lb, Some(ub)) if lb == ub);
+        if len > 16 || !fixed {
+            self.align();
+        }
+        let mut bytes = Vec::new();
+        let mut left = len;
+        while left > 0 {
+            let count = left.min(8);
+            bytes.push((self.bits(count)? << (8 - count)) as u8);
+            left -= count;
+        }
+        Ok((bytes, len))
+    }
+
+    fn octet_string(&mut self, size: Size) -> Result<Vec<u8>, String> {
+        let len = self.length(size)?;
+        let fixed = matches!(size, Size(lb, Some(ub)) if lb == ub);
+        if len > 2 || !fixed {
+            self.align();
+        }
+        self.octets(len)
+    }
+}
+
+/// Number of bits needed to encode `range` values
+fn bits_for(range: u64) -> usize {
+    (64 - (range - 1).leading_zeros()) as usize
+}
+
+fn format_bits(bytes: &[u8], len: usize) -> String {
+    if len <= 32 {
+        let bits: String = (0..len)
+            .map(|i| {
+                if bytes[i / 8] >> (7 - i % 8) & 1 == 1 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        format!("'{}'B", bits)
+    } else {
+        format!("{} ({} bits)", to_hex(bytes), len)
+    }
+}
+
+pub struct Decoder<'a> {
+    pub reader: BitReader<'a>,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(data: &'a [u8], aligned: bool) -> Self {
+        Self {
+            reader: BitReader::new(data, aligned),
+        }
+    }
+
+    /// Decode a value and add it to `out`, even partially decoded
+    pub fn decode(&mut self, name: &str, ty: &Type, out: &mut Vec<Node>) -> Result<(), Stop> {
+        match ty {
+            Type::Sequence(fields, extensible) => {
+                let mut node = Node::new(name);
+                let result = self.sequence(fields, *extensible, &mut node.children);
+                out.push(node);
+                result
+            }
+            Type::SequenceOf(item, size) => {
+                let mut node = Node::new(name);
+                let result = self.sequence_of(item, *size, &mut node);
+                out.push(node);
+                result
+            }
+            Type::Choice(alternatives, extensible) => {
+                let mut node = Node::new(name);
+                let result = self.choice(alternatives, *extensible, &mut node.children);
+                out.push(node);
+                result
+            }
+            Type::Containing(inner) => {
+                let bytes = self.value(name, out, |r| r.octet_string(UNBOUNDED))?;
+                let mut decoder = Decoder::new(&bytes, self.reader.aligned);
+                // the container was read whole, errors inside do not stop the rest
+                let _ = decoder.decode(name, inner, out);
+                Ok(())
+            }
+            Type::SizeExt(inner) => {
+                if !self.value(name, out, |r| r.bit())? {
+                    return self.decode(name, inner, out);
+                }
+                // out of the root, the length is not constrained
+                let unbounded = match inner {
+                    Type::BitString(_) => Type::BitString(UNBOUNDED),
+                    Type::OctetString(_) => Type::OctetString(UNBOUNDED),
+                    Type::SequenceOf(item, _) => Type::SequenceOf(item, UNBOUNDED),
+                    _ => return self.decode(name, inner, out),
+                };
+                self.decode(name, &unbounded, out)
+            }
+            Type::External(size, decode) => {
+                let bytes = self.value(name, out, |r| r.octet_string(*size))?;
+                let mut node = Node::leaf(name, to_hex(&bytes));
+                node.children = decode(&bytes);
+                out.push(node);
+                Ok(())
+            }
+            Type::Unsupported => {
+                out.push(Node::leaf(name, "not decoded".to_owned()));
+                Err(Stop)
+            }
+            leaf => {
+                let value = self.value(name, out, |r| leaf_value(r, leaf))?;
+                out.push(Node::leaf(name, value));
+                Ok(())
+            }
+        }
+    }
+
+    /// Read with `read`, an error node is added on failure
+    fn value<T>(
+        &mut self,
+        name: &str,
+        out: &mut Vec<Node>,
+        read: impl FnOnce(&mut BitReader<'a>) -> Result<T, String>,
+    ) -> Result<T, Stop> {
+        read(&mut self.reader).map_err(|e| {
+            out.push(Node::error(name, &e));
+            Stop
+        })
+    }
+
+    fn sequence(
+        &mut self,
+        fields: &[Field],
+        extensible: bool,
+        out: &mut Vec<Node>,
+    ) -> Result<(), Stop> {
+        let extended = extensible && self.value("extension bit", out, |r| r.bit())?;
+        let optional_count = fields.iter().filter(|field| field.optional).count();
+        let presence = self.value("presence bitmap", out, |r| r.bits(optional_count))?;
+        let mut optional_index = 0;
+        for field in fields {
+            if field.optional {
+                optional_index += 1;
+                if presence >> (optional_count - optional_index) & 1 == 0 {
+                    continue;
+                }
+            }
+            self.decode(field.name, field.ty, out)?;
+        }
+        if extended {
+            self.extensions(out)?;
+        }
+        Ok(())
+    }
+
+    /// Extension additions of a SEQUENCE, kept as raw octets
+    fn extensions(&mut self, out: &mut Vec<Node>) -> Result<(), Stop> {
+        let count = self.value("extensions", out, |r| match r.normally_small()? {
+            count @ 0..=63 => Ok(count as usize + 1),
+            count => Err(format!("{} extension additions", count + 1)),
+        })?;
+        let presence = self.value("extensions", out, |r| r.bits(count))?;
+        for index in 0..count {
+            if presence >> (count - 1 - index) & 1 == 1 {
+                let bytes = self.value("extension", out, |r| r.open_type())?;
+                out.push(Node::leaf(
+                    &format!("extension {}", index + 1),
+                    to_hex(&bytes),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn sequence_of(&mut self, item: &Type, size: Size, node: &mut Node) -> Result<(), Stop> {
+        let count = self.value("length", &mut node.children, |r| r.length(size))?;
+        node.value = Some(format!("{} items", count));
+        for index in 0..count {
+            self.decode(&format!("[{}]", index), item, &mut node.children)?;
+        }
+        Ok(())
+    }
+
+    fn choice(
+        &mut self,
+        alternatives: &[(&'static str, &'static Type)],
+        extensible: bool,
+        out: &mut Vec<Node>,
+    ) -> Result<(), Stop> {
+        if extensible && self.value("extension bit", out, |r| r.bit())? {
+            let index = self.value("choice", out, |r| r.normally_small())?;
+            let bytes = self.value("choice", out, |r| r.open_type())?;
+            out.push(Node::leaf(
+                &format!("extension {}", index + 1),
+                to_hex(&bytes),
+            ));
+            return Ok(());
+        }
+        let last = alternatives.len() as i64 - 1;
+        let (name, ty) = self.value("choice", out, |r| {
+            let index = r.constrained(0, last)?;
+            alternatives
+                .get(index as usize)
+                .copied()
+                .ok_or_else(|| "choice index out of range".to_owned())
+        })?;
+        self.decode(name, ty, out)
+    }
+}
+
+/// Item of an ENUMERATED root
+fn enumerated(reader: &mut BitReader<'_>, items: &[&str]) -> Result<String, String> {
+    let index = reader.constrained(0, items.len() as i64 - 1)?;
+    items
+        .get(index as usize)
+        .map(|item| (*item).to_owned())
+        .ok_or_else(|| "enumerated value out of range".to_owned())
+}
+
+fn leaf_value(reader: &mut BitReader<'_>, ty: &Type) -> Result<String, String> {
+    let value = match ty {
+        Type::Null => "NULL".to_owned(),
+        Type::Boolean => reader.bit()?.to_string(),
+        Type::Integer(lb, ub) => reader.constrained(*lb, *ub)?.to_string(),
+        Type::IntegerExt(lb, ub) => {
+            if reader.bit()? {
+                reader.unconstrained_integer()?.to_string()
+            } else {
+                reader.constrained(*lb, *ub)?.to_string()
+            }
+        }
+        Type::Unconstrained => reader.unconstrained_integer()?.to_string(),
+        Type::Enumerated(items) => enumerated(reader, items)?,
+        Type::EnumeratedExt(items, extensions) => {
+            if reader.bit()? {
+                let index = reader.normally_small()? as usize;
+                match extensions.get(index) {
+                    Some(item) => (*item).to_owned(),
+                    None => format!("extension {}", index + 1),
+                }
+            } else {
+                enumerated(reader, items)?
+            }
+        }
+        Type::BitString(size) => {
+            let (bytes, len) = reader.bit_string(*size)?;
+            format_bits(&bytes, len)
+        }
+        Type::OctetString(size) => to_hex(&reader.octet_string(*size)?),
+        _ => return Err("not a simple type".to_owned()),
+    };
+    Ok(value)
+}
+
+/// Decode a whole PDU
+pub fn decode(data: &[u8], aligned: bool, name: &str, ty: &Type) -> Node {
+    let mut decoder = Decoder::new(data, aligned);
+    let mut out = Vec::new();
+    if decoder.decode(name, ty, &mut out).is_ok() && decoder.reader.remaining_bits() >= 8 {
+        out.push(Node::error(
+            "trailing data",
+            &format!("{} bits", decoder.reader.remaining_bits()),
+        ));
+    }
+    if out.len() == 1 {
+        return out.remove(0);
+    }
+    let mut node = Node::new(name);
+    node.children = out;
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Octets of a string of '0' and '1', padded with zeros
+    fn bits(text: &str) -> Vec<u8> {
+        let bits: Vec<u8> = text
+            .bytes()
+            .filter(|c| *c != b' ')
+            .map(|c| c - b'0')
+            .collect();
+        bits.chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, bit)| byte | bit << (7 - i))
+            })
+            .collect()
+    }
+
+    fn value<'a>(node: &'a Node, name: &str) -> Option<&'a str> {
+        let child = node.children.iter().find(|child| child.name == name)?;
+        child.value.as_deref()
+    }
+
+    static COLOR: Type = Type::Enumerated(&["red", "green", "blue"]);
+    static SHAPE: Type = Type::Choice(
+        &[
+            ("circle", &Type::Integer(0, 255)),
+            ("square", &Type::Boolean),
+            ("none", &Type::Null),
+        ],
+        false,
+    );
+    static ITEM: Type = Type::Sequence(
+        &[
+            mandatory!("color", &COLOR),
+            optional!("size", &Type::Integer(1, 16)),
+            mandatory!("shape", &SHAPE),
+            optional!("label", &Type::OctetString(Size(1, Some(4)))),
+        ],
+        true,
+    );
+
+    #[test]
+    fn constrained_integers() {
+        let data = bits("101 11111111");
+        let mut unaligned = BitReader::new(&data, false);
+        assert_eq!(unaligned.constrained(0, 7), Ok(5));
+        assert_eq!(unaligned.constrained(-10, 245), Ok(245));
+        let data = bits("101 00000 00000000 00000001 00000000");
+        let mut aligned = BitReader::new(&data, true);
+        assert_eq!(aligned.constrained(0, 7), Ok(5));
+        assert_eq!(aligned.constrained(0, 1000), Ok(1));
+        assert_eq!(aligned.remaining_bits(), 8);
+    }
+
+    #[test]
+    fn sequence() {
+        // no extension, size and label present, green, square true, "ab"
+        let data = bits("0 11 01 1111 01 1 01 01100001 01100010");
+        let node = decode(&data, false, "item", &ITEM);
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(value(&node, "color"), Some("green"));
+        assert_eq!(value(&node, "size"), Some("16"));
+        assert_eq!(value(&node.children[2], "square"), Some("true"));
+        assert_eq!(value(&node, "label"), Some("6162"));
+    }
+
+    #[test]
+    fn optional_fields_absent() {
+        let data = bits("0 00 10 10");
+        let node = decode(&data, false, "item", &ITEM);
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(value(&node, "color"), Some("blue"));
+        assert_eq!(value(&node, "size"), None);
+        assert_eq!(value(&node.children[1], "none"), Some("NULL"));
+    }
+
+    #[test]
+    fn extension_additions() {
+        // two additions, the second present with one octet
+        let data = bits("1 00 00 10 0 000001 01 00000001 11111111");
+        let node = decode(&data, false, "item", &ITEM);
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(value(&node, "extension 2"), Some("ff"));
+    }
+
+    #[test]
+    fn too_many_extension_additions() {
+        // normally small number 100 in one octet
+        let data = bits("1 00 00 10 1 00000001 01100100 11111111");
+        let node = decode(&data, false, "item", &ITEM);
+        assert!(node.has_error());
+        assert_eq!(value(&node, "extensions"), Some("101 extension additions"));
+    }
+
+    #[test]
+    fn enumerated_out_of_range() {
+        let node = decode(&bits("0 00 11"), false, "item", &ITEM);
+        assert_eq!(value(&node, "color"), Some("enumerated value out of range"));
+        assert!(node.has_error());
+    }
+
+    #[test]
+    fn choice_out_of_range() {
+        let node = decode(&bits("0 00 00 11"), false, "item", &ITEM);
+        assert_eq!(
+            value(&node.children[1], "choice"),
+            Some("choice index out of range")
+        );
+        assert!(node.has_error());
+    }
+
+    #[test]
+    fn truncated() {
+        let data = bits("0 11 01 1111 01 1 01 01100001 01100010");
+        for len in 0..data.len() {
+            let node = decode(&data[..len], false, "item", &ITEM);
+            assert!(node.has_error(), "{} octets: {:?}", len, node);
+        }
+    }
+}
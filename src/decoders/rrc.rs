@@ -0,0 +1,217 @@
+//! LTE and NR RRC messages, the type is chosen from the channel and the
+//! direction of the log
+
+use super::{per, rrc_lte, rrc_nr, Node};
+use crate::OneLog;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Technology {
+    Lte,
+    Nr,
+}
+
+impl Technology {
+    pub fn name(self) -> &'static str {
+        match self {
+            Technology::Lte => "LTE",
+            Technology::Nr => "NR",
+        }
+    }
+
+    fn message_type(self, channel: Channel) -> (&'static str, &'static per::Type) {
+        match self {
+            Technology::Lte => rrc_lte::message_type(channel),
+            Technology::Nr => rrc_nr::message_type(channel),
+        }
+    }
+}
+
+/// First line of data without case, spaces nor separators
+fn message_name(log: &OneLog) -> String {
+    log.data
+        .first()
+        .map(|line| {
+            line.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .unwrap_or_default()
+}
+
+/// Technology of a log, `None` when nothing tells
+pub fn technology(log: &OneLog) -> Option<Technology> {
+    if log
+        .channel
+        .as_deref()
+        .is_some_and(|channel| channel.to_uppercase().contains("NR"))
+    {
+        return Some(Technology::Nr);
+    }
+    let message = message_name(log);
+    if message.contains("rrcconnection") || message.contains("systeminformationblocktype") {
+        return Some(Technology::Lte);
+    }
+    const NR_MESSAGES: [&str; 6] = [
+        "rrcsetup",
+        "rrcreconfiguration",
+        "rrcrelease",
+        "rrcresume",
+        "rrcreject",
+        "rrcreestablishment",
+    ];
+    if NR_MESSAGES.iter().any(|name| message.contains(name)) {
+        return Some(Technology::Nr);
+    }
+    None
+}
+
+/// Logical channel, giving the top level type of a message
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Channel {
+    BcchBch,
+    BcchDlSch,
+    Pcch,
+    UlCcch,
+    DlCcch,
+    UlDcch,
+    DlDcch,
+}
+
+impl Channel {
+    pub fn from_log(log: &OneLog) -> Option<Channel> {
+        let channel = log.channel.as_deref()?.to_uppercase();
+        let uplink = match log.dir.as_deref() {
+            Some("UL") => true,
+            Some("DL") => false,
+            _ => return None,
+        };
+        if channel.starts_with("BCCH") {
+            let message = message_name(log);
+            if channel.contains("-BCH") || message.contains("mib") || message.contains("master") {
+                return Some(Channel::BcchBch);
+            }
+            return Some(Channel::BcchDlSch);
+        }
+        if channel.starts_with("PCCH") {
+            return Some(Channel::Pcch);
+        }
+        if channel.starts_with("CCCH") || channel == "SRB0" {
+            return Some(if uplink {
+                Channel::UlCcch
+            } else {
+                Channel::DlCcch
+            });
+        }
+        if channel.starts_with("DCCH") || channel.starts_with("SRB") {
+            return Some(if uplink {
+                Channel::UlDcch
+            } else {
+                Channel::DlDcch
+            });
+        }
+        None
+    }
+}
+
+/// Decode the UPER encoded message of a RRC log
+pub fn decode(log: &OneLog, bytes: &[u8]) -> Option<Node> {
+    let channel = Channel::from_log(log)?;
+    let decode_as = |technology: Technology| {
+        let (name, ty) = technology.message_type(channel);
+        let mut node = per::decode(bytes, false, name, ty);
+        node.name = format!("{} {}", technology.name(), node.name);
+        node
+    };
+    match technology(log) {
+        Some(technology) => Some(decode_as(technology)),
+        None => {
+            // try both, keeping the first one decoded without error
+            let lte = decode_as(Technology::Lte);
+            if !lte.has_error() {
+                return Some(lte);
+            }
+            let nr = decode_as(Technology::Nr);
+            Some(if nr.has_error() { lte } else { nr })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(channel: &str, dir: &str, message: &str) -> OneLog {
+        OneLog {
+            data: vec![message.to_owned()],
+            timestamp: 0,
+            layer: "RRC".to_owned(),
+            level: 3,
+            dir: Some(dir.to_owned()),
+            cell: Some(1),
+            ue_id: Some(1),
+            rnti: None,
+            channel: Some(channel.to_owned()),
+            src: "ENB".to_owned(),
+            idx: 0,
+            source: 0,
+            info: None,
+        }
+    }
+
+    fn find<'a>(node: &'a Node, name: &str) -> Option<&'a Node> {
+        if node.name == name {
+            return Some(node);
+        }
+        node.children.iter().find_map(|child| find(child, name))
+    }
+
+    #[test]
+    fn rrc_connection_request() {
+        let bytes = [0x41, 0x23, 0x45, 0x67, 0x89, 0xa6];
+        let node = decode(&log("CCCH", "UL", "RRC connection request"), &bytes).unwrap();
+        assert!(!node.has_error(), "{:?}", node);
+        assert_eq!(node.name, "LTE UL-CCCH-Message");
+        let value = |name| find(&node, name).and_then(|node| node.value.as_deref());
+        assert_eq!(value("mmec"), Some("'00010010'B"));
+        assert_eq!(value("m-TMSI"), Some("'00110100010101100111100010011010'B"));
+        assert_eq!(value("establishmentCause"), Some("mo-Signalling"));
+    }
+
+    #[test]
+    fn truncated_message() {
+        let bytes = [0x41, 0x23, 0x45];
+        let node = decode(&log("CCCH", "UL", "RRC connection request"), &bytes).unwrap();
+        assert!(node.has_error());
+    }
+
+    #[test]
+    fn random_payloads() {
+        let channels = [
+            ("BCCH", "DL", "MIB"),
+            ("BCCH", "DL", "SIB1"),
+            ("PCCH", "DL", "Paging"),
+            ("CCCH", "UL", ""),
+            ("CCCH", "DL", ""),
+            ("DCCH", "UL", ""),
+            ("DCCH", "DL", ""),
+            ("DCCH-NR", "DL", ""),
+        ];
+        // xorshift, the same payloads on each run
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for (channel, dir, message) in channels {
+            let log = log(channel, dir, message);
+            for _ in 0..2000 {
+                let len = next() as usize % 32 + 1;
+                let bytes: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+                assert!(decode(&log, &bytes).is_some());
+            }
+        }
+    }
+}
@@ -0,0 +1,1522 @@
+//! Subset of the LTE RRC messages (3GPP TS 36.331)
+
+use super::per::{mandatory, optional, Size, Type, UNBOUNDED};
+use super::rrc::Channel;
+
+static EMPTY: Type = Type::Sequence(&[], false);
+static TRANSACTION_ID: Type = Type::Integer(0, 3);
+static DEDICATED_INFO_NAS: Type = Type::External(UNBOUNDED, super::nas::decode_container);
+
+static MCC_MNC_DIGIT: Type = Type::Integer(0, 9);
+static PLMN_IDENTITY: Type = Type::Sequence(
+    &[
+        optional!("mcc", &Type::SequenceOf(&MCC_MNC_DIGIT, Size(3, Some(3)))),
+        mandatory!("mnc", &Type::SequenceOf(&MCC_MNC_DIGIT, Size(2, Some(3)))),
+    ],
+    false,
+);
+static S_TMSI: Type = Type::Sequence(
+    &[
+        mandatory!("mmec", &Type::BitString(Size(8, Some(8)))),
+        mandatory!("m-TMSI", &Type::BitString(Size(32, Some(32)))),
+    ],
+    false,
+);
+static RAT_TYPE: Type = Type::EnumeratedExt(
+    &[
+        "eutra",
+        "utra",
+        "geran-cs",
+        "geran-ps",
+        "cdma2000-1XRTT",
+        "nr",
+        "eutra-nr",
+        "spare1",
+    ],
+    &[],
+);
+
+// BCCH-BCH
+
+static MASTER_INFORMATION_BLOCK: Type = Type::Sequence(
+    &[
+        mandatory!(
+            "dl-Bandwidth",
+            &Type::Enumerated(&["n6", "n15", "n25", "n50", "n75", "n100"])
+        ),
+        mandatory!(
+            "phich-Config",
+            &Type::Sequence(
+                &[
+                    mandatory!("phich-Duration", &Type::Enumerated(&["normal", "extended"])),
+                    mandatory!(
+                        "phich-Resource",
+                        &Type::Enumerated(&["oneSixth", "half", "one", "two"])
+                    ),
+                ],
+                false,
+            )
+        ),
+        mandatory!("systemFrameNumber", &Type::BitString(Size(8, Some(8)))),
+        mandatory!("schedulingInfoSIB1-BR-r13", &Type::Integer(0, 31)),
+        mandatory!("systemInfoUnchanged-BR-r15", &Type::Boolean),
+        mandatory!("spare", &Type::BitString(Size(4, Some(4)))),
+    ],
+    false,
+);
+
+static BCCH_BCH_MESSAGE: Type =
+    Type::Sequence(&[mandatory!("message", &MASTER_INFORMATION_BLOCK)], false);
+
+// BCCH-DL-SCH
+
+static SYSTEM_INFORMATION_BLOCK_TYPE1: Type = Type::Sequence(
+    &[
+        mandatory!(
+            "cellAccessRelatedInfo",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "plmn-IdentityList",
+                        &Type::SequenceOf(
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("plmn-Identity", &PLMN_IDENTITY),
+                                    mandatory!(
+                                        "cellReservedForOperatorUse",
+                                        &Type::Enumerated(&["reserved", "notReserved"])
+                                    ),
+                                ],
+                                false,
+                            ),
+                            Size(1, Some(6)),
+                        )
+                    ),
+                    mandatory!("trackingAreaCode", &Type::BitString(Size(16, Some(16)))),
+                    mandatory!("cellIdentity", &Type::BitString(Size(28, Some(28)))),
+                    mandatory!("cellBarred", &Type::Enumerated(&["barred", "notBarred"])),
+                    mandatory!(
+                        "intraFreqReselection",
+                        &Type::Enumerated(&["allowed", "notAllowed"])
+                    ),
+                    mandatory!("csg-Indication", &Type::Boolean),
+                    optional!("csg-Identity", &Type::BitString(Size(27, Some(27)))),
+                ],
+                false,
+            )
+        ),
+        mandatory!(
+            "cellSelectionInfo",
+            &Type::Sequence(
+                &[
+                    mandatory!("q-RxLevMin", &Type::Integer(-70, -22)),
+                    optional!("q-RxLevMinOffset", &Type::Integer(1, 8)),
+                ],
+                false,
+            )
+        ),
+        optional!("p-Max", &Type::Integer(-30, 33)),
+        mandatory!("freqBandIndicator", &Type::Integer(1, 64)),
+        mandatory!(
+            "schedulingInfoList",
+            &Type::SequenceOf(
+                &Type::Sequence(
+                    &[
+                        mandatory!(
+                            "si-Periodicity",
+                            &Type::Enumerated(&[
+                                "rf8", "rf16", "rf32", "rf64", "rf128", "rf256", "rf512"
+                            ])
+                        ),
+                        mandatory!(
+                            "sib-MappingInfo",
+                            &Type::SequenceOf(
+                                &Type::EnumeratedExt(
+                                    &[
+                                        "sibType3",
+                                        "sibType4",
+                                        "sibType5",
+                                        "sibType6",
+                                        "sibType7",
+                                        "sibType8",
+                                        "sibType9",
+                                        "sibType10",
+                                        "sibType11",
+                                        "sibType12-v920",
+                                        "sibType13-v920",
+                                        "sibType14-v1130",
+                                        "sibType15-v1130",
+                                        "sibType16-v1130",
+                                        "sibType17-v1250",
+                                        "sibType18-v1250",
+                                    ],
+                                    &[
+                                        "sibType19-v1250",
+                                        "sibType20-v1310",
+                                        "sibType21-v1430",
+                                        "sibType24-v1530",
+                                        "sibType25-v1530",
+                                        "sibType26-v1530",
+                                    ],
+                                ),
+                                Size(0, Some(31)),
+                            )
+                        ),
+                    ],
+                    false,
+                ),
+                Size(1, Some(32)),
+            )
+        ),
+        optional!(
+            "tdd-Config",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "subframeAssignment",
+                        &Type::Enumerated(&["sa0", "sa1", "sa2", "sa3", "sa4", "sa5", "sa6"])
+                    ),
+                    mandatory!(
+                        "specialSubframePatterns",
+                        &Type::Enumerated(&[
+                            "ssp0", "ssp1", "ssp2", "ssp3", "ssp4", "ssp5", "ssp6", "ssp7", "ssp8"
+                        ])
+                    ),
+                ],
+                false,
+            )
+        ),
+        mandatory!(
+            "si-WindowLength",
+            &Type::Enumerated(&["ms1", "ms2", "ms5", "ms10", "ms15", "ms20", "ms40"])
+        ),
+        mandatory!("systemInfoValueTag", &Type::Integer(0, 31)),
+        optional!("nonCriticalExtension", &Type::Unsupported),
+    ],
+    false,
+);
+
+static BCCH_DL_SCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("systemInformation", &Type::Unsupported),
+                            (
+                                "systemInformationBlockType1",
+                                &SYSTEM_INFORMATION_BLOCK_TYPE1
+                            ),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// PCCH
+
+static PAGING: Type = Type::Sequence(
+    &[
+        optional!(
+            "pagingRecordList",
+            &Type::SequenceOf(
+                &Type::Sequence(
+                    &[
+                        mandatory!(
+                            "ue-Identity",
+                            &Type::Choice(
+                                &[
+                                    ("s-TMSI", &S_TMSI),
+                                    ("imsi", &Type::SequenceOf(&MCC_MNC_DIGIT, Size(6, Some(21))),),
+                                ],
+                                true,
+                            )
+                        ),
+                        mandatory!("cn-Domain", &Type::Enumerated(&["ps", "cs"])),
+                    ],
+                    true,
+                ),
+                Size(1, Some(16)),
+            )
+        ),
+        optional!("systemInfoModification", &Type::Enumerated(&["true"])),
+        optional!("etws-Indication", &Type::Enumerated(&["true"])),
+        optional!("nonCriticalExtension", &Type::Unsupported),
+    ],
+    false,
+);
+
+static PCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                ("c1", &Type::Choice(&[("paging", &PAGING)], false)),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// UL-CCCH
+
+static RRC_CONNECTION_REESTABLISHMENT_REQUEST: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "rrcConnectionReestablishmentRequest-r8",
+                    &Type::Sequence(
+                        &[
+                            mandatory!(
+                                "ue-Identity",
+                                &Type::Sequence(
+                                    &[
+                                        mandatory!("c-RNTI", &Type::BitString(Size(16, Some(16)))),
+                                        mandatory!("physCellId", &Type::Integer(0, 503)),
+                                        mandatory!(
+                                            "shortMAC-I",
+                                            &Type::BitString(Size(16, Some(16)))
+                                        ),
+                                    ],
+                                    false,
+                                )
+                            ),
+                            mandatory!(
+                                "reestablishmentCause",
+                                &Type::Enumerated(&[
+                                    "reconfigurationFailure",
+                                    "handoverFailure",
+                                    "otherFailure",
+                                    "spare1",
+                                ])
+                            ),
+                            mandatory!("spare", &Type::BitString(Size(2, Some(2)))),
+                        ],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_CONNECTION_REQUEST: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "rrcConnectionRequest-r8",
+                    &Type::Sequence(
+                        &[
+                            mandatory!(
+                                "ue-Identity",
+                                &Type::Choice(
+                                    &[
+                                        ("s-TMSI", &S_TMSI),
+                                        ("randomValue", &Type::BitString(Size(40, Some(40)))),
+                                    ],
+                                    false,
+                                )
+                            ),
+                            mandatory!(
+                                "establishmentCause",
+                                &Type::Enumerated(&[
+                                    "emergency",
+                                    "highPriorityAccess",
+                                    "mt-Access",
+                                    "mo-Signalling",
+                                    "mo-Data",
+                                    "delayTolerantAccess-v1020",
+                                    "mo-VoiceCall-v1280",
+                                    "spare1",
+                                ])
+                            ),
+                            mandatory!("spare", &Type::BitString(Size(1, Some(1)))),
+                        ],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UL_CCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            (
+                                "rrcConnectionReestablishmentRequest",
+                                &RRC_CONNECTION_REESTABLISHMENT_REQUEST,
+                            ),
+                            ("rrcConnectionRequest", &RRC_CONNECTION_REQUEST),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// Radio resources
+
+static T_POLL_RETRANSMIT: Type = Type::Enumerated(&[
+    "ms5",
+    "ms10",
+    "ms15",
+    "ms20",
+    "ms25",
+    "ms30",
+    "ms35",
+    "ms40",
+    "ms45",
+    "ms50",
+    "ms55",
+    "ms60",
+    "ms65",
+    "ms70",
+    "ms75",
+    "ms80",
+    "ms85",
+    "ms90",
+    "ms95",
+    "ms100",
+    "ms105",
+    "ms110",
+    "ms115",
+    "ms120",
+    "ms125",
+    "ms130",
+    "ms135",
+    "ms140",
+    "ms145",
+    "ms150",
+    "ms155",
+    "ms160",
+    "ms165",
+    "ms170",
+    "ms175",
+    "ms180",
+    "ms185",
+    "ms190",
+    "ms195",
+    "ms200",
+    "ms205",
+    "ms210",
+    "ms215",
+    "ms220",
+    "ms225",
+    "ms230",
+    "ms235",
+    "ms240",
+    "ms245",
+    "ms250",
+    "ms300",
+    "ms350",
+    "ms400",
+    "ms450",
+    "ms500",
+    "ms800-v1310",
+    "ms1000-v1310",
+    "ms2000-v1310",
+    "ms4000-v1310",
+    "spare5",
+    "spare4",
+    "spare3",
+    "spare2",
+    "spare1",
+]);
+
+static T_REORDERING: Type = Type::Enumerated(&[
+    "ms0",
+    "ms5",
+    "ms10",
+    "ms15",
+    "ms20",
+    "ms25",
+    "ms30",
+    "ms35",
+    "ms40",
+    "ms45",
+    "ms50",
+    "ms55",
+    "ms60",
+    "ms65",
+    "ms70",
+    "ms75",
+    "ms80",
+    "ms85",
+    "ms90",
+    "ms95",
+    "ms100",
+    "ms110",
+    "ms120",
+    "ms130",
+    "ms140",
+    "ms150",
+    "ms160",
+    "ms170",
+    "ms180",
+    "ms190",
+    "ms200",
+    "ms1600-v1310",
+]);
+
+static T_STATUS_PROHIBIT: Type = Type::Enumerated(&[
+    "ms0",
+    "ms5",
+    "ms10",
+    "ms15",
+    "ms20",
+    "ms25",
+    "ms30",
+    "ms35",
+    "ms40",
+    "ms45",
+    "ms50",
+    "ms55",
+    "ms60",
+    "ms65",
+    "ms70",
+    "ms75",
+    "ms80",
+    "ms85",
+    "ms90",
+    "ms95",
+    "ms100",
+    "ms105",
+    "ms110",
+    "ms115",
+    "ms120",
+    "ms125",
+    "ms130",
+    "ms135",
+    "ms140",
+    "ms145",
+    "ms150",
+    "ms155",
+    "ms160",
+    "ms165",
+    "ms170",
+    "ms175",
+    "ms180",
+    "ms185",
+    "ms190",
+    "ms195",
+    "ms200",
+    "ms205",
+    "ms210",
+    "ms215",
+    "ms220",
+    "ms225",
+    "ms230",
+    "ms235",
+    "ms240",
+    "ms245",
+    "ms250",
+    "ms300",
+    "ms350",
+    "ms400",
+    "ms450",
+    "ms500",
+    "ms800-v1310",
+    "ms1000-v1310",
+    "ms1200-v1310",
+    "ms1600-v1310",
+    "ms2000-v1310",
+    "ms2400-v1310",
+    "spare2",
+    "spare1",
+]);
+
+static SN_FIELD_LENGTH: Type = Type::Enumerated(&["size5", "size10"]);
+
+static UL_AM_RLC: Type = Type::Sequence(
+    &[
+        mandatory!("t-PollRetransmit", &T_POLL_RETRANSMIT),
+        mandatory!(
+            "pollPDU",
+            &Type::Enumerated(&["p4", "p8", "p16", "p32", "p64", "p128", "p256", "pInfinity"])
+        ),
+        mandatory!(
+            "pollByte",
+            &Type::Enumerated(&[
+                "kB25",
+                "kB50",
+                "kB75",
+                "kB100",
+                "kB125",
+                "kB250",
+                "kB375",
+                "kB500",
+                "kB750",
+                "kB1000",
+                "kB1250",
+                "kB1500",
+                "kB2000",
+                "kB3000",
+                "kBinfinity",
+                "spare1",
+            ])
+        ),
+        mandatory!(
+            "maxRetxThreshold",
+            &Type::Enumerated(&["t1", "t2", "t3", "t4", "t6", "t8", "t16", "t32"])
+        ),
+    ],
+    false,
+);
+
+static DL_AM_RLC: Type = Type::Sequence(
+    &[
+        mandatory!("t-Reordering", &T_REORDERING),
+        mandatory!("t-StatusProhibit", &T_STATUS_PROHIBIT),
+    ],
+    false,
+);
+
+static UL_UM_RLC: Type = Type::Sequence(&[mandatory!("sn-FieldLength", &SN_FIELD_LENGTH)], false);
+
+static DL_UM_RLC: Type = Type::Sequence(
+    &[
+        mandatory!("sn-FieldLength", &SN_FIELD_LENGTH),
+        mandatory!("t-Reordering", &T_REORDERING),
+    ],
+    false,
+);
+
+static RLC_CONFIG: Type = Type::Choice(
+    &[
+        (
+            "am",
+            &Type::Sequence(
+                &[
+                    mandatory!("ul-AM-RLC", &UL_AM_RLC),
+                    mandatory!("dl-AM-RLC", &DL_AM_RLC),
+                ],
+                false,
+            ),
+        ),
+        (
+            "um-Bi-Directional",
+            &Type::Sequence(
+                &[
+                    mandatory!("ul-UM-RLC", &UL_UM_RLC),
+                    mandatory!("dl-UM-RLC", &DL_UM_RLC),
+                ],
+                false,
+            ),
+        ),
+        (
+            "um-Uni-Directional-UL",
+            &Type::Sequence(&[mandatory!("ul-UM-RLC", &UL_UM_RLC)], false),
+        ),
+        (
+            "um-Uni-Directional-DL",
+            &Type::Sequence(&[mandatory!("dl-UM-RLC", &DL_UM_RLC)], false),
+        ),
+    ],
+    true,
+);
+
+static LOGICAL_CHANNEL_CONFIG: Type = Type::Sequence(
+    &[optional!(
+        "ul-SpecificParameters",
+        &Type::Sequence(
+            &[
+                mandatory!("priority", &Type::Integer(1, 16)),
+                mandatory!(
+                    "prioritisedBitRate",
+                    &Type::Enumerated(&[
+                        "kBps0",
+                        "kBps8",
+                        "kBps16",
+                        "kBps32",
+                        "kBps64",
+                        "kBps128",
+                        "kBps256",
+                        "infinity",
+                        "kBps512-v1020",
+                        "kBps1024-v1020",
+                        "kBps2048-v1020",
+                        "spare5",
+                        "spare4",
+                        "spare3",
+                        "spare2",
+                        "spare1",
+                    ])
+                ),
+                mandatory!(
+                    "bucketSizeDuration",
+                    &Type::Enumerated(&[
+                        "ms50", "ms100", "ms150", "ms300", "ms500", "ms1000", "spare2", "spare1"
+                    ])
+                ),
+                optional!("logicalChannelGroup", &Type::Integer(0, 3)),
+            ],
+            false,
+        )
+    )],
+    true,
+);
+
+static SRB_TO_ADD_MOD: Type = Type::Sequence(
+    &[
+        mandatory!("srb-Identity", &Type::Integer(1, 2)),
+        optional!(
+            "rlc-Config",
+            &Type::Choice(
+                &[
+                    ("explicitValue", &RLC_CONFIG),
+                    ("defaultValue", &Type::Null)
+                ],
+                false,
+            )
+        ),
+        optional!(
+            "logicalChannelConfig",
+            &Type::Choice(
+                &[
+                    ("explicitValue", &LOGICAL_CHANNEL_CONFIG),
+                    ("defaultValue", &Type::Null),
+                ],
+                false,
+            )
+        ),
+    ],
+    true,
+);
+
+static DRB_TO_ADD_MOD: Type = Type::Sequence(
+    &[
+        optional!("eps-BearerIdentity", &Type::Integer(0, 15)),
+        mandatory!("drb-Identity", &Type::Integer(1, 32)),
+        optional!("pdcp-Config", &Type::Unsupported),
+        optional!("rlc-Config", &RLC_CONFIG),
+        optional!("logicalChannelIdentity", &Type::Integer(3, 10)),
+        optional!("logicalChannelConfig", &LOGICAL_CHANNEL_CONFIG),
+    ],
+    true,
+);
+
+static RADIO_RESOURCE_CONFIG_DEDICATED: Type = Type::Sequence(
+    &[
+        optional!(
+            "srb-ToAddModList",
+            &Type::SequenceOf(&SRB_TO_ADD_MOD, Size(1, Some(2)))
+        ),
+        optional!(
+            "drb-ToAddModList",
+            &Type::SequenceOf(&DRB_TO_ADD_MOD, Size(1, Some(11)))
+        ),
+        optional!(
+            "drb-ToReleaseList",
+            &Type::SequenceOf(&Type::Integer(1, 32), Size(1, Some(11)))
+        ),
+        optional!("mac-MainConfig", &Type::Unsupported),
+        optional!("sps-Config", &Type::Unsupported),
+        optional!("physicalConfigDedicated", &Type::Unsupported),
+    ],
+    true,
+);
+
+// DL-CCCH
+
+static RRC_CONNECTION_REESTABLISHMENT: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                (
+                                    "rrcConnectionReestablishment-r8",
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!(
+                                                "radioResourceConfigDedicated",
+                                                &RADIO_RESOURCE_CONFIG_DEDICATED
+                                            ),
+                                            mandatory!(
+                                                "nextHopChainingCount",
+                                                &Type::Integer(0, 7)
+                                            ),
+                                            optional!("nonCriticalExtension", &Type::Unsupported),
+                                        ],
+                                        false,
+                                    ),
+                                ),
+                                ("spare7", &Type::Null),
+                                ("spare6", &Type::Null),
+                                ("spare5", &Type::Null),
+                                ("spare4", &Type::Null),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static RRC_CONNECTION_REESTABLISHMENT_REJECT: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "rrcConnectionReestablishmentReject-r8",
+                    &Type::Sequence(
+                        &[optional!("nonCriticalExtension", &Type::Unsupported)],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_CONNECTION_REJECT: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            (
+                                "rrcConnectionReject-r8",
+                                &Type::Sequence(
+                                    &[
+                                        mandatory!("waitTime", &Type::Integer(1, 16)),
+                                        optional!("nonCriticalExtension", &Type::Unsupported),
+                                    ],
+                                    false,
+                                ),
+                            ),
+                            ("spare3", &Type::Null),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_CONNECTION_SETUP: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                (
+                                    "rrcConnectionSetup-r8",
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!(
+                                                "radioResourceConfigDedicated",
+                                                &RADIO_RESOURCE_CONFIG_DEDICATED
+                                            ),
+                                            optional!("nonCriticalExtension", &Type::Unsupported),
+                                        ],
+                                        false,
+                                    ),
+                                ),
+                                ("spare7", &Type::Null),
+                                ("spare6", &Type::Null),
+                                ("spare5", &Type::Null),
+                                ("spare4", &Type::Null),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DL_CCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            (
+                                "rrcConnectionReestablishment",
+                                &RRC_CONNECTION_REESTABLISHMENT
+                            ),
+                            (
+                                "rrcConnectionReestablishmentReject",
+                                &RRC_CONNECTION_REESTABLISHMENT_REJECT,
+                            ),
+                            ("rrcConnectionReject", &RRC_CONNECTION_REJECT),
+                            ("rrcConnectionSetup", &RRC_CONNECTION_SETUP),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// UL-DCCH
+
+/// `SEQUENCE { rrc-TransactionIdentifier, criticalExtensions }` of messages
+/// whose r8 content is only a `nonCriticalExtension`
+macro_rules! acknowledgement {
+    ($ies:literal) => {
+        Type::Sequence(
+            &[
+                mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+                mandatory!(
+                    "criticalExtensions",
+                    &Type::Choice(
+                        &[
+                            (
+                                $ies,
+                                &Type::Sequence(
+                                    &[optional!("nonCriticalExtension", &Type::Unsupported)],
+                                    false,
+                                ),
+                            ),
+                            ("criticalExtensionsFuture", &EMPTY),
+                        ],
+                        false,
+                    )
+                ),
+            ],
+            false,
+        )
+    };
+}
+
+static RRC_CONNECTION_RECONFIGURATION_COMPLETE: Type =
+    acknowledgement!("rrcConnectionReconfigurationComplete-r8");
+static RRC_CONNECTION_REESTABLISHMENT_COMPLETE: Type =
+    acknowledgement!("rrcConnectionReestablishmentComplete-r8");
+static SECURITY_MODE_COMPLETE: Type = acknowledgement!("securityModeComplete-r8");
+static SECURITY_MODE_FAILURE: Type = acknowledgement!("securityModeFailure-r8");
+
+static RRC_CONNECTION_SETUP_COMPLETE: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                (
+                                    "rrcConnectionSetupComplete-r8",
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!(
+                                                "selectedPLMN-Identity",
+                                                &Type::Integer(1, 6)
+                                            ),
+                                            optional!(
+                                                "registeredMME",
+                                                &Type::Sequence(
+                                                    &[
+                                                        optional!("plmn-Identity", &PLMN_IDENTITY),
+                                                        mandatory!(
+                                                            "mmegi",
+                                                            &Type::BitString(Size(16, Some(16)))
+                                                        ),
+                                                        mandatory!(
+                                                            "mmec",
+                                                            &Type::BitString(Size(8, Some(8)))
+                                                        ),
+                                                    ],
+                                                    false,
+                                                )
+                                            ),
+                                            mandatory!("dedicatedInfoNAS", &DEDICATED_INFO_NAS),
+                                            optional!("nonCriticalExtension", &Type::Unsupported),
+                                        ],
+                                        false,
+                                    ),
+                                ),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DEDICATED_INFO_TYPE: Type = Type::Choice(
+    &[
+        ("dedicatedInfoNAS", &DEDICATED_INFO_NAS),
+        ("dedicatedInfoCDMA2000-1XRTT", &Type::OctetString(UNBOUNDED)),
+        ("dedicatedInfoCDMA2000-HRPD", &Type::OctetString(UNBOUNDED)),
+    ],
+    false,
+);
+
+static UL_INFORMATION_TRANSFER: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            (
+                                "ulInformationTransfer-r8",
+                                &Type::Sequence(
+                                    &[
+                                        mandatory!("dedicatedInfoType", &DEDICATED_INFO_TYPE),
+                                        optional!("nonCriticalExtension", &Type::Unsupported),
+                                    ],
+                                    false,
+                                ),
+                            ),
+                            ("spare3", &Type::Null),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UE_CAPABILITY_INFORMATION: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                (
+                                    "ueCapabilityInformation-r8",
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!(
+                                                "ue-CapabilityRAT-ContainerList",
+                                                &Type::SequenceOf(
+                                                    &Type::Sequence(
+                                                        &[
+                                                            mandatory!("rat-Type", &RAT_TYPE),
+                                                            mandatory!(
+                                                                "ueCapabilityRAT-Container",
+                                                                &Type::OctetString(UNBOUNDED)
+                                                            ),
+                                                        ],
+                                                        false,
+                                                    ),
+                                                    Size(0, Some(8)),
+                                                )
+                                            ),
+                                            optional!("nonCriticalExtension", &Type::Unsupported),
+                                        ],
+                                        false,
+                                    ),
+                                ),
+                                ("spare7", &Type::Null),
+                                ("spare6", &Type::Null),
+                                ("spare5", &Type::Null),
+                                ("spare4", &Type::Null),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static RSRP_RANGE: Type = Type::Integer(0, 97);
+static RSRQ_RANGE: Type = Type::Integer(0, 34);
+
+static MEAS_RESULTS: Type = Type::Sequence(
+    &[
+        mandatory!("measId", &Type::Integer(1, 32)),
+        mandatory!(
+            "measResultPCell",
+            &Type::Sequence(
+                &[
+                    mandatory!("rsrpResult", &RSRP_RANGE),
+                    mandatory!("rsrqResult", &RSRQ_RANGE),
+                ],
+                false,
+            )
+        ),
+        optional!(
+            "measResultNeighCells",
+            &Type::Choice(
+                &[
+                    (
+                        "measResultListEUTRA",
+                        &Type::SequenceOf(
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("physCellId", &Type::Integer(0, 503)),
+                                    optional!("cgi-Info", &Type::Unsupported),
+                                    mandatory!(
+                                        "measResult",
+                                        &Type::Sequence(
+                                            &[
+                                                optional!("rsrpResult", &RSRP_RANGE),
+                                                optional!("rsrqResult", &RSRQ_RANGE),
+                                            ],
+                                            true,
+                                        )
+                                    ),
+                                ],
+                                false,
+                            ),
+                            Size(1, Some(8)),
+                        ),
+                    ),
+                    ("measResultListUTRA", &Type::Unsupported),
+                    ("measResultListGERAN", &Type::Unsupported),
+                    ("measResultsCDMA2000", &Type::Unsupported),
+                ],
+                true,
+            )
+        ),
+    ],
+    true,
+);
+
+static MEASUREMENT_REPORT: Type = Type::Sequence(
+    &[mandatory!(
+        "criticalExtensions",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            (
+                                "measurementReport-r8",
+                                &Type::Sequence(
+                                    &[
+                                        mandatory!("measResults", &MEAS_RESULTS),
+                                        optional!("nonCriticalExtension", &Type::Unsupported),
+                                    ],
+                                    false,
+                                ),
+                            ),
+                            ("spare7", &Type::Null),
+                            ("spare6", &Type::Null),
+                            ("spare5", &Type::Null),
+                            ("spare4", &Type::Null),
+                            ("spare3", &Type::Null),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("criticalExtensionsFuture", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UL_DCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("csfbParametersRequestCDMA2000", &Type::Unsupported),
+                            ("measurementReport", &MEASUREMENT_REPORT),
+                            (
+                                "rrcConnectionReconfigurationComplete",
+                                &RRC_CONNECTION_RECONFIGURATION_COMPLETE,
+                            ),
+                            (
+                                "rrcConnectionReestablishmentComplete",
+                                &RRC_CONNECTION_REESTABLISHMENT_COMPLETE,
+                            ),
+                            ("rrcConnectionSetupComplete", &RRC_CONNECTION_SETUP_COMPLETE),
+                            ("securityModeComplete", &SECURITY_MODE_COMPLETE),
+                            ("securityModeFailure", &SECURITY_MODE_FAILURE),
+                            ("ueCapabilityInformation", &UE_CAPABILITY_INFORMATION),
+                            ("ulHandoverPreparationTransfer", &Type::Unsupported),
+                            ("ulInformationTransfer", &UL_INFORMATION_TRANSFER),
+                            ("counterCheckResponse", &Type::Unsupported),
+                            ("ueInformationResponse-r9", &Type::Unsupported),
+                            ("proximityIndication-r9", &Type::Unsupported),
+                            ("rnReconfigurationComplete-r10", &Type::Unsupported),
+                            ("mbmsCountingResponse-r10", &Type::Unsupported),
+                            ("interFreqRSTDMeasurementIndication-r10", &Type::Unsupported),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// DL-DCCH
+
+/// `criticalExtensions` of DL-DCCH messages: `c1` with the r8 content and
+/// three spares
+macro_rules! critical_extensions {
+    ($ies:literal, $content:expr) => {
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                ($ies, $content),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        )
+    };
+}
+
+static DL_INFORMATION_TRANSFER: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "dlInformationTransfer-r8",
+            &Type::Sequence(
+                &[
+                    mandatory!("dedicatedInfoType", &DEDICATED_INFO_TYPE),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static RRC_CONNECTION_RECONFIGURATION: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[
+                    (
+                        "c1",
+                        &Type::Choice(
+                            &[
+                                (
+                                    "rrcConnectionReconfiguration-r8",
+                                    &Type::Sequence(
+                                        &[
+                                            optional!("measConfig", &Type::Unsupported),
+                                            optional!("mobilityControlInfo", &Type::Unsupported),
+                                            optional!(
+                                                "dedicatedInfoNASList",
+                                                &Type::SequenceOf(
+                                                    &DEDICATED_INFO_NAS,
+                                                    Size(1, Some(11)),
+                                                )
+                                            ),
+                                            optional!(
+                                                "radioResourceConfigDedicated",
+                                                &RADIO_RESOURCE_CONFIG_DEDICATED
+                                            ),
+                                            optional!("securityConfigHO", &Type::Unsupported),
+                                            optional!("nonCriticalExtension", &Type::Unsupported),
+                                        ],
+                                        false,
+                                    ),
+                                ),
+                                ("spare7", &Type::Null),
+                                ("spare6", &Type::Null),
+                                ("spare5", &Type::Null),
+                                ("spare4", &Type::Null),
+                                ("spare3", &Type::Null),
+                                ("spare2", &Type::Null),
+                                ("spare1", &Type::Null),
+                            ],
+                            false,
+                        ),
+                    ),
+                    ("criticalExtensionsFuture", &EMPTY),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static RRC_CONNECTION_RELEASE: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "rrcConnectionRelease-r8",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "releaseCause",
+                        &Type::Enumerated(&[
+                            "loadBalancingTAUrequired",
+                            "other",
+                            "cs-FallbackHighPriority-v1020",
+                            "rrc-Suspend-v1320",
+                        ])
+                    ),
+                    optional!("redirectedCarrierInfo", &Type::Unsupported),
+                    optional!("idleModeMobilityControlInfo", &Type::Unsupported),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static SECURITY_MODE_COMMAND: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "securityModeCommand-r8",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "securityConfigSMC",
+                        &Type::Sequence(
+                            &[mandatory!(
+                                "securityAlgorithmConfig",
+                                &Type::Sequence(
+                                    &[
+                                        mandatory!(
+                                            "cipheringAlgorithm",
+                                            &Type::EnumeratedExt(
+                                                &[
+                                                    "eea0",
+                                                    "eea1",
+                                                    "eea2",
+                                                    "eea3-v1130",
+                                                    "spare4",
+                                                    "spare3",
+                                                    "spare2",
+                                                    "spare1",
+                                                ],
+                                                &[],
+                                            )
+                                        ),
+                                        mandatory!(
+                                            "integrityProtAlgorithm",
+                                            &Type::EnumeratedExt(
+                                                &[
+                                                    "eia0-v920",
+                                                    "eia1",
+                                                    "eia2",
+                                                    "eia3-v1130",
+                                                    "spare4",
+                                                    "spare3",
+                                                    "spare2",
+                                                    "spare1",
+                                                ],
+                                                &[],
+                                            )
+                                        ),
+                                    ],
+                                    false,
+                                )
+                            )],
+                            true,
+                        )
+                    ),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static UE_CAPABILITY_ENQUIRY: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "ueCapabilityEnquiry-r8",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "ue-CapabilityRequest",
+                        &Type::SequenceOf(&RAT_TYPE, Size(1, Some(8)))
+                    ),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DL_DCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("csfbParametersResponseCDMA2000", &Type::Unsupported),
+                            ("dlInformationTransfer", &DL_INFORMATION_TRANSFER),
+                            ("handoverFromEUTRAPreparationRequest", &Type::Unsupported),
+                            ("mobilityFromEUTRACommand", &Type::Unsupported),
+                            (
+                                "rrcConnectionReconfiguration",
+                                &RRC_CONNECTION_RECONFIGURATION
+                            ),
+                            ("rrcConnectionRelease", &RRC_CONNECTION_RELEASE),
+                            ("securityModeCommand", &SECURITY_MODE_COMMAND),
+                            ("ueCapabilityEnquiry", &UE_CAPABILITY_ENQUIRY),
+                            ("counterCheck", &Type::Unsupported),
+                            ("ueInformationRequest-r9", &Type::Unsupported),
+                            ("loggedMeasurementConfiguration-r10", &Type::Unsupported),
+                            ("rnReconfiguration-r10", &Type::Unsupported),
+                            ("rrcConnectionResume-r13", &Type::Unsupported),
+                            ("spare3", &Type::Null),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+/// Top level type of a channel
+pub fn message_type(channel: Channel) -> (&'static str, &'static Type) {
+    match channel {
+        Channel::BcchBch => ("BCCH-BCH-Message", &BCCH_BCH_MESSAGE),
+        Channel::BcchDlSch => ("BCCH-DL-SCH-Message", &BCCH_DL_SCH_MESSAGE),
+        Channel::Pcch => ("PCCH-Message", &PCCH_MESSAGE),
+        Channel::UlCcch => ("UL-CCCH-Message", &UL_CCCH_MESSAGE),
+        Channel::DlCcch => ("DL-CCCH-Message", &DL_CCCH_MESSAGE),
+        Channel::UlDcch => ("UL-DCCH-Message", &UL_DCCH_MESSAGE),
+        Channel::DlDcch => ("DL-DCCH-Message", &DL_DCCH_MESSAGE),
+    }
+}
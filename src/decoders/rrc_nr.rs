@@ -0,0 +1,912 @@
+//! Subset of the NR RRC messages (3GPP TS 38.331)
+
+use super::per::{mandatory, optional, Size, Type, UNBOUNDED};
+use super::rrc::Channel;
+
+static EMPTY: Type = Type::Sequence(&[], false);
+static TRANSACTION_ID: Type = Type::Integer(0, 3);
+static DEDICATED_NAS_MESSAGE: Type = Type::External(UNBOUNDED, super::nas::decode_container);
+static LATE_NON_CRITICAL_EXTENSION: Type = Type::OctetString(UNBOUNDED);
+
+static MCC_MNC_DIGIT: Type = Type::Integer(0, 9);
+static PLMN_IDENTITY: Type = Type::Sequence(
+    &[
+        optional!("mcc", &Type::SequenceOf(&MCC_MNC_DIGIT, Size(3, Some(3)))),
+        mandatory!("mnc", &Type::SequenceOf(&MCC_MNC_DIGIT, Size(2, Some(3)))),
+    ],
+    false,
+);
+static RAT_TYPE: Type = Type::EnumeratedExt(&["nr", "eutra-nr", "eutra", "utra-fdd-v1610"], &[]);
+
+/// `criticalExtensions` with the content and an empty future extension
+macro_rules! critical_extensions {
+    ($ies:literal, $content:expr) => {
+        mandatory!(
+            "criticalExtensions",
+            &Type::Choice(
+                &[($ies, $content), ("criticalExtensionsFuture", &EMPTY)],
+                false,
+            )
+        )
+    };
+}
+
+/// Message whose content is only its extensions
+macro_rules! acknowledgement {
+    ($ies:literal) => {
+        Type::Sequence(
+            &[
+                mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+                critical_extensions!(
+                    $ies,
+                    &Type::Sequence(
+                        &[
+                            optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                            optional!("nonCriticalExtension", &Type::Unsupported),
+                        ],
+                        false,
+                    )
+                ),
+            ],
+            false,
+        )
+    };
+}
+
+// BCCH-BCH
+
+static MIB: Type = Type::Sequence(
+    &[
+        mandatory!("systemFrameNumber", &Type::BitString(Size(6, Some(6)))),
+        mandatory!(
+            "subCarrierSpacingCommon",
+            &Type::Enumerated(&["scs15or60", "scs30or120"])
+        ),
+        mandatory!("ssb-SubcarrierOffset", &Type::Integer(0, 15)),
+        mandatory!("dmrs-TypeA-Position", &Type::Enumerated(&["pos2", "pos3"])),
+        mandatory!(
+            "pdcch-ConfigSIB1",
+            &Type::Sequence(
+                &[
+                    mandatory!("controlResourceSetZero", &Type::Integer(0, 15)),
+                    mandatory!("searchSpaceZero", &Type::Integer(0, 15)),
+                ],
+                false,
+            )
+        ),
+        mandatory!("cellBarred", &Type::Enumerated(&["barred", "notBarred"])),
+        mandatory!(
+            "intraFreqReselection",
+            &Type::Enumerated(&["allowed", "notAllowed"])
+        ),
+        mandatory!("spare", &Type::BitString(Size(1, Some(1)))),
+    ],
+    false,
+);
+
+static BCCH_BCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(&[("mib", &MIB), ("messageClassExtension", &EMPTY)], false)
+    )],
+    false,
+);
+
+// BCCH-DL-SCH
+
+static BCCH_DL_SCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("systemInformation", &Type::Unsupported),
+                            ("systemInformationBlockType1", &Type::Unsupported),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// PCCH
+
+static PAGING: Type = Type::Sequence(
+    &[
+        optional!(
+            "pagingRecordList",
+            &Type::SequenceOf(
+                &Type::Sequence(
+                    &[
+                        mandatory!(
+                            "ue-Identity",
+                            &Type::Choice(
+                                &[
+                                    ("ng-5G-S-TMSI", &Type::BitString(Size(48, Some(48)))),
+                                    ("fullI-RNTI", &Type::BitString(Size(40, Some(40)))),
+                                ],
+                                true,
+                            )
+                        ),
+                        optional!("accessType", &Type::Enumerated(&["non3GPP"])),
+                    ],
+                    true,
+                ),
+                Size(1, Some(32)),
+            )
+        ),
+        optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+        optional!("nonCriticalExtension", &Type::Unsupported),
+    ],
+    false,
+);
+
+static PCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(&[("paging", &PAGING), ("spare1", &Type::Null)], false),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// UL-CCCH
+
+static RRC_SETUP_REQUEST: Type = Type::Sequence(
+    &[mandatory!(
+        "rrcSetupRequest",
+        &Type::Sequence(
+            &[
+                mandatory!(
+                    "ue-Identity",
+                    &Type::Choice(
+                        &[
+                            ("ng-5G-S-TMSI-Part1", &Type::BitString(Size(39, Some(39)))),
+                            ("randomValue", &Type::BitString(Size(39, Some(39)))),
+                        ],
+                        false,
+                    )
+                ),
+                mandatory!(
+                    "establishmentCause",
+                    &Type::Enumerated(&[
+                        "emergency",
+                        "highPriorityAccess",
+                        "mt-Access",
+                        "mo-Signalling",
+                        "mo-Data",
+                        "mo-VoiceCall",
+                        "mo-VideoCall",
+                        "mo-SMS",
+                        "mps-PriorityAccess",
+                        "mcs-PriorityAccess",
+                        "spare6",
+                        "spare5",
+                        "spare4",
+                        "spare3",
+                        "spare2",
+                        "spare1",
+                    ])
+                ),
+                mandatory!("spare", &Type::BitString(Size(1, Some(1)))),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_RESUME_REQUEST: Type = Type::Sequence(
+    &[mandatory!(
+        "rrcResumeRequest",
+        &Type::Sequence(
+            &[
+                mandatory!("resumeIdentity", &Type::BitString(Size(24, Some(24)))),
+                mandatory!("resumeMAC-I", &Type::BitString(Size(16, Some(16)))),
+                mandatory!(
+                    "resumeCause",
+                    &Type::Enumerated(&[
+                        "emergency",
+                        "highPriorityAccess",
+                        "mt-Access",
+                        "mo-Signalling",
+                        "mo-Data",
+                        "mo-VoiceCall",
+                        "mo-VideoCall",
+                        "mo-SMS",
+                        "rna-Update",
+                        "mps-PriorityAccess",
+                        "mcs-PriorityAccess",
+                        "spare1",
+                        "spare2",
+                        "spare3",
+                        "spare4",
+                        "spare5",
+                    ])
+                ),
+                mandatory!("spare", &Type::BitString(Size(1, Some(1)))),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_REESTABLISHMENT_REQUEST: Type = Type::Sequence(
+    &[mandatory!(
+        "rrcReestablishmentRequest",
+        &Type::Sequence(
+            &[
+                mandatory!(
+                    "ue-Identity",
+                    &Type::Sequence(
+                        &[
+                            mandatory!("c-RNTI", &Type::Integer(0, 65535)),
+                            mandatory!("physCellId", &Type::Integer(0, 1007)),
+                            mandatory!("shortMAC-I", &Type::BitString(Size(16, Some(16)))),
+                        ],
+                        false,
+                    )
+                ),
+                mandatory!(
+                    "reestablishmentCause",
+                    &Type::Enumerated(&[
+                        "reconfigurationFailure",
+                        "handoverFailure",
+                        "otherFailure",
+                        "spare1",
+                    ])
+                ),
+                mandatory!("spare", &Type::BitString(Size(1, Some(1)))),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UL_CCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("rrcSetupRequest", &RRC_SETUP_REQUEST),
+                            ("rrcResumeRequest", &RRC_RESUME_REQUEST),
+                            ("rrcReestablishmentRequest", &RRC_REESTABLISHMENT_REQUEST),
+                            ("rrcSystemInfoRequest", &Type::Unsupported),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// Radio bearers
+
+static SECURITY_ALGORITHM_CONFIG: Type = Type::Sequence(
+    &[
+        mandatory!(
+            "cipheringAlgorithm",
+            &Type::EnumeratedExt(
+                &["nea0", "nea1", "nea2", "nea3", "spare4", "spare3", "spare2", "spare1"],
+                &[],
+            )
+        ),
+        optional!(
+            "integrityProtAlgorithm",
+            &Type::EnumeratedExt(
+                &["nia0", "nia1", "nia2", "nia3", "spare4", "spare3", "spare2", "spare1"],
+                &[],
+            )
+        ),
+    ],
+    true,
+);
+
+static QFI_LIST: Type = Type::SequenceOf(&Type::Integer(0, 63), Size(1, Some(64)));
+
+static DRB_TO_ADD_MOD: Type = Type::Sequence(
+    &[
+        optional!(
+            "cnAssociation",
+            &Type::Choice(
+                &[
+                    ("eps-BearerIdentity", &Type::Integer(0, 15)),
+                    (
+                        "sdap-Config",
+                        &Type::Sequence(
+                            &[
+                                mandatory!("pdu-Session", &Type::Integer(0, 255)),
+                                mandatory!(
+                                    "sdap-HeaderDL",
+                                    &Type::Enumerated(&["present", "absent"])
+                                ),
+                                mandatory!(
+                                    "sdap-HeaderUL",
+                                    &Type::Enumerated(&["present", "absent"])
+                                ),
+                                mandatory!("defaultDRB", &Type::Boolean),
+                                optional!("mappedQoS-FlowsToAdd", &QFI_LIST),
+                                optional!("mappedQoS-FlowsToRelease", &QFI_LIST),
+                            ],
+                            true,
+                        ),
+                    ),
+                ],
+                false,
+            )
+        ),
+        mandatory!("drb-Identity", &Type::Integer(1, 32)),
+        optional!("reestablishPDCP", &Type::Enumerated(&["true"])),
+        optional!("recoverPDCP", &Type::Enumerated(&["true"])),
+        optional!("pdcp-Config", &Type::Unsupported),
+    ],
+    true,
+);
+
+static RADIO_BEARER_CONFIG: Type = Type::Sequence(
+    &[
+        optional!(
+            "srb-ToAddModList",
+            &Type::SequenceOf(
+                &Type::Sequence(
+                    &[
+                        mandatory!("srb-Identity", &Type::Integer(1, 3)),
+                        optional!("reestablishPDCP", &Type::Enumerated(&["true"])),
+                        optional!("discardOnPDCP", &Type::Enumerated(&["true"])),
+                        optional!("pdcp-Config", &Type::Unsupported),
+                    ],
+                    true,
+                ),
+                Size(1, Some(2)),
+            )
+        ),
+        optional!("srb3-ToRelease", &Type::Enumerated(&["true"])),
+        optional!(
+            "drb-ToAddModList",
+            &Type::SequenceOf(&DRB_TO_ADD_MOD, Size(1, Some(29)))
+        ),
+        optional!(
+            "drb-ToReleaseList",
+            &Type::SequenceOf(&Type::Integer(1, 32), Size(1, Some(29)))
+        ),
+        optional!(
+            "securityConfig",
+            &Type::Sequence(
+                &[
+                    optional!("securityAlgorithmConfig", &SECURITY_ALGORITHM_CONFIG),
+                    optional!("keyToUse", &Type::Enumerated(&["master", "secondary"])),
+                ],
+                true,
+            )
+        ),
+    ],
+    true,
+);
+
+// DL-CCCH
+
+static RRC_REJECT: Type = Type::Sequence(
+    &[critical_extensions!(
+        "rrcReject",
+        &Type::Sequence(
+            &[
+                optional!("waitTime", &Type::Integer(1, 16)),
+                optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                optional!("nonCriticalExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static RRC_SETUP: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "rrcSetup",
+            &Type::Sequence(
+                &[
+                    mandatory!("radioBearerConfig", &RADIO_BEARER_CONFIG),
+                    mandatory!("masterCellGroup", &Type::OctetString(UNBOUNDED)),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DL_CCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("rrcReject", &RRC_REJECT),
+                            ("rrcSetup", &RRC_SETUP),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &EMPTY),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// UL-DCCH
+
+static RRC_RECONFIGURATION_COMPLETE: Type = acknowledgement!("rrcReconfigurationComplete");
+static RRC_REESTABLISHMENT_COMPLETE: Type = acknowledgement!("rrcReestablishmentComplete");
+static SECURITY_MODE_COMPLETE: Type = acknowledgement!("securityModeComplete");
+static SECURITY_MODE_FAILURE: Type = acknowledgement!("securityModeFailure");
+
+static RRC_SETUP_COMPLETE: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "rrcSetupComplete",
+            &Type::Sequence(
+                &[
+                    mandatory!("selectedPLMN-Identity", &Type::Integer(1, 12)),
+                    optional!(
+                        "registeredAMF",
+                        &Type::Sequence(
+                            &[
+                                optional!("plmn-Identity", &PLMN_IDENTITY),
+                                mandatory!("amf-Identifier", &Type::BitString(Size(24, Some(24)))),
+                            ],
+                            false,
+                        )
+                    ),
+                    optional!("guami-Type", &Type::Enumerated(&["native", "mapped"])),
+                    optional!(
+                        "s-NSSAI-List",
+                        &Type::SequenceOf(
+                            &Type::Choice(
+                                &[
+                                    ("sst", &Type::BitString(Size(8, Some(8)))),
+                                    ("sst-SD", &Type::BitString(Size(32, Some(32)))),
+                                ],
+                                false,
+                            ),
+                            Size(1, Some(8)),
+                        )
+                    ),
+                    mandatory!("dedicatedNAS-Message", &DEDICATED_NAS_MESSAGE),
+                    optional!(
+                        "ng-5G-S-TMSI-Value",
+                        &Type::Choice(
+                            &[
+                                ("ng-5G-S-TMSI", &Type::BitString(Size(48, Some(48)))),
+                                ("ng-5G-S-TMSI-Part2", &Type::BitString(Size(9, Some(9)))),
+                            ],
+                            false,
+                        )
+                    ),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static UL_INFORMATION_TRANSFER: Type = Type::Sequence(
+    &[critical_extensions!(
+        "ulInformationTransfer",
+        &Type::Sequence(
+            &[
+                optional!("dedicatedNAS-Message", &DEDICATED_NAS_MESSAGE),
+                optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                optional!("nonCriticalExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UE_CAPABILITY_INFORMATION: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "ueCapabilityInformation",
+            &Type::Sequence(
+                &[
+                    optional!(
+                        "ue-CapabilityRAT-ContainerList",
+                        &Type::SequenceOf(
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("rat-Type", &RAT_TYPE),
+                                    mandatory!(
+                                        "ue-CapabilityRAT-Container",
+                                        &Type::OctetString(UNBOUNDED)
+                                    ),
+                                ],
+                                false,
+                            ),
+                            Size(0, Some(8)),
+                        )
+                    ),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static MEAS_QUANTITY_RESULTS: Type = Type::Sequence(
+    &[
+        optional!("rsrp", &Type::Integer(0, 127)),
+        optional!("rsrq", &Type::Integer(0, 127)),
+        optional!("sinr", &Type::Integer(0, 127)),
+    ],
+    false,
+);
+
+static MEAS_RESULT_NR: Type = Type::Sequence(
+    &[
+        optional!("physCellId", &Type::Integer(0, 1007)),
+        mandatory!(
+            "measResult",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "cellResults",
+                        &Type::Sequence(
+                            &[
+                                optional!("resultsSSB-Cell", &MEAS_QUANTITY_RESULTS),
+                                optional!("resultsCSI-RS-Cell", &MEAS_QUANTITY_RESULTS),
+                            ],
+                            false,
+                        )
+                    ),
+                    optional!("rsIndexResults", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    true,
+);
+
+static MEASUREMENT_REPORT: Type = Type::Sequence(
+    &[critical_extensions!(
+        "measurementReport",
+        &Type::Sequence(
+            &[
+                mandatory!(
+                    "measResults",
+                    &Type::Sequence(
+                        &[
+                            mandatory!("measId", &Type::Integer(1, 64)),
+                            mandatory!(
+                                "measResultServingMOList",
+                                &Type::SequenceOf(
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!("servCellId", &Type::Integer(0, 31)),
+                                            mandatory!("measResultServingCell", &MEAS_RESULT_NR),
+                                            optional!("measResultBestNeighCell", &MEAS_RESULT_NR),
+                                        ],
+                                        true,
+                                    ),
+                                    Size(1, Some(32)),
+                                )
+                            ),
+                            optional!("measResultNeighCells", &Type::Unsupported),
+                        ],
+                        true,
+                    )
+                ),
+                optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                optional!("nonCriticalExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+static UL_DCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("measurementReport", &MEASUREMENT_REPORT),
+                            ("rrcReconfigurationComplete", &RRC_RECONFIGURATION_COMPLETE),
+                            ("rrcSetupComplete", &RRC_SETUP_COMPLETE),
+                            ("rrcReestablishmentComplete", &RRC_REESTABLISHMENT_COMPLETE),
+                            ("rrcResumeComplete", &Type::Unsupported),
+                            ("securityModeComplete", &SECURITY_MODE_COMPLETE),
+                            ("securityModeFailure", &SECURITY_MODE_FAILURE),
+                            ("ulInformationTransfer", &UL_INFORMATION_TRANSFER),
+                            ("locationMeasurementIndication", &Type::Unsupported),
+                            ("ueCapabilityInformation", &UE_CAPABILITY_INFORMATION),
+                            ("counterCheckResponse", &Type::Unsupported),
+                            ("ueAssistanceInformation", &Type::Unsupported),
+                            ("failureInformation", &Type::Unsupported),
+                            ("ulInformationTransferMRDC", &Type::Unsupported),
+                            ("scgFailureInformation", &Type::Unsupported),
+                            ("scgFailureInformationEUTRA", &Type::Unsupported),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+// DL-DCCH
+
+static RRC_RECONFIGURATION: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "rrcReconfiguration",
+            &Type::Sequence(
+                &[
+                    optional!("radioBearerConfig", &RADIO_BEARER_CONFIG),
+                    optional!("secondaryCellGroup", &Type::OctetString(UNBOUNDED)),
+                    optional!("measConfig", &Type::Unsupported),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!(
+                        "nonCriticalExtension",
+                        &Type::Sequence(
+                            &[
+                                optional!("masterCellGroup", &Type::OctetString(UNBOUNDED)),
+                                optional!("fullConfig", &Type::Enumerated(&["true"])),
+                                optional!(
+                                    "dedicatedNAS-MessageList",
+                                    &Type::SequenceOf(&DEDICATED_NAS_MESSAGE, Size(1, Some(29)))
+                                ),
+                                optional!(
+                                    "masterKeyUpdate",
+                                    &Type::Sequence(
+                                        &[
+                                            mandatory!("keySetChangeIndicator", &Type::Boolean),
+                                            mandatory!(
+                                                "nextHopChainingCount",
+                                                &Type::Integer(0, 7)
+                                            ),
+                                            optional!(
+                                                "nas-Container",
+                                                &Type::OctetString(UNBOUNDED)
+                                            ),
+                                        ],
+                                        true,
+                                    )
+                                ),
+                                optional!("dedicatedSIB1-Delivery", &Type::OctetString(UNBOUNDED)),
+                                optional!(
+                                    "dedicatedSystemInformationDelivery",
+                                    &Type::OctetString(UNBOUNDED)
+                                ),
+                                optional!("otherConfig", &Type::Unsupported),
+                                optional!("nonCriticalExtension", &Type::Unsupported),
+                            ],
+                            false,
+                        )
+                    ),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static RRC_RELEASE: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "rrcRelease",
+            &Type::Sequence(
+                &[
+                    optional!("redirectedCarrierInfo", &Type::Unsupported),
+                    optional!("cellReselectionPriorities", &Type::Unsupported),
+                    optional!("suspendConfig", &Type::Unsupported),
+                    optional!(
+                        "deprioritisationReq",
+                        &Type::Sequence(
+                            &[
+                                mandatory!(
+                                    "deprioritisationType",
+                                    &Type::Enumerated(&["frequency", "nr"])
+                                ),
+                                mandatory!(
+                                    "deprioritisationTimer",
+                                    &Type::Enumerated(&["min5", "min10", "min15", "min30"])
+                                ),
+                            ],
+                            false,
+                        )
+                    ),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static SECURITY_MODE_COMMAND: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "securityModeCommand",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "securityConfigSMC",
+                        &Type::Sequence(
+                            &[mandatory!(
+                                "securityAlgorithmConfig",
+                                &SECURITY_ALGORITHM_CONFIG
+                            )],
+                            true,
+                        )
+                    ),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DL_INFORMATION_TRANSFER: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "dlInformationTransfer",
+            &Type::Sequence(
+                &[
+                    optional!("dedicatedNAS-Message", &DEDICATED_NAS_MESSAGE),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("nonCriticalExtension", &Type::Unsupported),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static UE_CAPABILITY_ENQUIRY: Type = Type::Sequence(
+    &[
+        mandatory!("rrc-TransactionIdentifier", &TRANSACTION_ID),
+        critical_extensions!(
+            "ueCapabilityEnquiry",
+            &Type::Sequence(
+                &[
+                    mandatory!(
+                        "ue-CapabilityRAT-RequestList",
+                        &Type::SequenceOf(
+                            &Type::Sequence(
+                                &[
+                                    mandatory!("rat-Type", &RAT_TYPE),
+                                    optional!(
+                                        "capabilityRequestFilter",
+                                        &Type::OctetString(UNBOUNDED)
+                                    ),
+                                ],
+                                true,
+                            ),
+                            Size(1, Some(8)),
+                        )
+                    ),
+                    optional!("lateNonCriticalExtension", &LATE_NON_CRITICAL_EXTENSION),
+                    optional!("ue-CapabilityEnquiryExt", &Type::OctetString(UNBOUNDED)),
+                ],
+                false,
+            )
+        ),
+    ],
+    false,
+);
+
+static DL_DCCH_MESSAGE: Type = Type::Sequence(
+    &[mandatory!(
+        "message",
+        &Type::Choice(
+            &[
+                (
+                    "c1",
+                    &Type::Choice(
+                        &[
+                            ("rrcReconfiguration", &RRC_RECONFIGURATION),
+                            ("rrcResume", &Type::Unsupported),
+                            ("rrcRelease", &RRC_RELEASE),
+                            ("rrcReestablishment", &Type::Unsupported),
+                            ("securityModeCommand", &SECURITY_MODE_COMMAND),
+                            ("dlInformationTransfer", &DL_INFORMATION_TRANSFER),
+                            ("ueCapabilityEnquiry", &UE_CAPABILITY_ENQUIRY),
+                            ("counterCheck", &Type::Unsupported),
+                            ("mobilityFromNRCommand", &Type::Unsupported),
+                            ("dlDedicatedMessageSegment-r16", &Type::Unsupported),
+                            ("ueInformationRequest-r16", &Type::Unsupported),
+                            ("dlInformationTransferMRDC-r16", &Type::Unsupported),
+                            ("loggedMeasurementConfiguration-r16", &Type::Unsupported),
+                            ("spare3", &Type::Null),
+                            ("spare2", &Type::Null),
+                            ("spare1", &Type::Null),
+                        ],
+                        false,
+                    ),
+                ),
+                ("messageClassExtension", &Type::Unsupported),
+            ],
+            false,
+        )
+    )],
+    false,
+);
+
+/// Top level type of a channel
+pub fn message_type(channel: Channel) -> (&'static str, &'static Type) {
+    match channel {
+        Channel::BcchBch => ("BCCH-BCH-Message", &BCCH_BCH_MESSAGE),
+        Channel::BcchDlSch => ("BCCH-DL-SCH-Message", &BCCH_DL_SCH_MESSAGE),
+        Channel::Pcch => ("PCCH-Message", &PCCH_MESSAGE),
+        Channel::UlCcch => ("UL-CCCH-Message", &UL_CCCH_MESSAGE),
+        Channel::DlCcch => ("DL-CCCH-Message", &DL_CCCH_MESSAGE),
+        Channel::UlDcch => ("UL-DCCH-Message", &UL_DCCH_MESSAGE),
+        Channel::DlDcch => ("DL-DCCH-Message", &DL_DCCH_MESSAGE),
+    }
+}
@@ -0,0 +1,645 @@
+//! Subset of the S1AP IEs (3GPP TS 36.413)
+
+use super::ap::{
+    ie, procedure, raw, Ie, Procedure, Protocol, CRITICALITY, IE_EXTENSIONS, PROTOCOL_IE_ID,
+};
+use super::per::{mandatory, optional, Size, Type, UNBOUNDED};
+
+pub static PROTOCOL: Protocol = Protocol {
+    name: "S1AP",
+    procedures: PROCEDURES,
+    ies: IES,
+};
+
+static PROCEDURES: &[Procedure] = &[
+    procedure(
+        0,
+        "HandoverRequired",
+        "HandoverCommand",
+        "HandoverPreparationFailure",
+    ),
+    procedure(
+        1,
+        "HandoverRequest",
+        "HandoverRequestAcknowledge",
+        "HandoverFailure",
+    ),
+    procedure(2, "HandoverNotify", "", ""),
+    procedure(
+        3,
+        "PathSwitchRequest",
+        "PathSwitchRequestAcknowledge",
+        "PathSwitchRequestFailure",
+    ),
+    procedure(4, "HandoverCancel", "HandoverCancelAcknowledge", ""),
+    procedure(5, "E-RABSetupRequest", "E-RABSetupResponse", ""),
+    procedure(6, "E-RABModifyRequest", "E-RABModifyResponse", ""),
+    procedure(7, "E-RABReleaseCommand", "E-RABReleaseResponse", ""),
+    procedure(8, "E-RABReleaseIndication", "", ""),
+    procedure(
+        9,
+        "InitialContextSetupRequest",
+        "InitialContextSetupResponse",
+        "InitialContextSetupFailure",
+    ),
+    procedure(10, "Paging", "", ""),
+    procedure(11, "DownlinkNASTransport", "", ""),
+    procedure(12, "InitialUEMessage", "", ""),
+    procedure(13, "UplinkNASTransport", "", ""),
+    procedure(14, "Reset", "ResetAcknowledge", ""),
+    procedure(15, "ErrorIndication", "", ""),
+    procedure(16, "NASNonDeliveryIndication", "", ""),
+    procedure(17, "S1SetupRequest", "S1SetupResponse", "S1SetupFailure"),
+    procedure(18, "UEContextReleaseRequest", "", ""),
+    procedure(19, "DownlinkS1cdma2000tunnelling", "", ""),
+    procedure(20, "UplinkS1cdma2000tunnelling", "", ""),
+    procedure(
+        21,
+        "UEContextModificationRequest",
+        "UEContextModificationResponse",
+        "UEContextModificationFailure",
+    ),
+    procedure(22, "UECapabilityInfoIndication", "", ""),
+    procedure(
+        23,
+        "UEContextReleaseCommand",
+        "UEContextReleaseComplete",
+        "",
+    ),
+    procedure(24, "ENBStatusTransfer", "", ""),
+    procedure(25, "MMEStatusTransfer", "", ""),
+    procedure(26, "DeactivateTrace", "", ""),
+    procedure(27, "TraceStart", "", ""),
+    procedure(28, "TraceFailureIndication", "", ""),
+    procedure(
+        29,
+        "ENBConfigurationUpdate",
+        "ENBConfigurationUpdateAcknowledge",
+        "ENBConfigurationUpdateFailure",
+    ),
+    procedure(
+        30,
+        "MMEConfigurationUpdate",
+        "MMEConfigurationUpdateAcknowledge",
+        "MMEConfigurationUpdateFailure",
+    ),
+    procedure(31, "LocationReportingControl", "", ""),
+    procedure(32, "LocationReportingFailureIndication", "", ""),
+    procedure(33, "LocationReport", "", ""),
+    procedure(34, "OverloadStart", "", ""),
+    procedure(35, "OverloadStop", "", ""),
+    procedure(
+        36,
+        "WriteReplaceWarningRequest",
+        "WriteReplaceWarningResponse",
+        "",
+    ),
+    procedure(37, "ENBDirectInformationTransfer", "", ""),
+    procedure(38, "MMEDirectInformationTransfer", "", ""),
+    procedure(39, "PrivateMessage", "", ""),
+    procedure(40, "ENBConfigurationTransfer", "", ""),
+    procedure(41, "MMEConfigurationTransfer", "", ""),
+    procedure(42, "CellTrafficTrace", "", ""),
+    procedure(43, "KillRequest", "KillResponse", ""),
+    procedure(44, "DownlinkUEAssociatedLPPaTransport", "", ""),
+    procedure(45, "UplinkUEAssociatedLPPaTransport", "", ""),
+    procedure(46, "DownlinkNonUEAssociatedLPPaTransport", "", ""),
+    procedure(47, "UplinkNonUEAssociatedLPPaTransport", "", ""),
+    procedure(
+        48,
+        "UERadioCapabilityMatchRequest",
+        "UERadioCapabilityMatchResponse",
+        "",
+    ),
+    procedure(49, "PWSRestartIndication", "", ""),
+    procedure(
+        50,
+        "E-RABModificationIndication",
+        "E-RABModificationConfirm",
+        "",
+    ),
+    procedure(51, "PWSFailureIndication", "", ""),
+    procedure(52, "RerouteNASRequest", "", ""),
+    procedure(
+        53,
+        "UEContextModificationIndication",
+        "UEContextModificationConfirm",
+        "",
+    ),
+    procedure(54, "ConnectionEstablishmentIndication", "", ""),
+    procedure(
+        55,
+        "UEContextSuspendRequest",
+        "UEContextSuspendResponse",
+        "",
+    ),
+    procedure(
+        56,
+        "UEContextResumeRequest",
+        "UEContextResumeResponse",
+        "UEContextResumeFailure",
+    ),
+    procedure(57, "NASDeliveryIndication", "", ""),
+    procedure(58, "RetrieveUEInformation", "", ""),
+    procedure(59, "UEInformationTransfer", "", ""),
+    procedure(60, "ENBCPRelocationIndication", "", ""),
+    procedure(61, "MMECPRelocationIndication", "", ""),
+    procedure(62, "SecondaryRATDataUsageReport", "", ""),
+];
+
+static MME_UE_S1AP_ID: Type = Type::Integer(0, 4294967295);
+static ENB_UE_S1AP_ID: Type = Type::Integer(0, 16777215);
+static NAS_PDU: Type = Type::External(UNBOUNDED, super::nas::decode_container);
+static PLMN_IDENTITY: Type = Type::OctetString(Size(3, Some(3)));
+static TAC: Type = Type::OctetString(Size(2, Some(2)));
+static BIT_RATE: Type = Type::Integer(0, 10000000000);
+static E_RAB_ID: Type = Type::IntegerExt(0, 15);
+static TRANSPORT_LAYER_ADDRESS: Type = Type::SizeExt(&Type::BitString(Size(1, Some(160))));
+static GTP_TEID: Type = Type::OctetString(Size(4, Some(4)));
+static ALGORITHMS: Type = Type::SizeExt(&Type::BitString(Size(16, Some(16))));
+
+static CAUSE: Type = Type::Choice(
+    &[
+        (
+            "radioNetwork",
+            &Type::EnumeratedExt(
+                &[
+                    "unspecified",
+                    "tx2relocoverall-expiry",
+                    "successful-handover",
+                    "release-due-to-eutran-generated-reason",
+                    "handover-cancelled",
+                    "partial-handover",
+                    "ho-failure-in-target-EPC-eNB-or-target-system",
+                    "ho-target-not-allowed",
+                    "tS1relocoverall-expiry",
+                    "tS1relocprep-expiry",
+                    "cell-not-available",
+                    "unknown-targetID",
+                    "no-radio-resources-available-in-target-cell",
+                    "unknown-mme-ue-s1ap-id",
+                    "unknown-enb-ue-s1ap-id",
+                    "unknown-pair-ue-s1ap-id",
+                    "handover-desirable-for-radio-reason",
+                    "time-critical-handover",
+                    "resource-optimisation-handover",
+                    "reduce-load-in-serving-cell",
+                    "user-inactivity",
+                    "radio-connection-with-ue-lost",
+                    "load-balancing-tau-required",
+                    "cs-fallback-triggered",
+                    "ue-not-available-for-ps-service",
+                    "radio-resources-not-available",
+                    "failure-in-radio-interface-procedure",
+                    "invalid-qos-combination",
+                    "interrat-redirection",
+                    "interaction-with-other-procedure",
+                    "unknown-E-RAB-ID",
+                    "multiple-E-RAB-ID-instances",
+                    "encryption-and-or-integrity-protection-algorithms-not-supported",
+                    "s1-intra-system-handover-triggered",
+                    "s1-inter-system-handover-triggered",
+                    "x2-handover-triggered",
+                ],
+                &[
+                    "redirection-towards-1xRTT",
+                    "not-supported-QCI-value",
+                    "invalid-CSG-Id",
+                    "release-due-to-pre-emption",
+                ],
+            ),
+        ),
+        (
+            "transport",
+            &Type::EnumeratedExt(&["transport-resource-unavailable", "unspecified"], &[]),
+        ),
+        (
+            "nas",
+            &Type::EnumeratedExt(
+                &[
+                    "normal-release",
+                    "authentication-failure",
+                    "detach",
+                    "unspecified",
+                ],
+                &["csg-subscription-expiry", "uE-not-in-PLMN-serving-area"],
+            ),
+        ),
+        (
+            "protocol",
+            &Type::EnumeratedExt(
+                &[
+                    "transfer-syntax-error",
+                    "abstract-syntax-error-reject",
+                    "abstract-syntax-error-ignore-and-notify",
+                    "message-not-compatible-with-receiver-state",
+                    "semantic-error",
+                    "abstract-syntax-error-falsely-constructed-message",
+                    "unspecified",
+                ],
+                &[],
+            ),
+        ),
+        (
+            "misc",
+            &Type::EnumeratedExt(
+                &[
+                    "control-processing-overload",
+                    "not-enough-user-plane-processing-resources",
+                    "hardware-failure",
+                    "om-intervention",
+                    "unspecified",
+                    "unknown-PLMN",
+                ],
+                &[],
+            ),
+        ),
+    ],
+    true,
+);
+
+static TAI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNidentity", &PLMN_IDENTITY),
+        mandatory!("tAC", &TAC),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static EUTRAN_CGI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNidentity", &PLMN_IDENTITY),
+        mandatory!("cell-ID", &Type::BitString(Size(28, Some(28)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static S_TMSI: Type = Type::Sequence(
+    &[
+        mandatory!("mMEC", &Type::OctetString(Size(1, Some(1)))),
+        mandatory!("m-TMSI", &Type::OctetString(Size(4, Some(4)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static GUMMEI: Type = Type::Sequence(
+    &[
+        mandatory!("pLMN-Identity", &PLMN_IDENTITY),
+        mandatory!("mME-Group-ID", &Type::OctetString(Size(2, Some(2)))),
+        mandatory!("mME-Code", &Type::OctetString(Size(1, Some(1)))),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static UE_AGGREGATE_MAXIMUM_BITRATE: Type = Type::Sequence(
+    &[
+        mandatory!("uEaggregateMaximumBitRateDL", &BIT_RATE),
+        mandatory!("uEaggregateMaximumBitRateUL", &BIT_RATE),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static UE_SECURITY_CAPABILITIES: Type = Type::Sequence(
+    &[
+        mandatory!("encryptionAlgorithms", &ALGORITHMS),
+        mandatory!("integrityProtectionAlgorithms", &ALGORITHMS),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static GLOBAL_ENB_ID: Type = Type::Sequence(
+    &[
+        mandatory!("pLMNidentity", &PLMN_IDENTITY),
+        mandatory!(
+            "eNB-ID",
+            &Type::Choice(
+                &[
+                    ("macroENB-ID", &Type::BitString(Size(20, Some(20)))),
+                    ("homeENB-ID", &Type::BitString(Size(28, Some(28)))),
+                ],
+                true,
+            )
+        ),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static SUPPORTED_TAS: Type = Type::SequenceOf(
+    &Type::Sequence(
+        &[
+            mandatory!("tAC", &TAC),
+            mandatory!(
+                "broadcastPLMNs",
+                &Type::SequenceOf(&PLMN_IDENTITY, Size(1, Some(6)))
+            ),
+            optional!("iE-Extensions", &IE_EXTENSIONS),
+        ],
+        true,
+    ),
+    Size(1, Some(256)),
+);
+
+static PAGING_DRX: Type = Type::EnumeratedExt(&["v32", "v64", "v128", "v256"], &[]);
+
+static UE_PAGING_ID: Type = Type::Choice(
+    &[
+        ("s-TMSI", &S_TMSI),
+        ("iMSI", &Type::OctetString(Size(3, Some(8)))),
+    ],
+    true,
+);
+
+static UE_S1AP_IDS: Type = Type::Choice(
+    &[
+        (
+            "uE-S1AP-ID-pair",
+            &Type::Sequence(
+                &[
+                    mandatory!("mME-UE-S1AP-ID", &MME_UE_S1AP_ID),
+                    mandatory!("eNB-UE-S1AP-ID", &ENB_UE_S1AP_ID),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            ),
+        ),
+        ("mME-UE-S1AP-ID", &MME_UE_S1AP_ID),
+    ],
+    true,
+);
+
+static ALLOCATION_RETENTION_PRIORITY: Type = Type::Sequence(
+    &[
+        mandatory!("priorityLevel", &Type::Integer(0, 15)),
+        mandatory!(
+            "pre-emptionCapability",
+            &Type::Enumerated(&["shall-not-trigger-pre-emption", "may-trigger-pre-emption"])
+        ),
+        mandatory!(
+            "pre-emptionVulnerability",
+            &Type::Enumerated(&["not-pre-emptable", "pre-emptable"])
+        ),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static E_RAB_LEVEL_QOS_PARAMETERS: Type = Type::Sequence(
+    &[
+        mandatory!("qCI", &Type::Integer(0, 255)),
+        mandatory!(
+            "allocationRetentionPriority",
+            &ALLOCATION_RETENTION_PRIORITY
+        ),
+        optional!(
+            "gbrQosInformation",
+            &Type::Sequence(
+                &[
+                    mandatory!("e-RAB-MaximumBitrateDL", &BIT_RATE),
+                    mandatory!("e-RAB-MaximumBitrateUL", &BIT_RATE),
+                    mandatory!("e-RAB-GuaranteedBitrateDL", &BIT_RATE),
+                    mandatory!("e-RAB-GuaranteedBitrateUL", &BIT_RATE),
+                    optional!("iE-Extensions", &IE_EXTENSIONS),
+                ],
+                true,
+            )
+        ),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+/// E-RAB-IE-ContainerList holding the same IE in each item
+macro_rules! e_rab_list {
+    ($item:expr) => {
+        Type::SequenceOf(
+            &Type::Sequence(
+                &[
+                    mandatory!("id", &PROTOCOL_IE_ID),
+                    mandatory!("criticality", &CRITICALITY),
+                    mandatory!("value", &Type::Containing($item)),
+                ],
+                false,
+            ),
+            Size(1, Some(256)),
+        )
+    };
+}
+
+static E_RAB_TO_BE_SETUP_ITEM_CTXT_SU_REQ: Type = Type::Sequence(
+    &[
+        mandatory!("e-RAB-ID", &E_RAB_ID),
+        mandatory!("e-RABlevelQoSParameters", &E_RAB_LEVEL_QOS_PARAMETERS),
+        mandatory!("transportLayerAddress", &TRANSPORT_LAYER_ADDRESS),
+        mandatory!("gTP-TEID", &GTP_TEID),
+        optional!("nAS-PDU", &NAS_PDU),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static E_RAB_TO_BE_SETUP_ITEM_BEARER_SU_REQ: Type = Type::Sequence(
+    &[
+        mandatory!("e-RAB-ID", &E_RAB_ID),
+        mandatory!("e-RABlevelQoSParameters", &E_RAB_LEVEL_QOS_PARAMETERS),
+        mandatory!("transportLayerAddress", &TRANSPORT_LAYER_ADDRESS),
+        mandatory!("gTP-TEID", &GTP_TEID),
+        mandatory!("nAS-PDU", &NAS_PDU),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+/// E-RABSetupItemCtxtSURes and E-RABSetupItemBearerSURes
+static E_RAB_SETUP_ITEM: Type = Type::Sequence(
+    &[
+        mandatory!("e-RAB-ID", &E_RAB_ID),
+        mandatory!("transportLayerAddress", &TRANSPORT_LAYER_ADDRESS),
+        mandatory!("gTP-TEID", &GTP_TEID),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static E_RAB_ITEM: Type = Type::Sequence(
+    &[
+        mandatory!("e-RAB-ID", &E_RAB_ID),
+        mandatory!("cause", &CAUSE),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static E_RAB_RELEASE_ITEM_BEARER_REL_COMP: Type = Type::Sequence(
+    &[
+        mandatory!("e-RAB-ID", &E_RAB_ID),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+);
+
+static TAI_LIST: Type = e_rab_list!(&Type::Sequence(
+    &[
+        mandatory!("tAI", &TAI),
+        optional!("iE-Extensions", &IE_EXTENSIONS),
+    ],
+    true,
+));
+
+static IES: &[Ie] = &[
+    ie(0, "MME-UE-S1AP-ID", &MME_UE_S1AP_ID),
+    ie(
+        1,
+        "HandoverType",
+        &Type::EnumeratedExt(
+            &[
+                "intralte",
+                "ltetoutran",
+                "ltetogeran",
+                "utrantolte",
+                "gerantolte",
+            ],
+            &["eps-to-5gs", "fivegs-to-eps"],
+        ),
+    ),
+    ie(2, "Cause", &CAUSE),
+    raw(3, "SourceID"),
+    raw(4, "TargetID"),
+    ie(8, "eNB-UE-S1AP-ID", &ENB_UE_S1AP_ID),
+    ie(
+        15,
+        "E-RABReleaseItemBearerRelComp",
+        &E_RAB_RELEASE_ITEM_BEARER_REL_COMP,
+    ),
+    ie(
+        16,
+        "E-RABToBeSetupListBearerSUReq",
+        &e_rab_list!(&E_RAB_TO_BE_SETUP_ITEM_BEARER_SU_REQ),
+    ),
+    ie(
+        17,
+        "E-RABToBeSetupItemBearerSUReq",
+        &E_RAB_TO_BE_SETUP_ITEM_BEARER_SU_REQ,
+    ),
+    ie(
+        24,
+        "E-RABToBeSetupListCtxtSUReq",
+        &e_rab_list!(&E_RAB_TO_BE_SETUP_ITEM_CTXT_SU_REQ),
+    ),
+    raw(25, "TraceActivation"),
+    ie(26, "NAS-PDU", &NAS_PDU),
+    ie(
+        28,
+        "E-RABSetupListBearerSURes",
+        &e_rab_list!(&E_RAB_SETUP_ITEM),
+    ),
+    ie(
+        29,
+        "E-RABFailedToSetupListBearerSURes",
+        &e_rab_list!(&E_RAB_ITEM),
+    ),
+    ie(33, "E-RABToBeReleasedList", &e_rab_list!(&E_RAB_ITEM)),
+    ie(34, "E-RABFailedToReleaseList", &e_rab_list!(&E_RAB_ITEM)),
+    ie(35, "E-RABItem", &E_RAB_ITEM),
+    ie(39, "E-RABSetupItemBearerSURes", &E_RAB_SETUP_ITEM),
+    raw(40, "SecurityContext"),
+    raw(41, "HandoverRestrictionList"),
+    ie(43, "UEPagingID", &UE_PAGING_ID),
+    ie(44, "pagingDRX", &PAGING_DRX),
+    ie(46, "TAIList", &TAI_LIST),
+    ie(
+        48,
+        "E-RABFailedToSetupListCtxtSURes",
+        &e_rab_list!(&E_RAB_ITEM),
+    ),
+    ie(50, "E-RABSetupItemCtxtSURes", &E_RAB_SETUP_ITEM),
+    ie(
+        51,
+        "E-RABSetupListCtxtSURes",
+        &e_rab_list!(&E_RAB_SETUP_ITEM),
+    ),
+    ie(
+        52,
+        "E-RABToBeSetupItemCtxtSUReq",
+        &E_RAB_TO_BE_SETUP_ITEM_CTXT_SU_REQ,
+    ),
+    raw(58, "CriticalityDiagnostics"),
+    ie(59, "Global-ENB-ID", &GLOBAL_ENB_ID),
+    raw(60, "eNBname"),
+    raw(61, "MMEname"),
+    ie(
+        63,
+        "ServedPLMNs",
+        &Type::SequenceOf(&PLMN_IDENTITY, Size(1, Some(32))),
+    ),
+    ie(64, "SupportedTAs", &SUPPORTED_TAS),
+    ie(
+        65,
+        "TimeToWait",
+        &Type::EnumeratedExt(&["v1s", "v2s", "v5s", "v10s", "v20s", "v60s"], &[]),
+    ),
+    ie(
+        66,
+        "uEaggregateMaximumBitrate",
+        &UE_AGGREGATE_MAXIMUM_BITRATE,
+    ),
+    ie(67, "TAI", &TAI),
+    ie(
+        69,
+        "E-RABReleaseListBearerRelComp",
+        &e_rab_list!(&E_RAB_RELEASE_ITEM_BEARER_REL_COMP),
+    ),
+    ie(73, "SecurityKey", &Type::BitString(Size(256, Some(256)))),
+    raw(74, "UERadioCapability"),
+    ie(75, "GUMMEI-ID", &GUMMEI),
+    ie(
+        80,
+        "UEIdentityIndexValue",
+        &Type::BitString(Size(10, Some(10))),
+    ),
+    ie(87, "RelativeMMECapacity", &Type::Integer(0, 255)),
+    ie(88, "SourceMME-UE-S1AP-ID", &MME_UE_S1AP_ID),
+    raw(92, "ResetType"),
+    ie(96, "S-TMSI", &S_TMSI),
+    ie(99, "UE-S1AP-IDs", &UE_S1AP_IDS),
+    ie(100, "EUTRAN-CGI", &EUTRAN_CGI),
+    raw(104, "Source-ToTarget-TransparentContainer"),
+    raw(105, "ServedGUMMEIs"),
+    ie(106, "SubscriberProfileIDforRFP", &Type::Integer(1, 256)),
+    ie(107, "UESecurityCapabilities", &UE_SECURITY_CAPABILITIES),
+    ie(
+        108,
+        "CSFallbackIndicator",
+        &Type::EnumeratedExt(&["cs-fallback-required"], &["cs-fallback-high-priority"]),
+    ),
+    ie(109, "CNDomain", &Type::Enumerated(&["ps", "cs"])),
+    ie(110, "E-RABReleasedList", &e_rab_list!(&E_RAB_ITEM)),
+    raw(123, "Target-ToSource-TransparentContainer"),
+    ie(
+        134,
+        "RRC-Establishment-Cause",
+        &Type::EnumeratedExt(
+            &[
+                "emergency",
+                "highPriorityAccess",
+                "mt-Access",
+                "mo-Signalling",
+                "mo-Data",
+            ],
+            &["delay-TolerantAccess", "mo-VoiceCall", "mo-ExceptionData"],
+        ),
+    ),
+    ie(137, "DefaultPagingDRX", &PAGING_DRX),
+    ie(158, "MME-UE-S1AP-ID-2", &MME_UE_S1AP_ID),
+    ie(
+        170,
+        "GUMMEIType",
+        &Type::EnumeratedExt(&["native", "mapped"], &["mappedFrom5G"]),
+    ),
+];
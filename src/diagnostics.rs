@@ -0,0 +1,151 @@
+//! Protocol traffic other than logs: messages of the remote API, decode
+//! errors and unexpected frames
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// Number of entries kept, the oldest are dropped first
+const MAX_ENTRIES: usize = 500;
+/// Characters of a message kept in a decode error
+const MAX_EXCERPT: usize = 2000;
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub number: usize, // position among all the entries ever recorded
+    pub kind: String,  // message name, "decode error", ...
+    pub message_id: Option<u64>,
+    pub text: String, // pretty-printed JSON or error details
+    pub is_error: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    pub entries: VecDeque<Entry>,
+    /// Messages received by name, log replies included
+    pub counts: BTreeMap<String, usize>,
+    pub decode_errors: usize,
+    recorded: usize,
+}
+
+impl Diagnostics {
+    fn push(&mut self, kind: String, message_id: Option<u64>, text: String, is_error: bool) {
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.recorded += 1;
+        self.entries.push_back(Entry {
+            number: self.recorded,
+            kind,
+            message_id,
+            text,
+            is_error,
+        });
+    }
+
+    /// Count a message of the remote API and keep it unless it carries logs
+    pub fn message(&mut self, name: &str, message_id: Option<u64>, raw: &str, has_logs: bool) {
+        *self.counts.entry(name.to_owned()).or_default() += 1;
+        if has_logs {
+            return;
+        }
+        let text = serde_json::from_str::<serde_json::Value>(raw)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .unwrap_or_else(|_| raw.to_owned());
+        self.push(name.to_owned(), message_id, text, false);
+    }
+
+    /// A text that is not a valid message, `error` was returned decoding it
+    pub fn decode_error(&mut self, error: &serde_json::Error, text: &str) {
+        self.decode_errors += 1;
+        let excerpt: String = text.chars().take(MAX_EXCERPT).collect();
+        let details = format!("{}\n\n{}", describe(error, text), excerpt);
+        self.push("decode error".to_owned(), None, details, true);
+    }
+
+    /// Logs of a message that could not be decoded, the others were kept
+    pub fn skipped_logs(&mut self, message_id: Option<u64>, count: usize, first_error: &str) {
+        self.decode_errors += count;
+        let details = format!("{} malformed logs skipped, first: {}", count, first_error);
+        self.push("decode error".to_owned(), message_id, details, true);
+    }
+
+    /// Something the source received or reported that is not a message
+    pub fn invalid(&mut self, kind: &str, text: String) {
+        self.push(kind.to_owned(), None, text, true);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.counts.clear();
+        self.decode_errors = 0;
+    }
+}
+
+/// Error with the JSON path of its position, e.g.
+/// `$.logs[2].timestamp: invalid type: string "1", expected u64 at line 1 column 42`
+pub fn describe(error: &serde_json::Error, text: &str) -> String {
+    let offset = text
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + error.column().saturating_sub(1); // columns start at 1
+    format!("{}: {}", path_at(text, offset), error)
+}
+
+/// JSON path of the value at a byte offset of a text
+pub fn path_at(text: &str, offset: usize) -> String {
+    enum Level {
+        Object(Option<String>), // key of the current member
+        Array(usize),           // index of the current element
+    }
+    let mut stack = Vec::new();
+    let mut string: Option<String> = None; // string being read
+    let mut last_string = None;
+    let mut escaped = false;
+    for (position, c) in text.char_indices() {
+        if position >= offset {
+            break;
+        }
+        if let Some(current) = &mut string {
+            if escaped {
+                escaped = false;
+                current.push(c);
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                last_string = string.take();
+            } else {
+                current.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' => string = Some(String::new()),
+            '{' => stack.push(Level::Object(None)),
+            '[' => stack.push(Level::Array(0)),
+            '}' | ']' => {
+                stack.pop();
+            }
+            ':' => {
+                if let Some(Level::Object(key)) = stack.last_mut() {
+                    *key = last_string.take();
+                }
+            }
+            ',' => match stack.last_mut() {
+                Some(Level::Array(index)) => *index += 1,
+                Some(Level::Object(key)) => *key = None,
+                None => {}
+            },
+            _ => {}
+        }
+    }
+    let mut path = "$".to_owned();
+    for level in stack {
+        match level {
+            Level::Object(Some(key)) => path.push_str(&format!(".{}", key)),
+            Level::Object(None) => {}
+            Level::Array(index) => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    path
+}
@@ -0,0 +1,70 @@
+use poll_promise::Promise;
+
+/// Ask the user where to save `content`, in the background.
+///
+/// The promise gives the name of the saved file.
+pub fn save_file(file_name: &str, content: Vec<u8>) -> Promise<Result<String, String>> {
+    let file_name = file_name.to_owned();
+    #[cfg(target_arch = "wasm32")]
+    {
+        Promise::spawn_local(async move {
+            let Some(file) = rfd::AsyncFileDialog::new()
+                .set_file_name(&file_name)
+                .save_file()
+                .await
+            else {
+                return Err("No file selected".to_string());
+            };
+            match file.write(&content).await {
+                Ok(()) => Ok(file.file_name()),
+                Err(e) => Err(e.to_string()),
+            }
+        })
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Promise::spawn_thread("save", move || {
+            let Some(path) = rfd::FileDialog::new().set_file_name(file_name).save_file() else {
+                return Err("No file selected".to_string());
+            };
+            match std::fs::write(&path, content) {
+                Ok(()) => Ok(path.display().to_string()),
+                Err(e) => {
+                    log::warn!("{:?}", e);
+                    Err(e.to_string())
+                }
+            }
+        })
+    }
+}
+
+/// Ask the user for a text file to read, in the background.
+///
+/// The promise gives the content and the name of the file.
+pub fn open_file() -> Promise<Result<(String, String), String>> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Promise::spawn_local(async move {
+            let Some(file) = rfd::AsyncFileDialog::new().pick_file().await else {
+                return Err("No file selected".to_string());
+            };
+            let content = String::from_utf8(file.read().await).map_err(|e| e.to_string())?;
+            Ok((content, file.file_name()))
+        })
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Promise::spawn_thread("open", move || {
+            let Some(path) = rfd::FileDialog::new().pick_file() else {
+                return Err("No file selected".to_string());
+            };
+            match std::fs::read_to_string(&path) {
+                Ok(content) => Ok((content, path.display().to_string())),
+                Err(e) => {
+                    log::warn!("{:?}", e);
+                    Err(e.to_string())
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,519 @@
+//! Filter expressions over [`OneLog`] fields, e.g.
+//!
+//! ```text
+//! layer == RRC && dir == DL && cell == 1 && data ~ "rrcConnectionSetup"
+//! ```
+//!
+//! Operators: `==`, `!=`, `<`, `<=`, `>`, `>=`, `~` (contains) and `!~`,
+//! combined with `&&`, `||`, `!` and parentheses. Text comparisons ignore case.
+
+use crate::OneLog;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    Layer,
+    Dir,
+    Cell,
+    UeId,
+    Rnti,
+    Channel,
+    Src,
+    Level,
+    Idx,
+    Timestamp,
+    Message, // first line of data
+    Data,    // any line of data
+}
+
+impl Field {
+    pub const ALL: [(&'static str, Field); 12] = [
+        ("layer", Field::Layer),
+        ("dir", Field::Dir),
+        ("cell", Field::Cell),
+        ("ue_id", Field::UeId),
+        ("rnti", Field::Rnti),
+        ("channel", Field::Channel),
+        ("src", Field::Src),
+        ("level", Field::Level),
+        ("idx", Field::Idx),
+        ("timestamp", Field::Timestamp),
+        ("message", Field::Message),
+        ("data", Field::Data),
+    ];
+
+    fn from_name(name: &str) -> Option<Field> {
+        Self::ALL
+            .iter()
+            .find(|(field_name, _)| field_name.eq_ignore_ascii_case(name))
+            .map(|(_, field)| *field)
+    }
+
+    fn number(self, log: &OneLog) -> Option<u64> {
+        match self {
+            Field::Cell => log.cell,
+            Field::UeId => log.ue_id,
+            Field::Rnti => log.rnti,
+            Field::Level => Some(log.level),
+            Field::Idx => Some(log.idx),
+            Field::Timestamp => Some(log.timestamp),
+            _ => None,
+        }
+    }
+
+    /// Text values of the field, several for `data`
+    fn texts(self, log: &OneLog) -> Vec<&str> {
+        match self {
+            Field::Layer => vec![log.layer.as_str()],
+            Field::Dir => log.dir.as_deref().into_iter().collect(),
+            Field::Channel => log.channel.as_deref().into_iter().collect(),
+            Field::Src => vec![log.src.as_str()],
+            Field::Message => log.data.first().map(String::as_str).into_iter().collect(),
+            Field::Data => log.data.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    NotContains,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    /// The value is lowercased once parsed, text comparisons ignore case
+    Compare(Field, Op, String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn matches(&self, log: &OneLog) -> bool {
+        match self {
+            Expr::Compare(field, op, value) => compare(*field, *op, value, log),
+            Expr::Not(expr) => !expr.matches(log),
+            Expr::And(left, right) => left.matches(log) && right.matches(log),
+            Expr::Or(left, right) => left.matches(log) || right.matches(log),
+        }
+    }
+}
+
+fn compare(field: Field, op: Op, value: &str, log: &OneLog) -> bool {
+    let number = field.number(log);
+    if let (Some(number), Ok(expected)) = (number, value.parse::<u64>()) {
+        match op {
+            Op::Eq => return number == expected,
+            Op::Ne => return number != expected,
+            Op::Lt => return number < expected,
+            Op::Le => return number <= expected,
+            Op::Gt => return number > expected,
+            Op::Ge => return number >= expected,
+            Op::Contains | Op::NotContains => {}
+        }
+    }
+    let number_text = number.map(|number| number.to_string());
+    let texts = match &number_text {
+        Some(text) => vec![text.as_str()],
+        None => field.texts(log),
+    };
+    let contains = |text: &&str| contains_lowercase(text, value);
+    let equals = |text: &&str| text.eq_ignore_ascii_case(value);
+    match op {
+        Op::Eq => texts.iter().any(equals),
+        Op::Ne => !texts.iter().any(equals),
+        Op::Contains => texts.iter().any(contains),
+        Op::NotContains => !texts.iter().any(contains),
+        Op::Lt => texts
+            .iter()
+            .any(|text| text.to_lowercase().as_str() < value),
+        Op::Le => texts
+            .iter()
+            .any(|text| text.to_lowercase().as_str() <= value),
+        Op::Gt => texts
+            .iter()
+            .any(|text| text.to_lowercase().as_str() > value),
+        Op::Ge => texts
+            .iter()
+            .any(|text| text.to_lowercase().as_str() >= value),
+    }
+}
+
+/// Whether `text` contains `pattern` ignoring case, `pattern` is lowercase.
+/// Only a non-ASCII pattern needs a lowercase copy of the text
+pub fn contains_lowercase(text: &str, pattern: &str) -> bool {
+    if !pattern.is_ascii() {
+        return text.to_lowercase().contains(pattern);
+    }
+    let pattern = pattern.as_bytes();
+    pattern.is_empty()
+        || text
+            .as_bytes()
+            .windows(pattern.len())
+            .any(|window| window.eq_ignore_ascii_case(pattern))
+}
+
+#[derive(Debug, Clone)]
+pub struct FilterError {
+    pub position: usize, // byte offset in the expression
+    pub message: String,
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: {}", self.position + 1, self.message)
+    }
+}
+
+/// A parsed filter expression
+#[derive(Debug, Clone)]
+pub struct Filter {
+    pub text: String,
+    pub expr: Expr,
+}
+
+impl Filter {
+    pub fn parse(text: &str) -> Result<Filter, FilterError> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            end: text.len(),
+        };
+        let expr = parser.parse_or()?;
+        if let Some((token, position)) = parser.tokens.get(parser.position) {
+            return Err(FilterError {
+                position: *position,
+                message: format!("unexpected {}", token),
+            });
+        }
+        Ok(Filter {
+            text: text.to_owned(),
+            expr,
+        })
+    }
+
+    pub fn matches(&self, log: &OneLog) -> bool {
+        self.expr.matches(log)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(Op),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "'{}'", word),
+            Token::Quoted(text) => write!(f, "\"{}\"", text),
+            Token::Op(op) => write!(f, "operator {:?}", op),
+            Token::And => write!(f, "'&&'"),
+            Token::Or => write!(f, "'||'"),
+            Token::Not => write!(f, "'!'"),
+            Token::Open => write!(f, "'('"),
+            Token::Close => write!(f, "')'"),
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '/')
+}
+
+fn next_is(chars: &mut Peekable<CharIndices<'_>>, expected: char) -> bool {
+    chars.next_if(|(_, c)| *c == expected).is_some()
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, FilterError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '~' => Token::Op(Op::Contains),
+            '&' if next_is(&mut chars, '&') => Token::And,
+            '|' if next_is(&mut chars, '|') => Token::Or,
+            '=' if next_is(&mut chars, '=') => Token::Op(Op::Eq),
+            '!' if next_is(&mut chars, '=') => Token::Op(Op::Ne),
+            '!' if next_is(&mut chars, '~') => Token::Op(Op::NotContains),
+            '!' => Token::Not,
+            '<' if next_is(&mut chars, '=') => Token::Op(Op::Le),
+            '<' => Token::Op(Op::Lt),
+            '>' if next_is(&mut chars, '=') => Token::Op(Op::Ge),
+            '>' => Token::Op(Op::Gt),
+            '"' => {
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped)) => quoted.push(escaped),
+                            None => break,
+                        },
+                        Some((_, c)) => quoted.push(c),
+                        None => {
+                            return Err(FilterError {
+                                position,
+                                message: "unterminated string".to_owned(),
+                            })
+                        }
+                    }
+                }
+                Token::Quoted(quoted)
+            }
+            c if is_word_char(c) => {
+                let mut word = c.to_string();
+                while let Some((_, c)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+            c => {
+                return Err(FilterError {
+                    position,
+                    message: format!("unexpected character '{}'", c),
+                })
+            }
+        };
+        tokens.push((token, position));
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, `&&` binds tighter than `||`
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    end: usize, // length of the text, for errors at the end
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), FilterError> {
+        match self.tokens.get(self.position) {
+            Some(token) => {
+                self.position += 1;
+                Ok(token.clone())
+            }
+            None => Err(FilterError {
+                position: self.end,
+                message: "unexpected end of expression".to_owned(),
+            }),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.position += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.position += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, FilterError> {
+        if self.peek() == Some(&Token::Not) {
+            self.position += 1;
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, FilterError> {
+        match self.next()? {
+            (Token::Open, _) => {
+                let expr = self.parse_or()?;
+                match self.next()? {
+                    (Token::Close, _) => Ok(expr),
+                    (token, position) => Err(FilterError {
+                        position,
+                        message: format!("expected ')', found {}", token),
+                    }),
+                }
+            }
+            (Token::Word(name), position) => {
+                let Some(field) = Field::from_name(&name) else {
+                    return Err(FilterError {
+                        position,
+                        message: format!("unknown field '{}'", name),
+                    });
+                };
+                let op = match self.next()? {
+                    (Token::Op(op), _) => op,
+                    (token, position) => {
+                        return Err(FilterError {
+                            position,
+                            message: format!("expected an operator, found {}", token),
+                        })
+                    }
+                };
+                match self.next()? {
+                    (Token::Word(value) | Token::Quoted(value), _) => {
+                        Ok(Expr::Compare(field, op, value.to_lowercase()))
+                    }
+                    (token, position) => Err(FilterError {
+                        position,
+                        message: format!("expected a value, found {}", token),
+                    }),
+                }
+            }
+            (token, position) => Err(FilterError {
+                position,
+                message: format!("expected a field, found {}", token),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(layer: &str, cell: u64, data: &[&str]) -> OneLog {
+        OneLog {
+            data: data.iter().map(|line| (*line).to_owned()).collect(),
+            timestamp: 1000,
+            layer: layer.to_owned(),
+            level: 3,
+            dir: Some("DL".to_owned()),
+            cell: Some(cell),
+            ue_id: None,
+            rnti: Some(0x4601),
+            channel: Some("CCCH".to_owned()),
+            src: "ENB".to_owned(),
+            idx: 7,
+            source: 0,
+            info: None,
+        }
+    }
+
+    fn matches(text: &str, log: &OneLog) -> bool {
+        Filter::parse(text).unwrap().matches(log)
+    }
+
+    fn error(text: &str) -> (usize, String) {
+        let error = Filter::parse(text).unwrap_err();
+        (error.position, error.message)
+    }
+
+    #[test]
+    fn comparisons() {
+        let log = log("RRC", 1, &["rrcConnectionSetup", "0000:  60 12"]);
+        assert!(matches("layer == rrc", &log));
+        assert!(matches("layer != NAS", &log));
+        assert!(matches("cell == 1 && cell < 2 && cell >= 1", &log));
+        assert!(!matches("cell > 1", &log));
+        assert!(matches("ue_id != 1", &log));
+        assert!(!matches("ue_id == 1", &log));
+        assert!(matches("idx <= 7 && timestamp > 999", &log));
+        assert!(matches("message == RRCConnectionSetup", &log));
+        assert!(matches("channel ~ cch && dir !~ ul", &log));
+    }
+
+    #[test]
+    fn data_contains() {
+        let setup = log("RRC", 1, &["rrcConnectionSetup", "Message: SetupComplete"]);
+        assert!(matches("data ~ \"SETUPCOMPLETE\"", &setup));
+        assert!(matches("data ~ \"message: setup\"", &setup));
+        assert!(matches("data ~ \"\"", &setup));
+        assert!(!matches("data ~ release", &setup));
+        assert!(matches("data !~ release", &setup));
+        assert!(!matches("message ~ complete", &setup));
+        let accented = log("RRC", 1, &["Cellule RÉSERVÉE"]);
+        assert!(matches("data ~ \"réservée\"", &accented));
+    }
+
+    #[test]
+    fn contains_ignoring_case() {
+        assert!(contains_lowercase("PDCCH: harq=0", "pdcch"));
+        assert!(contains_lowercase("rrcConnectionSetup", "connectionsetup"));
+        assert!(!contains_lowercase("RRC", "rrc setup"));
+        assert!(contains_lowercase("", ""));
+        assert!(contains_lowercase("Cellule RÉSERVÉE", "réservée"));
+    }
+
+    #[test]
+    fn precedence() {
+        let rrc = log("RRC", 1, &[]);
+        let nas = log("NAS", 2, &[]);
+        // && binds tighter than ||
+        let text = "layer == NAS || layer == RRC && cell == 2";
+        assert!(!matches(text, &rrc));
+        assert!(matches(text, &nas));
+        let text = "(layer == NAS || layer == RRC) && cell == 2";
+        assert!(!matches(text, &rrc));
+        assert!(matches(text, &nas));
+        // ! applies to the next comparison only
+        assert!(matches("!layer == NAS && cell == 1", &rrc));
+        assert!(!matches("!(layer == RRC && cell == 1)", &rrc));
+        assert!(matches("!!layer == RRC", &rrc));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            error("layer =="),
+            (8, "unexpected end of expression".to_owned())
+        );
+        assert_eq!(error("lyer == RRC"), (0, "unknown field 'lyer'".to_owned()));
+        assert_eq!(
+            error("layer RRC"),
+            (6, "expected an operator, found 'RRC'".to_owned())
+        );
+        assert_eq!(
+            error("(layer == RRC"),
+            (13, "unexpected end of expression".to_owned())
+        );
+        assert_eq!(error("layer == RRC)"), (12, "unexpected ')'".to_owned()));
+        assert_eq!(error("data ~ \"abc"), (7, "unterminated string".to_owned()));
+        assert_eq!(
+            error("layer = RRC"),
+            (6, "unexpected character '='".to_owned())
+        );
+        assert_eq!(
+            error("layer == && cell == 1"),
+            (9, "expected a value, found '&&'".to_owned())
+        );
+        assert_eq!(error(""), (0, "unexpected end of expression".to_owned()));
+    }
+
+    #[test]
+    fn quoted_values() {
+        let log = log("RRC", 1, &["say \"hi\" now"]);
+        assert!(matches(r#"data ~ "say \"hi\"""#, &log));
+        assert!(matches("data ~ \"hi\\\" now\"", &log));
+    }
+}
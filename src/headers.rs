@@ -0,0 +1,76 @@
+//! Information found in the header lines of a server log, e.g.
+//!
+//! ```text
+//! # lteenb version 2023-03-17, Copyright (C) 2012-2023 Amarisoft
+//! # Cell 0x01: SISO, FDD, band=7, dl_earfcn=3350 (2680.000 MHz), n_rb_dl=100, pci=1
+//! # Logging level: all.level=error,phy.level=debug
+//! ```
+
+/// Cell of the header, the values are kept as written
+#[derive(Debug, Clone, Default)]
+pub struct CellInfo {
+    pub id: String,
+    pub pci: Option<String>,
+    pub arfcn: Option<String>,
+    pub bandwidth: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HeaderInfo {
+    pub component: Option<String>, // lteenb, lteue, ltemme, ...
+    pub version: Option<String>,
+    pub cells: Vec<CellInfo>,
+    pub layers: Option<String>, // logging options
+}
+
+const ARFCN_KEYS: [&str; 5] = ["dl_earfcn", "earfcn", "dl_nr_arfcn", "nr_arfcn", "arfcn"];
+const BANDWIDTH_KEYS: [&str; 2] = ["bandwidth", "bw"];
+
+pub fn parse(headers: &[String]) -> HeaderInfo {
+    let mut info = HeaderInfo::default();
+    for line in headers {
+        let line = line.trim_start_matches('#').trim();
+        if let Some((component, rest)) = line.split_once(" version ") {
+            if info.version.is_none() {
+                info.component = Some(component.trim().to_owned());
+                let version = rest.split([',', ' ']).next().unwrap_or_default();
+                info.version = Some(version.to_owned());
+            }
+        } else if let Some(cell) = line.strip_prefix("Cell ") {
+            info.cells.push(parse_cell(cell));
+        } else if line.contains(".level=") {
+            let options = line.split_once(": ").map_or(line, |(_, options)| options);
+            info.layers = Some(options.to_owned());
+        }
+    }
+    info
+}
+
+/// `0x01: SISO, FDD, band=7, dl_earfcn=3350 (2680.000 MHz), n_rb_dl=100, pci=1`
+fn parse_cell(cell: &str) -> CellInfo {
+    let (id, description) = cell.split_once(':').unwrap_or((cell, ""));
+    let mut info = CellInfo {
+        id: id.trim().to_owned(),
+        description: description.trim().to_owned(),
+        ..Default::default()
+    };
+    let values: Vec<(&str, &str)> = description
+        .split(',')
+        .filter_map(|field| field.trim().split_once('='))
+        // `3350 (2680.000 MHz)` is 3350
+        .map(|(key, value)| (key, value.split(' ').next().unwrap_or_default()))
+        .collect();
+    let find = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            values
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value.to_string())
+        })
+    };
+    info.pci = find(&["pci", "n_id_cell"]);
+    info.arfcn = find(&ARFCN_KEYS);
+    info.bandwidth = find(&BANDWIDTH_KEYS).or_else(|| Some(format!("{} RB", find(&["n_rb_dl"])?)));
+    info
+}
@@ -0,0 +1,185 @@
+//! Hex dumps found in the continuation lines of a log:
+//!
+//! ```text
+//! 0000:  40 12 98 76 54 32 10 0a  bb cc dd ee ff 00 11 22  @..vT2........."
+//! 0010:  33 44                                             3D
+//! ```
+
+use crate::OneLog;
+use std::fmt::Write as _;
+
+/// Number of bytes per line when it cannot be deduced from the offsets
+const DEFAULT_WIDTH: usize = 16;
+
+/// One line of a hex dump: its offset and the text after the colon
+fn split_line(line: &str) -> Option<(usize, &str)> {
+    let (offset, rest) = line.trim_start().split_once(':')?;
+    if offset.is_empty() || !offset.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some((usize::from_str_radix(offset, 16).ok()?, rest))
+}
+
+/// Bytes of one line, at most `width` and stopping at the ASCII column
+fn parse_bytes(rest: &str, width: usize, bytes: &mut Vec<u8>) {
+    let mut remaining = rest;
+    let mut count = 0;
+    while count < width {
+        let trimmed = remaining.trim_start_matches(' ');
+        let gap = remaining.len() - trimmed.len();
+        // bytes are separated by one or two spaces, the ASCII column by more
+        if count > 0 && gap > 2 {
+            break;
+        }
+        let mut chars = trimmed.chars();
+        let (Some(high), Some(low)) = (chars.next(), chars.next()) else {
+            break;
+        };
+        let next = chars.next();
+        if next.is_some_and(|c| c != ' ') {
+            break;
+        }
+        let (Some(high), Some(low)) = (high.to_digit(16), low.to_digit(16)) else {
+            break;
+        };
+        bytes.push((high * 16 + low) as u8);
+        count += 1;
+        remaining = &trimmed[2..];
+    }
+}
+
+/// Decode the hex dump lines, `None` when there are none
+pub fn parse_hex_dump<S: AsRef<str>>(lines: &[S]) -> Option<Vec<u8>> {
+    let dump: Vec<(usize, &str)> = lines
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|line| is_hex_dump_line(line))
+        .filter_map(split_line)
+        .collect();
+    if dump.is_empty() {
+        return None;
+    }
+    let mut bytes = Vec::new();
+    for (position, (offset, rest)) in dump.iter().enumerate() {
+        let width = match dump.get(position + 1) {
+            Some((next_offset, _)) if next_offset > offset => next_offset - offset,
+            _ => DEFAULT_WIDTH,
+        };
+        if *offset > bytes.len() {
+            if *offset - bytes.len() > bytes.len() + DEFAULT_WIDTH {
+                // more missing than read: a malformed offset, the dump ends here
+                break;
+            }
+            // a line is missing, keep the offsets right
+            bytes.resize(*offset, 0);
+        }
+        bytes.truncate(*offset);
+        parse_bytes(rest, width, &mut bytes);
+    }
+    Some(bytes)
+}
+
+/// Payload of a log, empty when it has no hex dump
+pub fn log_bytes(log: &OneLog) -> Vec<u8> {
+    parse_hex_dump(&log.data).unwrap_or_default()
+}
+
+/// Whether a data line is part of a hex dump
+pub fn is_hex_dump_line(line: &str) -> bool {
+    split_line(line).is_some_and(|(_, rest)| {
+        let mut bytes = Vec::new();
+        parse_bytes(rest, 1, &mut bytes);
+        !bytes.is_empty()
+    })
+}
+
+/// `0a1b2c...`
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+pub fn to_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sixteen_bytes_per_line() {
+        let lines = [
+            "rrcConnectionRequest",
+            "0000:  40 12 98 76 54 32 10 0a  bb cc dd ee ff 00 11 22  @..vT2.........\"",
+            "0010:  33 44                                             3D",
+        ];
+        let bytes = parse_hex_dump(&lines).unwrap();
+        assert_eq!(to_hex(&bytes), "401298765432100abbccddeeff0011223344");
+        assert!(!is_hex_dump_line(lines[0]));
+        assert!(is_hex_dump_line(lines[1]));
+    }
+
+    #[test]
+    fn width_from_offsets() {
+        // the ASCII column would be read as bytes without the width
+        let lines = [
+            "  0000: 01 02 03 04  ab cd",
+            "  0004: 05 06 07 08  ef 01",
+            "  0008: 09           ab",
+        ];
+        let bytes = parse_hex_dump(&lines).unwrap();
+        assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn missing_line() {
+        let lines = ["0000: 01 02 03 04", "0008: 09 0a"];
+        let bytes = parse_hex_dump(&lines).unwrap();
+        assert_eq!(bytes, [1, 2, 3, 4, 0, 0, 0, 0, 9, 10]);
+    }
+
+    #[test]
+    fn malformed_offset() {
+        let lines = [
+            "0000: 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f 10",
+            "ffffffff: 11 12",
+        ];
+        let bytes = parse_hex_dump(&lines).unwrap();
+        assert_eq!(bytes.len(), 16);
+        let bytes = parse_hex_dump(&["ffffffff: 11 12"]).unwrap();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn not_a_dump() {
+        assert!(parse_hex_dump(&["Message: rrcConnectionSetup", "12:34:56 text"]).is_none());
+        assert!(!is_hex_dump_line("0000: not hex"));
+        assert!(!is_hex_dump_line(": 01 02"));
+    }
+
+    #[test]
+    fn encodings() {
+        assert_eq!(to_hex(&[0x00, 0xab, 0x10]), "00ab10");
+        assert_eq!(to_base64(b""), "");
+        assert_eq!(to_base64(b"f"), "Zg==");
+        assert_eq!(to_base64(b"fo"), "Zm8=");
+        assert_eq!(to_base64(b"foobar"), "Zm9vYmFy");
+    }
+}
@@ -0,0 +1,109 @@
+//! Key performance indicators of the logs, updated log after log
+
+use crate::procedures::{Kind, Procedures, State};
+use crate::OneLog;
+use std::collections::BTreeMap;
+
+/// Levels of the logs of the remote API, text logs have none
+const LEVEL_ERROR: u64 = 1;
+const LEVEL_WARN: u64 = 2;
+
+/// Outcomes of the procedures of some kinds
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Outcomes {
+    pub success: usize,
+    pub failure: usize,
+    pub timeout: usize,
+    pub in_progress: usize,
+}
+
+impl Outcomes {
+    /// Outcomes of the procedures of some kinds
+    pub fn of(procedures: &Procedures, kinds: &[Kind]) -> Self {
+        let mut outcomes = Outcomes::default();
+        for procedure in &procedures.list {
+            if !kinds.contains(&procedure.kind) {
+                continue;
+            }
+            match procedure.state {
+                State::Success => outcomes.success += 1,
+                State::Failure => outcomes.failure += 1,
+                State::Timeout => outcomes.timeout += 1,
+                State::InProgress => outcomes.in_progress += 1,
+            }
+        }
+        outcomes
+    }
+
+    /// Successful procedures among the finished ones, from 0 to 1
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self.success + self.failure + self.timeout;
+        (finished > 0).then_some(self.success as f64 / finished as f64)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LayerCounts {
+    pub messages: usize,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Counts of the logs, the procedures are the ones of `Data::procedures`
+#[derive(Debug, Default)]
+pub struct Kpis {
+    pub rrc_releases: usize,
+    pub cells: BTreeMap<u64, usize>, // messages per cell
+    pub layers: BTreeMap<String, LayerCounts>,
+    first: Option<u64>,
+    last: Option<u64>,
+}
+
+impl Kpis {
+    pub fn push(&mut self, log: &OneLog) {
+        self.first = Some(
+            self.first
+                .map_or(log.timestamp, |first| first.min(log.timestamp)),
+        );
+        self.last = Some(
+            self.last
+                .map_or(log.timestamp, |last| last.max(log.timestamp)),
+        );
+        if let Some(cell) = log.cell {
+            *self.cells.entry(cell).or_default() += 1;
+        }
+        let counts = self.layers.entry(log.layer.to_uppercase()).or_default();
+        counts.messages += 1;
+        match log.level {
+            LEVEL_ERROR => counts.errors += 1,
+            LEVEL_WARN => counts.warnings += 1,
+            _ => {}
+        }
+        if log.layer.eq_ignore_ascii_case("RRC") {
+            let message = log.data.first().map_or(String::new(), |m| m.to_lowercase());
+            let message = message.replace([' ', '-', '_'], "");
+            if message.contains("connectionrelease") || message.contains("rrcrelease") {
+                self.rrc_releases += 1;
+            }
+        }
+    }
+
+    /// Time between the first and the last log, in seconds
+    pub fn span(&self) -> f64 {
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => (last - first) as f64 / 1000.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Average duration of the successful procedures of a kind, in milliseconds
+pub fn average_duration(procedures: &Procedures, kind: Kind) -> Option<f64> {
+    let durations: Vec<u64> = procedures
+        .list
+        .iter()
+        .filter(|procedure| procedure.kind == kind && procedure.state == State::Success)
+        .filter_map(|procedure| procedure.duration())
+        .collect();
+    (!durations.is_empty()).then(|| durations.iter().sum::<u64>() as f64 / durations.len() as f64)
+}
@@ -1,18 +1,275 @@
 mod app;
+pub mod channels;
+pub mod connection;
+pub mod decoders;
+pub mod diagnostics;
+pub mod export;
+pub mod filter;
+pub mod headers;
+pub mod hexdump;
+pub mod kpi;
+pub mod loader;
 pub mod panels;
+pub mod procedures;
+pub mod profiles;
+pub mod protocol;
+pub mod sequence;
+pub mod session;
+pub mod sources;
+pub mod text_log;
+pub mod ue;
 pub use app::ExampleApp;
 
-use ewebsock::WsSender;
+use connection::Connection;
+use decoders::MessageInfo;
+use filter::Filter;
+use procedures::Procedures;
+use protocol::LogProfile;
 use std::collections::BTreeSet;
 
 pub struct Data {
-    pub ws_sender: WsSender,
+    /// Sources of the logs, never removed: `OneLog::source` is an index
+    pub connections: Vec<Connection>,
+    pub profile: LogProfile, // parameters of the `log_get` requests
+    pub follow: bool,        // a `log_get` is kept outstanding on every remote connection
     pub events: Vec<OneLog>,
     pub open_windows: BTreeSet<String>,
     pub current_index: usize,
+    pub filter: Option<Filter>,
+    pub time_range: Option<(u64, u64)>, // timestamps of the visible logs, both included
+    pub ue_id: Option<(usize, u64)>,    // connection and id of the UE of the visible logs
+    pub source_id: Option<usize>,       // connection of the visible logs
+    pub filter_version: u64,            // incremented each time the filter or the order changes
+    pub order_version: u64,             // incremented each time the order of the events changes
+    /// Procedures of the visible events, found once for all the panels
+    pub procedures: Procedures,
+    procedures_checked: usize, // number of events in `procedures`
+    procedures_version: u64,   // `filter_version` of `procedures`
+    pub received_at: f64,      // time the last logs were added, see `egui::InputState::time`
 }
 
-#[derive(serde::Deserialize, Debug)]
+impl Data {
+    /// No connection nor log yet
+    pub fn new(profile: LogProfile) -> Self {
+        Self {
+            connections: Vec::new(),
+            profile,
+            follow: false,
+            events: Vec::new(),
+            current_index: 0,
+            open_windows: BTreeSet::new(),
+            filter: None,
+            time_range: None,
+            ue_id: None,
+            source_id: None,
+            filter_version: 0,
+            order_version: 0,
+            procedures: Procedures::default(),
+            procedures_checked: 0,
+            procedures_version: 0,
+            received_at: 0.0,
+        }
+    }
+
+    /// Send a `log_get` request on every ready connection
+    pub fn request_logs(&mut self) -> Result<(), String> {
+        let mut sent = false;
+        for connection in self.connections.iter_mut() {
+            if connection.session.is_ready() {
+                let profile = connection.log_profile(&self.profile);
+                connection
+                    .session
+                    .request_logs(connection.source.as_mut(), &profile)?;
+                sent = true;
+            }
+        }
+        if sent {
+            Ok(())
+        } else {
+            Err("No server is ready".to_owned())
+        }
+    }
+
+    /// Send a `log_get` request on a connection answered once new logs are
+    /// available, returns its message_id
+    pub fn poll_logs(&mut self, source_id: usize, timeout: u64, max: u64) -> Result<u64, String> {
+        let connection = &mut self.connections[source_id];
+        let profile = connection.log_profile(&self.profile);
+        connection
+            .session
+            .poll_logs(connection.source.as_mut(), &profile, timeout, max)
+    }
+
+    /// Send a request of the remote API on a connection, returns its message_id
+    pub fn send_request(
+        &mut self,
+        source_id: usize,
+        request: serde_json::Value,
+    ) -> Result<u64, String> {
+        let connection = &mut self.connections[source_id];
+        connection
+            .session
+            .send_request(connection.source.as_mut(), request)
+    }
+
+    /// Whether a `log_get` is kept outstanding on a connection
+    pub fn is_followed(&self, source_id: usize) -> bool {
+        self.follow || self.connections[source_id].follow
+    }
+
+    /// Name of the connection of a log
+    pub fn source_name(&self, source_id: usize) -> &str {
+        self.connections[source_id].source.name()
+    }
+
+    pub fn set_filter(&mut self, filter: Option<Filter>) {
+        self.filter = filter;
+        self.filter_version += 1;
+    }
+
+    pub fn set_time_range(&mut self, time_range: Option<(u64, u64)>) {
+        self.time_range = time_range;
+        self.filter_version += 1;
+    }
+
+    pub fn set_ue_id(&mut self, ue_id: Option<(usize, u64)>) {
+        self.ue_id = ue_id;
+        self.filter_version += 1;
+    }
+
+    pub fn set_source_id(&mut self, source_id: Option<usize>) {
+        self.source_id = source_id;
+        self.filter_version += 1;
+    }
+
+    /// Whether a log passes the filter, is in the time range and belongs to
+    /// the selected UE and connection
+    pub fn is_visible(&self, log: &OneLog) -> bool {
+        if self.source_id.is_some_and(|id| log.source != id) {
+            return false;
+        }
+        if self
+            .ue_id
+            .is_some_and(|(source, ue_id)| log.source != source || log.ue_id != Some(ue_id))
+        {
+            return false;
+        }
+        if let Some((start, end)) = self.time_range {
+            if log.timestamp < start || log.timestamp > end {
+                return false;
+            }
+        }
+        match &self.filter {
+            Some(filter) => filter.matches(log),
+            None => true,
+        }
+    }
+
+    /// Logs passing the filter
+    pub fn visible_events(&self) -> impl Iterator<Item = &OneLog> {
+        self.events.iter().filter(|log| self.is_visible(log))
+    }
+
+    /// Index of the previous log passing the filter
+    pub fn previous_visible(&self) -> Option<usize> {
+        let end = self.current_index.min(self.events.len());
+        self.events[..end]
+            .iter()
+            .rposition(|log| self.is_visible(log))
+    }
+
+    /// Index of the next log passing the filter
+    pub fn next_visible(&self) -> Option<usize> {
+        let start = self.current_index + 1;
+        let events = self.events.get(start..)?;
+        Some(start + events.iter().position(|log| self.is_visible(log))?)
+    }
+
+    /// Find the procedures in the visible logs added since the last call and
+    /// time out the pending ones at `now`, see `egui::InputState::time`
+    pub fn update_procedures(&mut self, now: f64) {
+        let mut procedures = std::mem::take(&mut self.procedures);
+        if self.procedures_checked > self.events.len()
+            || self.procedures_version != self.filter_version
+        {
+            // events were replaced or the filter changed
+            procedures = Procedures::default();
+            self.procedures_checked = 0;
+            self.procedures_version = self.filter_version;
+        }
+        let events = self.events.iter().enumerate().skip(self.procedures_checked);
+        for (index, log) in events {
+            if self.is_visible(log) {
+                procedures.push(index, log);
+            }
+        }
+        self.procedures_checked = self.events.len();
+        // later logs may be hidden by the filter, or not come at all
+        if !self.connections.iter().any(Connection::may_receive) {
+            // no more logs: what is still pending will never end
+            procedures.expire(u64::MAX);
+        } else if let Some(last) = self.events.last() {
+            // the clock of a quiet server went on since its last logs
+            let quiet = ((now - self.received_at).max(0.0) * 1000.0) as u64;
+            procedures.expire(last.timestamp + quiet);
+        }
+        self.procedures = procedures;
+    }
+
+    /// Add the logs of a connection. With several connections the events
+    /// are kept ordered by timestamp, the logs of one connection keep their order
+    pub fn add_logs(&mut self, source_id: usize, mut logs: Vec<OneLog>) {
+        for log in logs.iter_mut() {
+            log.source = source_id;
+            log.info = MessageInfo::decode(log).map(Box::new);
+        }
+        if self.connections.len() < 2 {
+            self.events.extend(logs);
+            return;
+        }
+        logs.sort_by_key(|log| log.timestamp);
+        let Some(first) = logs.first().map(|log| log.timestamp) else {
+            return;
+        };
+        let start = self.events.partition_point(|log| log.timestamp <= first);
+        if start == self.events.len() {
+            self.events.extend(logs);
+            return;
+        }
+        // merge with the later events, the current log is followed
+        let current = self.current_index.checked_sub(start);
+        let mut later = self
+            .events
+            .split_off(start)
+            .into_iter()
+            .enumerate()
+            .peekable();
+        let mut logs = logs.into_iter().peekable();
+        loop {
+            let from_later = match (later.peek(), logs.peek()) {
+                (Some((_, event)), Some(log)) => event.timestamp <= log.timestamp,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            if from_later {
+                if let Some((index, event)) = later.next() {
+                    if Some(index) == current {
+                        self.current_index = self.events.len();
+                    }
+                    self.events.push(event);
+                }
+            } else {
+                self.events.extend(logs.next());
+            }
+        }
+        // the indices of the events changed: views must be rebuilt
+        self.filter_version += 1;
+        self.order_version += 1;
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug)]
 pub struct OneLog {
     pub data: Vec<String>,   // Each item is a string representing a line of log.
     pub timestamp: u64,      // Milliseconds since January 1st 1970.
@@ -20,9 +277,15 @@ pub struct OneLog {
     pub level: u64,          // Log level: error, warn, info or debug.
     pub dir: Option<String>, //  Log direction: UL, DL, FROM or TO.
     pub cell: Option<u64>,   // cell id
+    pub ue_id: Option<u64>,  // UE id given by the eNB, gNB or core network
+    pub rnti: Option<u64>,
     pub channel: Option<String>, // channels
     pub src: String,
     pub idx: u64,
+    #[serde(skip)]
+    pub source: usize, // index of its connection in `Data::connections`
+    #[serde(skip)]
+    pub info: Option<Box<MessageInfo>>, // decoded message of the RRC and NAS logs
 }
 
 // deserialize the message
@@ -31,33 +294,22 @@ pub struct WebSocketLog {
     pub logs: Vec<OneLog>,
 }
 
-use egui::text::LayoutJob;
-use egui::{Color32, TextFormat, Ui};
-
-fn color_label(job: &mut LayoutJob, ui: &Ui, label: &str, need_color: bool) {
-    let default_color = if ui.visuals().dark_mode {
-        Color32::LIGHT_GRAY
-    } else {
-        Color32::DARK_GRAY
-    };
-    let background = if need_color {
-        Color32::DARK_BLUE
-    } else {
-        Color32::DARK_RED
-    };
-    job.append(
-        label,
-        0.0,
-        TextFormat {
-            color: default_color,
-            background: background,
-            ..Default::default()
-        },
-    );
+/// Time of day of a timestamp, `HH:MM:SS.mmm`
+pub fn format_timestamp(timestamp: u64) -> String {
+    let millis = timestamp % 1000;
+    let seconds = timestamp / 1000;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        (seconds / 3600) % 24,
+        (seconds / 60) % 60,
+        seconds % 60,
+        millis
+    )
 }
 
+use egui::{Color32, RichText, Ui};
+
 pub fn display_log(ui: &mut Ui, log: &OneLog) {
-    let job = LayoutJob::default();
     let data_type = match log.data.len() {
         0 => None,
         _ => Some(&log.data[0]),
@@ -66,25 +318,41 @@ pub fn display_log(ui: &mut Ui, log: &OneLog) {
         ui.label(data_type);
     }
 
-    let data: Vec<&str> = log
-        .data
-        .iter()
-        .filter(|one_string| {
-            if let Some(first_char) = one_string.chars().next() {
-                return first_char.is_numeric();
-            }
-            return false;
-        })
-        .map(|one_string| {
-            if one_string.len() > 57 {
-                let str = &one_string[6..57];
-                return str;
-            }
-            return "";
-        })
-        .collect();
-    for one_data in data {
-        ui.label(one_data);
+    for line in log.data.iter().skip(1) {
+        if !hexdump::is_hex_dump_line(line) {
+            ui.monospace(line);
+        }
+    }
+    if let Some(bytes) = hexdump::parse_hex_dump(&log.data) {
+        for chunk in bytes.chunks(16) {
+            let line: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+            ui.monospace(line.join(" "));
+        }
     }
-    ui.label(job);
+}
+
+/// Decoded message as a tree of collapsible components
+pub fn display_tree(ui: &mut Ui, node: &decoders::Node) {
+    let text = match &node.value {
+        Some(value) => format!("{}: {}", node.name, value),
+        None => node.name.clone(),
+    };
+    let text = if node.error {
+        RichText::new(text).color(Color32::RED)
+    } else if node.warning {
+        RichText::new(text).color(ui.visuals().warn_fg_color)
+    } else {
+        RichText::new(text)
+    };
+    if node.children.is_empty() {
+        ui.label(text);
+        return;
+    }
+    egui::CollapsingHeader::new(text)
+        .default_open(true)
+        .show(ui, |ui| {
+            for (index, child) in node.children.iter().enumerate() {
+                ui.push_id(index, |ui| display_tree(ui, child));
+            }
+        });
 }
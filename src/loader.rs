@@ -0,0 +1,45 @@
+use crate::text_log::{self, ParseError};
+use crate::{OneLog, WebSocketLog};
+
+/// Parse the content of a saved log file.
+///
+/// Both the JSON dump of a `log_get` reply (`{"logs":[...]}`) and the
+/// plain-text `.log` export are accepted. Malformed entries of text logs are
+/// skipped and returned along with the logs.
+pub fn parse_log_file(
+    content: &str,
+    file_name: &str,
+) -> Result<(Vec<OneLog>, Vec<ParseError>), String> {
+    if content.trim_start().starts_with('{') {
+        return match serde_json::from_str::<WebSocketLog>(content) {
+            Ok(decoded) => Ok((decoded.logs, Vec::new())),
+            Err(e) => Err(format!("{}: {}", file_name, e)),
+        };
+    }
+    let mut logs = Vec::new();
+    let mut errors = Vec::new();
+    for result in text_log::parse_text_logs(content.as_bytes(), &source_name(file_name)) {
+        match result {
+            Ok(log) => logs.push(log),
+            Err(e) => {
+                log::warn!("{}: {}", file_name, e);
+                errors.push(e);
+            }
+        }
+    }
+    if logs.is_empty() {
+        if let Some(e) = errors.first() {
+            return Err(format!("{}: {}", file_name, e));
+        }
+    }
+    Ok((logs, errors))
+}
+
+/// Name of the component that produced a file, e.g. `enb0` for `/tmp/enb0.log`
+fn source_name(file_name: &str) -> String {
+    let base = file_name.rsplit(['/', '\\']).next().unwrap_or(file_name);
+    match base.split_once('.') {
+        Some((stem, _)) => stem.to_owned(),
+        None => base.to_owned(),
+    }
+}
@@ -6,7 +6,6 @@
 fn main() -> eframe::Result<()> {
     env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
 
-    let app = my_project::ExampleApp::default();
     let native_options = eframe::NativeOptions {
         viewport: eframe::egui::ViewportBuilder::default()
             .with_inner_size([400.0, 300.0])
@@ -16,7 +15,7 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "ewebsocket example app",
         native_options,
-        Box::new(|_cc| Box::new(app)),
+        Box::new(|cc| Box::new(my_project::ExampleApp::new(cc))),
     )
 }
 
@@ -0,0 +1,191 @@
+use crate::kpi::{average_duration, Kpis, Outcomes};
+use crate::procedures::Kind;
+use crate::Data;
+use eframe::egui;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const HANDOVERS: [Kind; 4] = [
+    Kind::S1Handover,
+    Kind::N2Handover,
+    Kind::X2Handover,
+    Kind::XnHandover,
+];
+
+pub struct Dashboard {
+    data: Rc<RefCell<Data>>,
+    kpis: Kpis,
+    checked: usize,      // number of events in `kpis`
+    filter_version: u64, // `Data::filter_version` the KPIs were computed with
+}
+
+impl Dashboard {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            kpis: Kpis::default(),
+            checked: 0,
+            filter_version: 0,
+        }
+    }
+
+    /// Add the logs received since last frame to the KPIs, `now` is the time
+    /// the pending procedures time out at
+    fn update_kpis(&mut self, now: f64) {
+        self.data.borrow_mut().update_procedures(now);
+        let data = self.data.borrow();
+        if self.checked > data.events.len() || self.filter_version != data.filter_version {
+            // events were replaced or the filter changed
+            self.kpis = Kpis::default();
+            self.checked = 0;
+            self.filter_version = data.filter_version;
+        }
+        for one_log in &data.events[self.checked..] {
+            if data.is_visible(one_log) {
+                self.kpis.push(one_log);
+            }
+        }
+        self.checked = data.events.len();
+    }
+}
+
+fn percent(rate: Option<f64>) -> String {
+    rate.map_or("-".to_owned(), |rate| format!("{:.1} %", rate * 100.0))
+}
+
+fn outcomes_row(ui: &mut egui::Ui, name: &str, outcomes: Outcomes) {
+    ui.label(name);
+    ui.strong(percent(outcomes.success_rate()));
+    ui.label(format!(
+        "{} successful, {} failed, {} timed out, {} in progress",
+        outcomes.success, outcomes.failure, outcomes.timeout, outcomes.in_progress
+    ));
+    ui.end_row();
+}
+
+fn latency_row(ui: &mut egui::Ui, name: &str, latency: Option<f64>) {
+    ui.label(name);
+    ui.strong(latency.map_or("-".to_owned(), |latency| format!("{:.0} ms", latency)));
+    ui.end_row();
+}
+
+impl super::PanelController for Dashboard {
+    fn name(&self) -> &'static str {
+        "KPIs"
+    }
+    fn window_title(&self) -> &'static str {
+        "KPI dashboard"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        self.update_kpis(ctx.input(|i| i.time));
+        egui::Window::new(self.window_title())
+            .default_width(500.0)
+            .default_height(400.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for Dashboard {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let kpis = &self.kpis;
+        let data = self.data.borrow();
+        let procedures = &data.procedures;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.strong("Procedures");
+            egui::Grid::new("procedures").striped(true).show(ui, |ui| {
+                outcomes_row(ui, "RRC setup", Outcomes::of(procedures, &[Kind::RrcSetup]));
+                outcomes_row(ui, "Handover", Outcomes::of(procedures, &HANDOVERS));
+                outcomes_row(
+                    ui,
+                    "Re-establishment",
+                    Outcomes::of(procedures, &[Kind::Reestablishment]),
+                );
+                outcomes_row(ui, "Attach", Outcomes::of(procedures, &[Kind::Attach]));
+                outcomes_row(
+                    ui,
+                    "Registration",
+                    Outcomes::of(procedures, &[Kind::Registration]),
+                );
+            });
+
+            ui.separator();
+            ui.strong("RRC connections");
+            let setups = Outcomes::of(procedures, &[Kind::RrcSetup]);
+            egui::Grid::new("rrc").striped(true).show(ui, |ui| {
+                ui.label("Requested");
+                ui.label(
+                    (setups.success + setups.failure + setups.timeout + setups.in_progress)
+                        .to_string(),
+                );
+                ui.end_row();
+                ui.label("Established");
+                ui.label(setups.success.to_string());
+                ui.end_row();
+                ui.label("Released");
+                ui.label(kpis.rrc_releases.to_string());
+                ui.end_row();
+            });
+
+            ui.separator();
+            ui.strong("Latency");
+            egui::Grid::new("latency").striped(true).show(ui, |ui| {
+                latency_row(ui, "Attach", average_duration(procedures, Kind::Attach));
+                latency_row(
+                    ui,
+                    "Registration",
+                    average_duration(procedures, Kind::Registration),
+                );
+                latency_row(
+                    ui,
+                    "RRC setup",
+                    average_duration(procedures, Kind::RrcSetup),
+                );
+            });
+
+            ui.separator();
+            ui.strong("Cells");
+            let span = kpis.span().max(1.0);
+            egui::Grid::new("cells").striped(true).show(ui, |ui| {
+                for title in ["Cell", "Logs", "Logs/s"] {
+                    ui.label(title);
+                }
+                ui.end_row();
+                for (cell, count) in &kpis.cells {
+                    ui.label(cell.to_string());
+                    ui.label(count.to_string());
+                    ui.label(format!("{:.1}", *count as f64 / span));
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.strong("Layers");
+            egui::Grid::new("layers").striped(true).show(ui, |ui| {
+                for title in ["Layer", "Logs", "Errors", "Warnings"] {
+                    ui.label(title);
+                }
+                ui.end_row();
+                for (layer, counts) in &kpis.layers {
+                    ui.label(layer);
+                    ui.label(counts.messages.to_string());
+                    if counts.errors > 0 {
+                        ui.colored_label(ui.visuals().error_fg_color, counts.errors.to_string());
+                    } else {
+                        ui.label("0");
+                    }
+                    if counts.warnings > 0 {
+                        ui.colored_label(ui.visuals().warn_fg_color, counts.warnings.to_string());
+                    } else {
+                        ui.label("0");
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    }
+}
@@ -20,8 +20,19 @@ impl FileHandler {
             error: None,
         }
     }
-    pub fn get_result(&mut self) -> Result<String, String> {
-        return Err("Not implemented".to_string());
+    /// Content and name of the picked file, once it has been read
+    pub fn get_result(&mut self) -> Option<(String, String)> {
+        match self.file_upload.as_ref()?.ready()? {
+            Ok((content, name)) => Some((content.clone(), name.clone())),
+            Err(_) => None,
+        }
+    }
+
+    /// Report an error about the picked file (e.g. it could not be parsed)
+    pub fn set_error(&mut self, error: String) {
+        self.picked_path = None;
+        self.file_upload = None;
+        self.error = Some(error);
     }
     fn handle_dialog(&mut self) {
         #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,160 @@
+use crate::hexdump::{self, log_bytes};
+use crate::Data;
+use eframe::egui::{self, Color32, RichText};
+use std::cell::RefCell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+const BYTES_PER_ROW: usize = 16;
+
+pub struct HexViewer {
+    data: Rc<RefCell<Data>>,
+    /// Source and idx of the log the bytes were decoded from
+    shown_log: Option<(usize, u64)>,
+    bytes: Vec<u8>,
+    /// First clicked byte and last shift-clicked one
+    selection: Option<(usize, usize)>,
+}
+
+impl HexViewer {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            shown_log: None,
+            bytes: Vec::new(),
+            selection: None,
+        }
+    }
+
+    fn selected_range(&self) -> Option<RangeInclusive<usize>> {
+        let (anchor, end) = self.selection?;
+        Some(anchor.min(end)..=anchor.max(end))
+    }
+
+    /// Selected bytes, or all of them when nothing is selected
+    fn selected_bytes(&self) -> &[u8] {
+        match self.selected_range() {
+            Some(range) => &self.bytes[range],
+            None => &self.bytes,
+        }
+    }
+}
+
+impl super::PanelController for HexViewer {
+    fn name(&self) -> &'static str {
+        "Hex viewer"
+    }
+    fn window_title(&self) -> &'static str {
+        "Hex viewer"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        {
+            // in a closure to avoid borrow checker
+            let borrowed = &self.data.borrow();
+            // indices change when logs of several connections are merged
+            let current = borrowed.events.get(borrowed.current_index);
+            let shown_log = current.map(|log| (log.source, log.idx));
+            if self.shown_log != shown_log {
+                self.shown_log = shown_log;
+                self.bytes = current.map(log_bytes).unwrap_or_default();
+                self.selection = None;
+            }
+        }
+        egui::Window::new(self.window_title())
+            .default_width(560.0)
+            .default_height(320.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+fn printable(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+impl super::PanelView for HexViewer {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        if self.bytes.is_empty() {
+            ui.label("No hex dump in this log");
+            return;
+        }
+        ui.horizontal(|ui| {
+            match self.selected_range() {
+                Some(range) => ui.label(format!(
+                    "Selected {} bytes at 0x{:04x}",
+                    range.end() - range.start() + 1,
+                    range.start()
+                )),
+                None => ui.label(format!("{} bytes", self.bytes.len())),
+            };
+            if ui.button("Copy hex").clicked() {
+                let text = hexdump::to_hex(self.selected_bytes());
+                ui.output_mut(|o| o.copied_text = text);
+            }
+            if ui.button("Copy base64").clicked() {
+                let text = hexdump::to_base64(self.selected_bytes());
+                ui.output_mut(|o| o.copied_text = text);
+            }
+        });
+        ui.separator();
+
+        let selected = self.selected_range();
+        let selected_color = ui.visuals().selection.bg_fill;
+        let mut clicked = None;
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+        let rows = self.bytes.len().div_ceil(BYTES_PER_ROW);
+        egui::ScrollArea::vertical().show_rows(ui, row_height, rows, |ui, row_range| {
+            for row in row_range {
+                let start = row * BYTES_PER_ROW;
+                let end = (start + BYTES_PER_ROW).min(self.bytes.len());
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 4.0;
+                    ui.label(RichText::new(format!("{:04x}:", start)).monospace().weak());
+                    for index in start..start + BYTES_PER_ROW {
+                        let text = match self.bytes.get(index) {
+                            Some(byte) => format!("{:02x}", byte),
+                            None => "  ".to_owned(),
+                        };
+                        let mut text = RichText::new(text).monospace();
+                        if selected
+                            .as_ref()
+                            .is_some_and(|range| range.contains(&index))
+                        {
+                            text = text.background_color(selected_color);
+                        }
+                        if index < end {
+                            let label = egui::Label::new(text).sense(egui::Sense::click());
+                            if ui.add(label).clicked() {
+                                clicked = Some(index);
+                            }
+                        } else {
+                            ui.label(text);
+                        }
+                    }
+                    let ascii: String = self.bytes[start..end]
+                        .iter()
+                        .map(|b| printable(*b))
+                        .collect();
+                    ui.label(RichText::new(ascii).monospace().color(Color32::GRAY));
+                });
+            }
+        });
+
+        if let Some(index) = clicked {
+            let extend = ui.input(|i| i.modifiers.shift);
+            self.selection = match self.selection {
+                Some((anchor, _)) if extend => Some((anchor, index)),
+                Some((anchor, end)) if anchor == index && end == index => None,
+                _ => Some((index, index)),
+            };
+        }
+    }
+}
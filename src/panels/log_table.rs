@@ -0,0 +1,366 @@
+use crate::export;
+use crate::filter::{contains_lowercase, Filter, FilterError};
+use crate::{format_timestamp, Data, OneLog};
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use poll_promise::Promise;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct LogTable {
+    data: Rc<RefCell<Data>>,
+    search: String,
+    filter_text: String,
+    filter_error: Option<FilterError>,
+    /// `Data::filter_version` the rows were computed with
+    filter_version: u64,
+    export: Option<Promise<Result<String, String>>>,
+    /// Indexes of the events matching the search
+    rows: Vec<usize>,
+    /// Number of events already checked against the search
+    checked: usize,
+    /// Last `current_index` seen, to scroll when another panel changes it
+    shown_index: usize,
+}
+
+impl LogTable {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            search: String::new(),
+            filter_text: String::new(),
+            filter_error: None,
+            filter_version: 0,
+            export: None,
+            rows: Vec::new(),
+            checked: 0,
+            shown_index: 0,
+        }
+    }
+
+    /// Check the events received since last frame
+    fn update_rows(&mut self, data: &Data) {
+        if self.checked > data.events.len() || self.filter_version != data.filter_version {
+            // events were replaced or the filter changed
+            self.rows.clear();
+            self.checked = 0;
+            self.filter_version = data.filter_version;
+        }
+        let search = self.search.to_lowercase();
+        for (index, one_log) in data.events.iter().enumerate().skip(self.checked) {
+            if data.is_visible(one_log) && (search.is_empty() || matches_search(one_log, &search)) {
+                self.rows.push(index);
+            }
+        }
+        self.checked = data.events.len();
+    }
+
+    fn apply_filter(&mut self) {
+        let filter = if self.filter_text.trim().is_empty() {
+            Ok(None)
+        } else {
+            Filter::parse(&self.filter_text).map(Some)
+        };
+        match filter {
+            Ok(filter) => {
+                self.filter_error = None;
+                self.data.borrow_mut().set_filter(filter);
+            }
+            Err(e) => self.filter_error = Some(e),
+        }
+    }
+
+    /// Choice between the merged logs of all the connections and those of one
+    fn source_ui(&mut self, ui: &mut egui::Ui) {
+        let mut data = self.data.borrow_mut();
+        let mut source_id = data.source_id;
+        let selected = source_id.map_or("All sources", |id| data.source_name(id));
+        ui.horizontal(|ui| {
+            ui.label("Source:");
+            egui::ComboBox::from_id_source("log_source")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut source_id, None, "All sources");
+                    for (id, connection) in data.connections.iter().enumerate() {
+                        ui.selectable_value(&mut source_id, Some(id), connection.source.name());
+                    }
+                });
+        });
+        if source_id != data.source_id {
+            data.set_source_id(source_id);
+        }
+    }
+
+    /// Save the logs passing the filter, in the format of a `log_get` reply
+    fn export_logs(&mut self) {
+        let borrowed = self.data.borrow();
+        let logs: Vec<&OneLog> = borrowed.visible_events().collect();
+        match serde_json::to_vec(&serde_json::json!({ "logs": logs })) {
+            Ok(content) => self.export = Some(export::save_file("logs.json", content)),
+            Err(e) => log::error!("Failed to export logs: {}", e),
+        }
+    }
+}
+
+/// Whether a log contains `search`, already lowercase, without copying its lines
+fn matches_search(one_log: &OneLog, search: &str) -> bool {
+    let contains = |text: &str| contains_lowercase(text, search);
+    contains(&one_log.layer)
+        || contains(&one_log.src)
+        || one_log.channel.as_deref().is_some_and(contains)
+        || one_log.data.iter().any(|line| contains(line))
+}
+
+impl super::PanelController for LogTable {
+    fn name(&self) -> &'static str {
+        "Logs"
+    }
+    fn window_title(&self) -> &'static str {
+        "Logs"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.window_title())
+            .default_width(720.0)
+            .default_height(480.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for LogTable {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Filter:");
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.filter_text)
+                    .hint_text("layer == RRC && dir == DL")
+                    .desired_width(f32::INFINITY),
+            );
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                self.apply_filter();
+            }
+        });
+        if let Some(error) = &self.filter_error {
+            ui.colored_label(egui::Color32::RED, error.to_string());
+        }
+        if self.data.borrow().connections.len() > 1 {
+            self.source_ui(ui);
+        }
+        let time_range = self.data.borrow().time_range;
+        if let Some((start, end)) = time_range {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Time range: {} – {}",
+                    format_timestamp(start),
+                    format_timestamp(end)
+                ));
+                if ui.small_button("Clear").clicked() {
+                    self.data.borrow_mut().set_time_range(None);
+                }
+            });
+        }
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            if ui.text_edit_singleline(&mut self.search).changed() {
+                self.rows.clear();
+                self.checked = 0;
+            }
+            if ui.button("Export…").clicked() {
+                self.export_logs();
+            }
+            if let Some(export) = &self.export {
+                match export.ready() {
+                    None => {
+                        ui.add(egui::Spinner::new());
+                    }
+                    Some(Ok(file_name)) => {
+                        ui.label(format!("Saved to {}", file_name));
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::RED, e);
+                    }
+                }
+            }
+        });
+
+        let mut selected = None;
+        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        {
+            let data = Rc::clone(&self.data);
+            let borrowed = data.borrow();
+            self.update_rows(&borrowed);
+            ui.label(format!(
+                "{} / {} logs",
+                self.rows.len(),
+                borrowed.events.len()
+            ));
+
+            let current_index = borrowed.current_index;
+            let mut table = TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .sense(egui::Sense::click())
+                .column(Column::auto().at_least(90.0))
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::remainder().clip(true));
+            if current_index != self.shown_index {
+                self.shown_index = current_index;
+                if let Ok(row) = self.rows.binary_search(&current_index) {
+                    table = table.scroll_to_row(row, Some(egui::Align::Center));
+                }
+            }
+            table
+                .header(20.0, |mut header| {
+                    for title in [
+                        "Time", "Layer", "Dir", "Cell", "UE", "Channel", "Src", "Message",
+                    ] {
+                        header.col(|ui| {
+                            ui.strong(title);
+                        });
+                    }
+                })
+                .body(|body| {
+                    body.rows(row_height, self.rows.len(), |mut row| {
+                        let index = self.rows[row.index()];
+                        let one_log = &borrowed.events[index];
+                        row.set_selected(index == current_index);
+                        row.col(|ui| {
+                            ui.monospace(format_timestamp(one_log.timestamp));
+                        });
+                        row.col(|ui| {
+                            ui.label(&one_log.layer);
+                        });
+                        row.col(|ui| {
+                            ui.label(one_log.dir.as_deref().unwrap_or_default());
+                        });
+                        row.col(|ui| {
+                            if let Some(cell) = one_log.cell {
+                                ui.label(cell.to_string());
+                            }
+                        });
+                        row.col(|ui| {
+                            if let Some(ue_id) = one_log.ue_id {
+                                ui.label(ue_id.to_string());
+                            }
+                        });
+                        row.col(|ui| {
+                            ui.label(one_log.channel.as_deref().unwrap_or_default());
+                        });
+                        row.col(|ui| {
+                            ui.label(&one_log.src)
+                                .on_hover_text(borrowed.source_name(one_log.source));
+                        });
+                        row.col(|ui| {
+                            let message = one_log.data.first().map_or("", String::as_str);
+                            ui.add(egui::Label::new(message).truncate(true));
+                        });
+                        if row.response().clicked() {
+                            selected = Some(index);
+                        }
+                    });
+                });
+        }
+        if let Some(index) = selected {
+            self.data.borrow_mut().current_index = index;
+            self.shown_index = index;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Connection;
+    use crate::panels::PanelController as _;
+    use crate::protocol::LogProfile;
+    use crate::sources::MemorySource;
+    use crate::text_log::parse_text_logs;
+
+    const LOG: &str = "\
+10:27:22.358 [RRC] UL 0001 01 CCCH: rrcConnectionRequest
+10:27:22.360 [RRC] DL 0001 01 CCCH: rrcConnectionSetup
+10:27:22.371 [RRC] UL 0001 01 DCCH: rrcConnectionSetupComplete
+10:27:23.001 [RRC] UL 0002 01 CCCH: rrcConnectionRequest
+10:27:23.005 [NAS] UL 0002 Attach request
+10:27:23.010 [PHY] DL 0002 01 4601 120.3 PDSCH: harq=0 prb=0:5";
+
+    /// Table over the logs of `LOG`, with the rows of the first frame
+    fn table() -> (LogTable, Rc<RefCell<Data>>, egui::Context) {
+        let mut data = Data::new(LogProfile::default());
+        let source = MemorySource::new("enb0".to_owned(), Vec::new());
+        data.connections
+            .push(Connection::new(Box::new(source), None));
+        let logs = parse_text_logs(LOG.as_bytes(), "enb0")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        data.add_logs(0, logs);
+        let data = Rc::new(RefCell::new(data));
+        let mut table = LogTable::new(Rc::clone(&data));
+        let ctx = egui::Context::default();
+        frame(&ctx, &mut table);
+        (table, data, ctx)
+    }
+
+    fn frame(ctx: &egui::Context, table: &mut LogTable) {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| table.show(ctx, &mut true));
+    }
+
+    #[test]
+    fn rows_follow_the_filter() {
+        let (mut table, data, ctx) = table();
+        assert_eq!(table.rows, [0, 1, 2, 3, 4, 5]);
+
+        table.filter_text = "layer == RRC".to_owned();
+        table.apply_filter();
+        frame(&ctx, &mut table);
+        assert_eq!(table.rows, [0, 1, 2, 3]);
+
+        data.borrow_mut().set_ue_id(Some((0, 2)));
+        frame(&ctx, &mut table);
+        assert_eq!(table.rows, [3]);
+
+        table.filter_text = "layer == ?".to_owned();
+        table.apply_filter();
+        assert!(table.filter_error.is_some());
+        table.filter_text.clear();
+        table.apply_filter();
+        frame(&ctx, &mut table);
+        assert_eq!(table.rows, [3, 4, 5]);
+    }
+
+    #[test]
+    fn navigation_in_the_visible_rows() {
+        let (mut table, data, ctx) = table();
+        data.borrow_mut().set_ue_id(Some((0, 2)));
+        frame(&ctx, &mut table);
+        let mut data = data.borrow_mut();
+        assert_eq!(data.next_visible(), Some(3));
+        data.current_index = 3;
+        assert_eq!(data.previous_visible(), None);
+        data.current_index = 5;
+        assert_eq!(data.previous_visible(), Some(4));
+        assert_eq!(data.next_visible(), None);
+    }
+
+    #[test]
+    fn search() {
+        let (mut table, _, ctx) = table();
+        for (search, rows) in [("SETUP", vec![1, 2]), ("pdsch", vec![5]), ("nas", vec![4])] {
+            // as when the search field is edited
+            table.search = search.to_owned();
+            table.rows.clear();
+            table.checked = 0;
+            frame(&ctx, &mut table);
+            assert_eq!(table.rows, rows);
+        }
+    }
+}
@@ -1,19 +1,79 @@
+use crate::channels::{self, channel_map, Activity, Direction, Layer, Path, LAYERS};
+use crate::decoders::rrc::Technology;
 use crate::Data;
 use eframe::egui::{self, Color32, TextFormat};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Number of logs looked at backwards to find the technology in use
+const DETECTION_DEPTH: usize = 1000;
+
+/// Milliseconds for the heat of a message to be divided by e over all the logs
+const ALL_LOGS_DECAY: f64 = 200_000.0;
+
+/// Time windows of the activity, `None` for all the logs
+const WINDOWS: [(Option<u64>, &str); 5] = [
+    (Some(1_000), "1 s"),
+    (Some(10_000), "10 s"),
+    (Some(60_000), "1 min"),
+    (Some(600_000), "10 min"),
+    (None, "Tout"),
+];
+
 pub struct LogicalChannels {
     data: Rc<RefCell<Data>>,
-    channel: String,
+    selection: Option<Technology>, // `None` to follow the logs
+    detected: Option<Technology>,
+    detected_for: Option<(usize, usize)>, // current log and log count
+    window: Option<u64>,                  // in milliseconds, `None` for all the logs
+    computed: Option<(usize, usize, Technology, Option<u64>, u64)>, // state of `active` and `activity`
+    active: Vec<(&'static Path, Layer)>,
+    activity: Activity,
+    counted: usize, // logs in `activity` over all the logs
 }
 
 impl LogicalChannels {
     pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
         Self {
             data: ref_data,
-            channel: "".to_string(),
+            selection: None,
+            detected: None,
+            detected_for: None,
+            window: Some(10_000),
+            computed: None,
+            active: Vec::new(),
+            activity: Activity::new(ALL_LOGS_DECAY),
+            counted: 0,
+        }
+    }
+
+    fn technology(&self) -> Technology {
+        self.selection.or(self.detected).unwrap_or(Technology::Lte)
+    }
+
+    /// Whether `channel` of `layer` is on the active chain
+    fn is_active(&self, direction: Direction, layer: Layer, channel: &str) -> bool {
+        self.active.iter().any(|(path, from)| {
+            path.direction == direction && path.channel(layer) == channel && *from <= layer
+        })
+    }
+
+    /// Active chains as text, `DCCH → DL-SCH → PDSCH`
+    fn chains(&self) -> Vec<String> {
+        let mut chains = Vec::new();
+        for (path, from) in &self.active {
+            let chain = LAYERS
+                .iter()
+                .filter(|layer| *layer >= from)
+                .map(|layer| path.channel(*layer))
+                .filter(|channel| !channel.is_empty())
+                .collect::<Vec<_>>()
+                .join(" → ");
+            if !chains.contains(&chain) {
+                chains.push(chain);
+            }
         }
+        chains
     }
 }
 
@@ -30,11 +90,52 @@ impl super::PanelController for LogicalChannels {
             // in a closure to avoid borrow checker
             let borrowed = &self.data.borrow();
             let events = &borrowed.events;
-            if let Some(one_log) = events.get(borrowed.current_index) {
-                if let Some(log) = &one_log.channel {
-                    self.channel = log.to_owned();
+            let state = (borrowed.current_index, events.len());
+            if self.detected_for != Some(state) {
+                self.detected_for = Some(state);
+                let end = (state.0 + 1).min(state.1);
+                if let Some(technology) = events[..end]
+                    .iter()
+                    .rev()
+                    .take(DETECTION_DEPTH)
+                    .find_map(channels::technology)
+                {
+                    self.detected = Some(technology);
                 }
             }
+            let computed = (
+                state.0,
+                state.1,
+                self.technology(),
+                self.window,
+                borrowed.filter_version,
+            );
+            if self.computed != Some(computed) {
+                let map = channel_map(computed.2);
+                self.active = events
+                    .get(state.0)
+                    .map(|log| map.active(log))
+                    .unwrap_or_default();
+                match self.window {
+                    Some(window) => {
+                        self.activity = Activity::compute(map, events, state.0, window);
+                    }
+                    None => {
+                        // counted since the previous state unless the logs changed
+                        let end = (state.0 + 1).min(state.1);
+                        let same_logs = self.computed.is_some_and(|previous| {
+                            (previous.2, previous.3, previous.4) == (computed.2, None, computed.4)
+                        });
+                        if !same_logs || end < self.counted {
+                            self.activity = Activity::new(ALL_LOGS_DECAY);
+                            self.counted = 0;
+                        }
+                        self.activity.add(map, &events[self.counted..end]);
+                        self.counted = end;
+                    }
+                }
+                self.computed = Some(computed);
+            }
         }
         egui::Window::new(self.window_title())
             .default_width(320.0)
@@ -47,27 +148,40 @@ impl super::PanelController for LogicalChannels {
     }
 }
 
-pub fn make_label(ui: &mut egui::Ui, label: &str, state: &str, color: &str) {
+fn layer_color(layer: Layer) -> Color32 {
+    match layer {
+        Layer::Logical => Color32::from_rgb(255, 84, 84),
+        Layer::Transport => Color32::from_rgb(68, 143, 255),
+        Layer::Physical => Color32::from_rgb(255, 181, 68),
+    }
+}
+
+/// Background of a channel from its heat, from 0 to 1
+fn heat_color(heat: f32) -> Color32 {
+    Color32::from_rgba_unmultiplied(255, 140, 0, (40.0 + heat * 160.0) as u8)
+}
+
+fn layer_name(layer: Layer) -> &'static str {
+    match layer {
+        Layer::Logical => "Canaux logiques",
+        Layer::Transport => "Canaux de transport",
+        Layer::Physical => "Canaux physiques",
+    }
+}
+
+pub fn make_label(ui: &mut egui::Ui, label: &str, highlight: Option<Color32>) -> egui::Response {
     use egui::text::LayoutJob;
     let mut job = LayoutJob::default();
     let mut format = TextFormat {
         ..Default::default()
     };
-    if label == state {
+    if let Some(color) = highlight {
         format.color = Color32::BLACK;
-        format.background = match color {
-            "red" => Color32::from_rgb(255, 84, 84),
-            "blue" => Color32::from_rgb(68, 143, 255),
-            "orange" => Color32::from_rgb(255, 181, 68),
-            "green" => Color32::from_rgb(90, 235, 100),
-            _ => Color32::from_rgb(90, 235, 100),
-        };
+        format.background = color;
     }
 
     job.append(label, 0.0, format);
-    ui.vertical_centered(|ui| {
-        ui.label(job);
-    });
+    ui.vertical_centered(|ui| ui.label(job)).inner
 }
 
 pub fn print_on_grid(ui: &mut egui::Ui, label: &str) {
@@ -78,67 +192,73 @@ pub fn print_on_grid(ui: &mut egui::Ui, label: &str) {
 
 impl super::PanelView for LogicalChannels {
     fn ui(&mut self, ui: &mut egui::Ui) {
-        egui::Grid::new("some_unique_id")
-            .max_col_width(120.0)
-            .show(ui, |ui| {
-                make_label(ui, "PCCH", &self.channel, "red");
-                make_label(ui, "BCCH", &self.channel, "red");
-                make_label(ui, "CCCH", &self.channel, "green");
-                make_label(ui, "DCCH", &self.channel, "red");
-                make_label(ui, "DTCH", &self.channel, "red");
-                make_label(ui, "MCCH", &self.channel, "red");
-                make_label(ui, "MTCH", &self.channel, "red");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "Canaux Logiques");
-                print_on_grid(ui, "----");
-                make_label(ui, "CCCH", &self.channel, "red");
-                make_label(ui, "DCCH", &self.channel, "red");
-                make_label(ui, "DTCH", &self.channel, "red");
-                ui.end_row();
-
-                make_label(ui, "PCH", &self.channel, "red");
-                make_label(ui, "BCH", &self.channel, "red");
-                print_on_grid(ui, "");
-                print_on_grid(ui, "");
-                make_label(ui, "DL-SCH", &self.channel, "red");
-                print_on_grid(ui, "");
-                make_label(ui, "MCH", &self.channel, "red");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "Canaux de Transport");
-                print_on_grid(ui, "----");
-                make_label(ui, "RACH", &self.channel, "blue");
-                make_label(ui, "UL-SCH", &self.channel, "blue");
-                ui.end_row();
-
-                make_label(ui, "PDSCH", &self.channel, "blue");
-                make_label(ui, "PBCH", &self.channel, "orange");
-                print_on_grid(ui, "");
-                print_on_grid(ui, "");
-                make_label(ui, "PDCCH", &self.channel, "orange");
-                print_on_grid(ui, "");
-                make_label(ui, "PMCH", &self.channel, "orange");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "Canaux Logiques");
-                print_on_grid(ui, "----");
-                make_label(ui, "PRACH", &self.channel, "blue");
-                make_label(ui, "PUSCH", &self.channel, "blue");
-                make_label(ui, "PUCCH", &self.channel, "orange");
-                ui.end_row();
-
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "Downlink");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "");
-                print_on_grid(ui, "Technologie : LTE");
-                print_on_grid(ui, "");
-                print_on_grid(ui, "----");
-                print_on_grid(ui, "Uplink");
-                print_on_grid(ui, "----");
-                ui.end_row();
+        let detected = self.detected.map_or("?", Technology::name);
+        ui.horizontal(|ui| {
+            ui.label("Technologie :");
+            egui::ComboBox::from_id_source("technology")
+                .selected_text(match self.selection {
+                    Some(technology) => technology.name().to_owned(),
+                    None => format!("{} (auto)", detected),
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.selection, None, format!("Auto ({})", detected));
+                    ui.selectable_value(&mut self.selection, Some(Technology::Lte), "LTE");
+                    ui.selectable_value(&mut self.selection, Some(Technology::Nr), "NR");
+                });
+            ui.label("Fenêtre :");
+            let window_name = |window| {
+                WINDOWS
+                    .iter()
+                    .find(|(w, _)| *w == window)
+                    .map_or("", |(_, name)| *name)
+            };
+            egui::ComboBox::from_id_source("window")
+                .selected_text(window_name(self.window))
+                .show_ui(ui, |ui| {
+                    for (window, name) in WINDOWS {
+                        ui.selectable_value(&mut self.window, window, name);
+                    }
+                });
+        });
+        let map = channel_map(self.technology());
+        for (direction, title) in [
+            (Direction::Downlink, "Downlink"),
+            (Direction::Uplink, "Uplink"),
+        ] {
+            ui.separator();
+            ui.strong(title);
+            egui::Grid::new(title).max_col_width(120.0).show(ui, |ui| {
+                for layer in LAYERS {
+                    print_on_grid(ui, layer_name(layer));
+                    for channel in map.channels(direction, layer) {
+                        let count = self.activity.count(direction, layer, channel);
+                        let highlight = if self.is_active(direction, layer, channel) {
+                            Some(layer_color(layer))
+                        } else {
+                            let heat = self.activity.heat(direction, layer, channel);
+                            (heat > 0.0).then(|| heat_color(heat))
+                        };
+                        let label = match count {
+                            0 => channel.to_owned(),
+                            _ => format!("{} ({})", channel, count),
+                        };
+                        let mut hover = format!("{} messages", count);
+                        if let Some(note) = map.note(channel) {
+                            hover = format!("{}\n{}", note, hover);
+                        }
+                        make_label(ui, &label, highlight).on_hover_text(hover);
+                    }
+                    ui.end_row();
+                }
             });
+        }
+        ui.separator();
+        let chains = self.chains();
+        if chains.is_empty() {
+            ui.label("Aucun canal pour ce message");
+        }
+        for chain in chains {
+            ui.label(chain);
+        }
     }
 }
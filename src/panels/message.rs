@@ -1,15 +1,21 @@
-use crate::{display_log, Data};
+use crate::decoders::{decode_log, Node};
+use crate::{display_log, display_tree, Data};
 use eframe::egui;
 use std::cell::RefCell;
 use std::rc::Rc;
 
 pub struct MessageBox {
     data: Rc<RefCell<Data>>,
+    /// Decoded message of the log with this source and idx
+    decoded: Option<((usize, u64), Option<Node>)>,
 }
 
 impl MessageBox {
     pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
-        Self { data: ref_data }
+        Self {
+            data: ref_data,
+            decoded: None,
+        }
     }
 }
 
@@ -45,7 +51,18 @@ impl super::PanelView for MessageBox {
         ui.label(format!("Current msg index: {}", borrowed.current_index));
 
         if let Some(one_log) = events.get(borrowed.current_index) {
-            display_log(ui, &one_log);
+            // indices change when logs of several connections are merged
+            let key = (one_log.source, one_log.idx);
+            if !matches!(self.decoded, Some((decoded_key, _)) if decoded_key == key) {
+                self.decoded = Some((key, decode_log(one_log)));
+            }
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                display_log(ui, one_log);
+                if let Some((_, Some(tree))) = &self.decoded {
+                    ui.separator();
+                    display_tree(ui, tree);
+                }
+            });
         }
     }
 }
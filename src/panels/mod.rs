@@ -1,14 +1,34 @@
 pub mod about;
+pub mod dashboard;
 pub mod file_handler;
+pub mod hex_viewer;
+pub mod log_table;
 pub mod logical_channels;
 pub mod message;
+pub mod procedures;
+pub mod profile_manager;
+pub mod protocol;
+pub mod sequence_chart;
+pub mod session_info;
 pub mod socket_manager;
+pub mod timeline;
+pub mod ue_list;
 
 pub use about::AboutPanel;
+pub use dashboard::Dashboard;
 pub use file_handler::FileHandler;
+pub use hex_viewer::HexViewer;
+pub use log_table::LogTable;
 pub use logical_channels::LogicalChannels;
 pub use message::MessageBox;
+pub use procedures::ProceduresPanel;
+pub use profile_manager::ProfileManager;
+pub use protocol::ProtocolPanel;
+pub use sequence_chart::SequenceChart;
+pub use session_info::SessionInfo;
 pub use socket_manager::SocketManager;
+pub use timeline::Timeline;
+pub use ue_list::UePanel;
 
 use eframe::egui;
 
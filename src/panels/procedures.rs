@@ -0,0 +1,137 @@
+use crate::procedures::State;
+use crate::{format_timestamp, Data};
+use eframe::egui::{self, Color32};
+use egui_extras::{Column, TableBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct ProceduresPanel {
+    data: Rc<RefCell<Data>>,
+}
+
+impl ProceduresPanel {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self { data: ref_data }
+    }
+}
+
+fn state_color(state: State) -> Option<Color32> {
+    match state {
+        State::InProgress => None,
+        State::Success => Some(Color32::from_rgb(90, 200, 100)),
+        State::Failure => Some(Color32::RED),
+        State::Timeout => Some(Color32::from_rgb(255, 181, 68)),
+    }
+}
+
+impl super::PanelController for ProceduresPanel {
+    fn name(&self) -> &'static str {
+        "Procedures"
+    }
+    fn window_title(&self) -> &'static str {
+        "Procedures"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        let now = ctx.input(|i| i.time);
+        self.data.borrow_mut().update_procedures(now);
+        egui::Window::new(self.window_title())
+            .default_width(600.0)
+            .default_height(300.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for ProceduresPanel {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let data = self.data.borrow();
+        let list = &data.procedures.list;
+        let count = |state| list.iter().filter(|p| p.state == state).count();
+        ui.label(format!(
+            "{} procedures: {} successful, {} failed, {} timed out, {} in progress",
+            list.len(),
+            count(State::Success),
+            count(State::Failure),
+            count(State::Timeout),
+            count(State::InProgress)
+        ));
+
+        let current_index = data.current_index;
+        let mut selected = None;
+        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .sense(egui::Sense::click())
+            .column(Column::auto().at_least(90.0))
+            .column(Column::auto().at_least(120.0))
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::remainder().clip(true))
+            .header(20.0, |mut header| {
+                for title in [
+                    "Start",
+                    "Procedure",
+                    "UE",
+                    "State",
+                    "Duration",
+                    "Logs",
+                    "Outcome",
+                ] {
+                    header.col(|ui| {
+                        ui.strong(title);
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, list.len(), |mut row| {
+                    let procedure = &list[row.index()];
+                    row.set_selected(
+                        (procedure.first_index..=procedure.last_index).contains(&current_index),
+                    );
+                    row.col(|ui| {
+                        ui.monospace(format_timestamp(procedure.start));
+                    });
+                    row.col(|ui| {
+                        ui.label(procedure.kind.name());
+                    });
+                    row.col(|ui| {
+                        if let Some(ue_id) = procedure.ue_id {
+                            ui.label(ue_id.to_string());
+                        }
+                    });
+                    row.col(|ui| {
+                        let name = procedure.state.name();
+                        match state_color(procedure.state) {
+                            Some(color) => ui.colored_label(color, name),
+                            None => ui.label(name),
+                        };
+                    });
+                    row.col(|ui| {
+                        if let Some(duration) = procedure.duration() {
+                            ui.label(format!("{} ms", duration));
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.label(procedure.messages.to_string());
+                    });
+                    row.col(|ui| {
+                        ui.label(procedure.outcome.as_deref().unwrap_or_default());
+                    });
+                    if row.response().clicked() {
+                        selected = Some(procedure.first_index);
+                    }
+                });
+            });
+        drop(data);
+        if let Some(index) = selected {
+            self.data.borrow_mut().current_index = index;
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::export;
+use crate::profiles::{self, ConnectionProfile};
+use crate::protocol::Level;
+use eframe::egui;
+use poll_promise::Promise;
+
+#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[serde(default)]
+pub struct ProfileManager {
+    pub profiles: Vec<ConnectionProfile>,
+    #[serde(skip)]
+    pub is_open: bool,
+    #[serde(skip)]
+    selected: usize,
+    #[serde(skip)]
+    connect: Option<usize>, // profile to connect to, taken by the app
+    #[serde(skip)]
+    import: Option<Promise<Result<(String, String), String>>>,
+    #[serde(skip)]
+    export: Option<Promise<Result<String, String>>>,
+    #[serde(skip)]
+    outcome: Option<Result<String, String>>, // of the last import or export
+}
+
+impl ProfileManager {
+    /// Profile chosen to connect to, given once
+    pub fn take_connect(&mut self) -> Option<ConnectionProfile> {
+        let index = self.connect.take()?;
+        self.profiles.get(index).cloned()
+    }
+
+    pub fn add(&mut self, profile: ConnectionProfile) {
+        self.profiles.push(profile);
+        self.selected = self.profiles.len() - 1;
+    }
+
+    /// Quick-connect menu: one entry per profile
+    pub fn menu_ui(&mut self, ui: &mut egui::Ui) {
+        for (index, profile) in self.profiles.iter().enumerate() {
+            if ui
+                .button(&profile.name)
+                .on_hover_text(profile.url())
+                .clicked()
+            {
+                self.connect = Some(index);
+                ui.close_menu();
+            }
+        }
+        if !self.profiles.is_empty() {
+            ui.separator();
+        }
+        if ui.button("Manage profiles…").clicked() {
+            self.is_open = true;
+            ui.close_menu();
+        }
+    }
+
+    fn export_profiles(&mut self, ron: bool) {
+        let (file_name, content) = if ron {
+            ("profiles.ron", profiles::to_ron(&self.profiles))
+        } else {
+            ("profiles.json", profiles::to_json(&self.profiles))
+        };
+        match content {
+            Ok(content) => self.export = Some(export::save_file(file_name, content.into_bytes())),
+            Err(e) => self.outcome = Some(Err(e)),
+        }
+    }
+
+    /// Add the profiles of the imported file once it is read
+    fn poll_import(&mut self) {
+        let Some(result) = self
+            .import
+            .as_ref()
+            .and_then(|import| import.ready().cloned())
+        else {
+            return;
+        };
+        self.import = None;
+        self.outcome = Some(result.and_then(|(content, file_name)| {
+            let imported = profiles::parse(&content)?;
+            let count = imported.len();
+            self.profiles.extend(imported);
+            Ok(format!("{} profiles imported from {}", count, file_name))
+        }));
+    }
+
+    fn poll_export(&mut self) {
+        let Some(result) = self
+            .export
+            .as_ref()
+            .and_then(|export| export.ready().cloned())
+        else {
+            return;
+        };
+        self.export = None;
+        self.outcome = Some(result.map(|file_name| format!("Saved to {}", file_name)));
+    }
+}
+
+/// Settings of one profile
+fn profile_ui(ui: &mut egui::Ui, profile: &mut ConnectionProfile) {
+    egui::Grid::new("profile").show(ui, |ui| {
+        ui.label("Name");
+        ui.text_edit_singleline(&mut profile.name);
+        ui.end_row();
+        ui.label("URL");
+        ui.text_edit_singleline(&mut profile.url);
+        ui.end_row();
+        ui.label("Password");
+        ui.add(egui::TextEdit::singleline(&mut profile.password).password(true));
+        ui.end_row();
+        ui.label("Secure");
+        ui.checkbox(&mut profile.secure, "wss://");
+        ui.end_row();
+        ui.label("Max frame");
+        let mut kib = profile.options.max_frame_size / 1024;
+        let frame_size = egui::DragValue::new(&mut kib)
+            .clamp_range(1..=1024 * 1024)
+            .suffix(" KiB");
+        if ui.add(frame_size).changed() {
+            profile.options.max_frame_size = kib * 1024;
+        }
+        ui.end_row();
+        ui.label("Follow");
+        ui.checkbox(&mut profile.follow, "");
+        ui.end_row();
+    });
+    ui.collapsing("Layers", |ui| {
+        egui::Grid::new("profile_layers")
+            .striped(true)
+            .show(ui, |ui| {
+                for (name, layer) in profile.layers.all_mut() {
+                    ui.label(name);
+                    egui::ComboBox::from_id_source(("profile", name))
+                        .selected_text(layer.level.name())
+                        .show_ui(ui, |ui| {
+                            for level in Level::ALL {
+                                ui.selectable_value(&mut layer.level, level, level.name());
+                            }
+                        });
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+impl super::PanelController for ProfileManager {
+    fn name(&self) -> &'static str {
+        "Profiles"
+    }
+    fn window_title(&self) -> &'static str {
+        "Connection profiles"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        self.poll_import();
+        self.poll_export();
+        egui::Window::new(self.window_title())
+            .default_width(480.0)
+            .default_height(400.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for ProfileManager {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let has_selection = self.selected < self.profiles.len();
+        ui.horizontal(|ui| {
+            if ui.button("New").clicked() {
+                self.add(ConnectionProfile::default());
+            }
+            if ui
+                .add_enabled(has_selection, egui::Button::new("Duplicate"))
+                .clicked()
+            {
+                let mut copy = self.profiles[self.selected].clone();
+                copy.name.push_str(" (copy)");
+                self.add(copy);
+            }
+            if ui
+                .add_enabled(has_selection, egui::Button::new("Delete"))
+                .clicked()
+            {
+                self.profiles.remove(self.selected);
+                self.selected = self.selected.saturating_sub(1);
+            }
+            ui.separator();
+            if ui.button("Import…").clicked() {
+                self.import = Some(export::open_file());
+            }
+            if ui.button("Export RON…").clicked() {
+                self.export_profiles(true);
+            }
+            if ui.button("Export JSON…").clicked() {
+                self.export_profiles(false);
+            }
+            if self.import.is_some() || self.export.is_some() {
+                ui.add(egui::Spinner::new());
+            }
+        });
+        ui.label("Passwords are not exported");
+        match &self.outcome {
+            Some(Ok(outcome)) => {
+                ui.label(outcome);
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, e);
+            }
+            None => {}
+        }
+        ui.separator();
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                for (index, profile) in self.profiles.iter().enumerate() {
+                    ui.selectable_value(&mut self.selected, index, &profile.name);
+                }
+            });
+            ui.separator();
+            ui.vertical(|ui| {
+                let Some(profile) = self.profiles.get_mut(self.selected) else {
+                    ui.label("No profile selected");
+                    return;
+                };
+                profile_ui(ui, profile);
+                if ui.button("Connect").clicked() {
+                    self.connect = Some(self.selected);
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Data;
+use eframe::egui::{self, RichText};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct ProtocolPanel {
+    data: Rc<RefCell<Data>>,
+    errors_only: bool,
+    source_id: usize, // connection of the diagnostics shown
+}
+
+impl ProtocolPanel {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            errors_only: false,
+            source_id: 0,
+        }
+    }
+}
+
+impl super::PanelController for ProtocolPanel {
+    fn name(&self) -> &'static str {
+        "Protocol"
+    }
+    fn window_title(&self) -> &'static str {
+        "Protocol diagnostics"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.window_title())
+            .default_width(500.0)
+            .default_height(400.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for ProtocolPanel {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let mut borrowed = self.data.borrow_mut();
+        if self.source_id >= borrowed.connections.len() {
+            return;
+        }
+        ui.horizontal(|ui| {
+            if borrowed.connections.len() > 1 {
+                egui::ComboBox::from_id_source("protocol_source")
+                    .selected_text(borrowed.source_name(self.source_id))
+                    .show_ui(ui, |ui| {
+                        for (id, connection) in borrowed.connections.iter().enumerate() {
+                            ui.selectable_value(&mut self.source_id, id, connection.source.name());
+                        }
+                    });
+            }
+            ui.checkbox(&mut self.errors_only, "Errors only");
+            if ui.button("Clear").clicked() {
+                borrowed.connections[self.source_id]
+                    .session
+                    .diagnostics
+                    .clear();
+            }
+        });
+        let diagnostics = &borrowed.connections[self.source_id].session.diagnostics;
+        egui::Grid::new("counts").striped(true).show(ui, |ui| {
+            ui.strong("Message");
+            ui.strong("Received");
+            ui.end_row();
+            for (name, count) in &diagnostics.counts {
+                ui.label(name);
+                ui.label(count.to_string());
+                ui.end_row();
+            }
+            ui.label("Decode errors");
+            if diagnostics.decode_errors > 0 {
+                let count = diagnostics.decode_errors.to_string();
+                ui.colored_label(ui.visuals().error_fg_color, count);
+            } else {
+                ui.label("0");
+            }
+            ui.end_row();
+        });
+
+        ui.separator();
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                // most recent first
+                for entry in diagnostics.entries.iter().rev() {
+                    if self.errors_only && !entry.is_error {
+                        continue;
+                    }
+                    let mut title = format!("#{} {}", entry.number, entry.kind);
+                    if let Some(id) = entry.message_id {
+                        title.push_str(&format!(" (message_id {})", id));
+                    }
+                    let mut title = RichText::new(title);
+                    if entry.is_error {
+                        title = title.color(ui.visuals().error_fg_color);
+                    }
+                    egui::CollapsingHeader::new(title)
+                        .id_source(entry.number)
+                        .show(ui, |ui| ui.monospace(&entry.text));
+                }
+            });
+    }
+}
@@ -0,0 +1,188 @@
+use crate::export;
+use crate::sequence::Chart;
+use crate::{format_timestamp, Data};
+use eframe::egui::{self, Align2, FontId, Pos2, Rect, Sense, Stroke, Vec2};
+use poll_promise::Promise;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const ROW_HEIGHT: f32 = 24.0;
+const HEADER_HEIGHT: f32 = 24.0;
+const TIME_WIDTH: f32 = 90.0;
+const ARROW_HEAD: f32 = 6.0;
+
+pub struct SequenceChart {
+    data: Rc<RefCell<Data>>,
+    chart: Chart,
+    checked: usize,      // number of events in `chart`
+    filter_version: u64, // `Data::filter_version` the chart was built with
+    export: Option<Promise<Result<String, String>>>,
+}
+
+impl SequenceChart {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            chart: Chart::default(),
+            checked: 0,
+            filter_version: 0,
+            export: None,
+        }
+    }
+
+    /// Add the logs received since last frame to the chart
+    fn update_chart(&mut self) {
+        let data = self.data.borrow();
+        if self.checked > data.events.len() || self.filter_version != data.filter_version {
+            // events were replaced or the filter changed
+            self.chart = Chart::default();
+            self.checked = 0;
+            self.filter_version = data.filter_version;
+        }
+        for (index, one_log) in data.events.iter().enumerate().skip(self.checked) {
+            if data.is_visible(one_log) {
+                self.chart.push(index, one_log);
+            }
+        }
+        self.checked = data.events.len();
+    }
+
+    fn export_chart(&mut self, file_name: &str, content: String) {
+        self.export = Some(export::save_file(file_name, content.into_bytes()));
+    }
+}
+
+impl super::PanelController for SequenceChart {
+    fn name(&self) -> &'static str {
+        "Sequence chart"
+    }
+    fn window_title(&self) -> &'static str {
+        "Sequence chart"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        self.update_chart();
+        egui::Window::new(self.window_title())
+            .default_width(700.0)
+            .default_height(500.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for SequenceChart {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Export:");
+            if ui.button("SVG").clicked() {
+                self.export_chart("sequence.svg", self.chart.to_svg());
+            }
+            if ui.button("PlantUML").clicked() {
+                self.export_chart("sequence.puml", self.chart.to_plantuml());
+            }
+            if ui.button("Mermaid").clicked() {
+                self.export_chart("sequence.mmd", self.chart.to_mermaid());
+            }
+            if let Some(export) = &self.export {
+                match export.ready() {
+                    None => {
+                        ui.add(egui::Spinner::new());
+                    }
+                    Some(Ok(file_name)) => {
+                        ui.label(format!("Saved to {}", file_name));
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::RED, e);
+                    }
+                }
+            }
+        });
+        if self.chart.arrows.is_empty() {
+            ui.label("No RRC, NAS, S1AP or NGAP message");
+            return;
+        }
+
+        let visuals = ui.visuals().clone();
+        let font = FontId::proportional(12.0);
+        let columns = self.chart.entities.len() as f32;
+        let column_x = |rect: Rect, column: usize| {
+            let width = (rect.width() - TIME_WIDTH) / columns;
+            rect.left() + TIME_WIDTH + (column as f32 + 0.5) * width
+        };
+
+        let (header, _) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), HEADER_HEIGHT),
+            Sense::hover(),
+        );
+        for (column, entity) in self.chart.entities.iter().enumerate() {
+            ui.painter().text(
+                Pos2::new(column_x(header, column), header.center().y),
+                Align2::CENTER_CENTER,
+                entity.name(),
+                FontId::proportional(14.0),
+                visuals.strong_text_color(),
+            );
+        }
+
+        let current_index = self.data.borrow().current_index;
+        let mut selected = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show_rows(ui, ROW_HEIGHT, self.chart.arrows.len(), |ui, rows| {
+                for arrow in &self.chart.arrows[rows] {
+                    let (rect, response) = ui.allocate_exact_size(
+                        Vec2::new(ui.available_width(), ROW_HEIGHT),
+                        Sense::click(),
+                    );
+                    let painter = ui.painter();
+                    if arrow.index == current_index {
+                        painter.rect_filled(rect, 0.0, visuals.selection.bg_fill);
+                    } else if response.hovered() {
+                        painter.rect_filled(rect, 0.0, visuals.faint_bg_color);
+                    }
+                    for column in 0..self.chart.entities.len() {
+                        let x = column_x(rect, column);
+                        painter.line_segment(
+                            [Pos2::new(x, rect.top()), Pos2::new(x, rect.bottom())],
+                            visuals.widgets.noninteractive.bg_stroke,
+                        );
+                    }
+                    painter.text(
+                        Pos2::new(rect.left() + 2.0, rect.center().y),
+                        Align2::LEFT_CENTER,
+                        format_timestamp(arrow.timestamp),
+                        font.clone(),
+                        visuals.weak_text_color(),
+                    );
+                    let y = rect.bottom() - 5.0;
+                    let from = column_x(rect, self.chart.column(arrow.from));
+                    let to = column_x(rect, self.chart.column(arrow.to));
+                    let stroke = Stroke::new(1.5, visuals.text_color());
+                    painter.line_segment([Pos2::new(from, y), Pos2::new(to, y)], stroke);
+                    let back = if to > from { -ARROW_HEAD } else { ARROW_HEAD };
+                    for side in [-ARROW_HEAD / 2.0, ARROW_HEAD / 2.0] {
+                        painter.line_segment(
+                            [Pos2::new(to, y), Pos2::new(to + back, y + side)],
+                            stroke,
+                        );
+                    }
+                    painter.text(
+                        Pos2::new((from + to) / 2.0, y - 2.0),
+                        Align2::CENTER_BOTTOM,
+                        &arrow.label,
+                        font.clone(),
+                        visuals.text_color(),
+                    );
+                    if response.clicked() {
+                        selected = Some(arrow.index);
+                    }
+                }
+            });
+        if let Some(index) = selected {
+            self.data.borrow_mut().current_index = index;
+        }
+    }
+}
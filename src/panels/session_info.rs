@@ -0,0 +1,147 @@
+use crate::connection::Connection;
+use crate::headers::{self, HeaderInfo};
+use crate::protocol::Layers;
+use crate::Data;
+use eframe::egui;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct SessionInfo {
+    data: Rc<RefCell<Data>>,
+    infos: Vec<(usize, HeaderInfo)>, // by connection, with the number of header lines parsed
+}
+
+impl SessionInfo {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            infos: Vec::new(),
+        }
+    }
+}
+
+fn optional(ui: &mut egui::Ui, value: &Option<String>) {
+    ui.label(value.as_deref().unwrap_or("-"));
+}
+
+impl super::PanelController for SessionInfo {
+    fn name(&self) -> &'static str {
+        "Session info"
+    }
+    fn window_title(&self) -> &'static str {
+        "Session info"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        {
+            let borrowed = self.data.borrow();
+            let connections = &borrowed.connections;
+            self.infos.resize_with(connections.len(), Default::default);
+            for (connection, (parsed, info)) in connections.iter().zip(self.infos.iter_mut()) {
+                let headers = &connection.session.headers;
+                if headers.len() != *parsed {
+                    *parsed = headers.len();
+                    *info = headers::parse(headers);
+                }
+            }
+        }
+        egui::Window::new(self.window_title())
+            .default_width(500.0)
+            .default_height(400.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for SessionInfo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let borrowed = self.data.borrow();
+        let connections = borrowed.connections.iter().zip(&self.infos);
+        if borrowed.connections.len() == 1 {
+            for (connection, (_, info)) in connections {
+                let profile = connection.log_profile(&borrowed.profile);
+                connection_ui(ui, connection, info, &profile.layers);
+            }
+            return;
+        }
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (id, (connection, (_, info))) in connections.enumerate() {
+                egui::CollapsingHeader::new(connection.source.name())
+                    .id_source(id)
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        let profile = connection.log_profile(&borrowed.profile);
+                        connection_ui(ui, connection, info, &profile.layers)
+                    });
+            }
+        });
+    }
+}
+
+/// Software, cells and layers of the server of a connection
+fn connection_ui(
+    ui: &mut egui::Ui,
+    connection: &Connection,
+    info: &HeaderInfo,
+    layers: &Layers, // requested on this connection
+) {
+    egui::Grid::new("software").show(ui, |ui| {
+        if let Some(server) = &connection.session.server {
+            ui.label("Server");
+            ui.label(format!(
+                "{} {} (version {})",
+                server.kind, server.name, server.version
+            ));
+            ui.end_row();
+        }
+        ui.label("Component");
+        optional(ui, &info.component);
+        ui.end_row();
+        ui.label("Software version");
+        optional(ui, &info.version);
+        ui.end_row();
+    });
+
+    ui.separator();
+    ui.strong("Cells");
+    if info.cells.is_empty() {
+        ui.label("No cell in the headers");
+    } else {
+        egui::Grid::new("cells").striped(true).show(ui, |ui| {
+            for title in ["Cell", "PCI", "ARFCN", "Bandwidth"] {
+                ui.strong(title);
+            }
+            ui.end_row();
+            for cell in &info.cells {
+                ui.label(&cell.id).on_hover_text(&cell.description);
+                optional(ui, &cell.pci);
+                optional(ui, &cell.arfcn);
+                optional(ui, &cell.bandwidth);
+                ui.end_row();
+            }
+        });
+    }
+
+    ui.separator();
+    ui.strong("Layers");
+    if let Some(layers) = &info.layers {
+        ui.label(format!("Logged: {}", layers));
+    }
+    let requested: Vec<String> = layers
+        .all()
+        .into_iter()
+        .map(|(name, layer)| format!("{}={}", name, layer.level.name()))
+        .collect();
+    ui.label(format!("Requested: {}", requested.join(", ")));
+
+    ui.separator();
+    let headers = &connection.session.headers;
+    ui.collapsing(format!("Headers ({} lines)", headers.len()), |ui| {
+        for line in headers {
+            ui.monospace(line);
+        }
+    });
+}
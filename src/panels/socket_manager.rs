@@ -1,32 +1,96 @@
+use crate::protocol::{Level, LogProfile};
+use crate::session::SessionState;
 use crate::Data;
 use eframe::egui;
-use ewebsock::WsMessage;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 
+/// Seconds the server may hold a follow request while it has no new logs
+const FOLLOW_TIMEOUT: u64 = 1;
+/// Most logs of an answer to a follow request: with a single request
+/// outstanding, a busy server is read a bounded number of logs per frame
+const FOLLOW_MAX: u64 = 512;
+
 pub struct SocketManager {
     data: Rc<RefCell<Data>>,
-    msg_id: u64,
-    layers: Layers,
+    follow_requests: BTreeMap<usize, u64>, // message_id of the outstanding `log_get` by connection
+    followed: Option<(usize, u64)>, // source and idx of the last log selected by the follow mode
+    followed_len: usize,            // number of events when it was selected
 }
 
 impl SocketManager {
     pub fn new(ws_sender: Rc<RefCell<Data>>) -> Self {
         Self {
             data: ws_sender,
-            msg_id: 1,
-            layers: Layers::new(),
+            follow_requests: BTreeMap::new(),
+            followed: None,
+            followed_len: 0,
+        }
+    }
+
+    /// Whether the user moved away from the last log while following
+    fn is_paused(&self) -> bool {
+        let data = self.data.borrow();
+        let current = data.events.get(data.current_index);
+        self.followed
+            .is_some_and(|followed| current.map(|log| (log.source, log.idx)) != Some(followed))
+    }
+
+    /// Whether the logs of any connection are followed
+    fn is_following(&self) -> bool {
+        let data = self.data.borrow();
+        (0..data.connections.len()).any(|source_id| data.is_followed(source_id))
+    }
+
+    fn resume(&mut self) {
+        self.followed = None;
+        self.followed_len = 0;
+    }
+
+    /// Keep a `log_get` outstanding and select the last log as they arrive
+    fn update_follow(&mut self) {
+        if !self.is_following() {
+            return;
+        }
+        let paused = self.is_paused();
+        let mut data = self.data.borrow_mut();
+        if !paused && data.events.len() != self.followed_len {
+            self.followed_len = data.events.len();
+            if let Some(last) = data.events.iter().rposition(|log| data.is_visible(log)) {
+                data.current_index = last;
+                self.followed = Some((data.events[last].source, data.events[last].idx));
+            }
+        }
+        for source_id in 0..data.connections.len() {
+            let connection = &data.connections[source_id];
+            let session = &connection.session;
+            let waiting = self
+                .follow_requests
+                .get(&source_id)
+                .is_some_and(|id| session.pending().contains_key(id));
+            if waiting
+                || !data.is_followed(source_id)
+                || !session.is_ready()
+                || !connection.source.is_remote()
+            {
+                continue;
+            }
+            match data.poll_logs(source_id, FOLLOW_TIMEOUT, FOLLOW_MAX) {
+                Ok(id) => {
+                    self.follow_requests.insert(source_id, id);
+                }
+                Err(e) => {
+                    log::warn!("Cannot follow the logs: {}", e);
+                    data.follow = false;
+                    data.connections[source_id].follow = false;
+                }
+            }
         }
     }
     pub fn get_more_logs(&mut self) {
-        let msg = LogGet::new(self.msg_id, self.layers.clone());
-        self.msg_id += 1;
-        if let Ok(msg_stringed) = serde_json::to_string(&msg) {
-            log::info!("{}", msg_stringed);
-            self.data
-                .borrow_mut()
-                .ws_sender
-                .send(WsMessage::Text(msg_stringed));
+        if let Err(e) = self.data.borrow_mut().request_logs() {
+            log::warn!("Cannot get more logs: {}", e);
         }
     }
 }
@@ -40,6 +104,7 @@ impl super::PanelController for SocketManager {
     }
 
     fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        self.update_follow();
         egui::Window::new(self.window_title())
             .default_width(320.0)
             .default_height(480.0)
@@ -51,104 +116,49 @@ impl super::PanelController for SocketManager {
     }
 }
 
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
-struct Layers {
-    #[serde(rename(serialize = "PHY"))]
-    phy: String,
-    #[serde(rename(serialize = "MAC"))]
-    mac: String,
-    #[serde(rename(serialize = "RLC"))]
-    rlc: String,
-    #[serde(rename(serialize = "PDCP"))]
-    pdcp: String,
-    #[serde(rename(serialize = "RRC"))]
-    rrc: String,
-    #[serde(rename(serialize = "NAS"))]
-    nas: String,
-    #[serde(rename(serialize = "S72"))]
-    s72: String,
-    #[serde(rename(serialize = "S1AP"))]
-    s1ap: String,
-    #[serde(rename(serialize = "NGAP"))]
-    ngap: String,
-    #[serde(rename(serialize = "GTPU"))]
-    gtpu: String,
-    #[serde(rename(serialize = "X2AP"))]
-    x2ap: String,
-    #[serde(rename(serialize = "XnAP"))]
-    xnap: String,
-    #[serde(rename(serialize = "M2AP"))]
-    m2ap: String,
-    #[serde(rename(serialize = "LPPa"))]
-    lppa: String,
-    #[serde(rename(serialize = "NRPPa"))]
-    nrppa: String,
-    #[serde(rename(serialize = "TRX"))]
-    trx: String,
-}
-impl Layers {
-    pub fn new() -> Self {
-        Self {
-            phy: "debug".to_owned(),
-            mac: "warn".to_owned(),
-            rlc: "warn".to_owned(),
-            pdcp: "warn".to_owned(),
-            rrc: "debug".to_owned(),
-            nas: "debug".to_owned(),
-            s72: "warn".to_owned(),
-            s1ap: "warn".to_owned(),
-            ngap: "warn".to_owned(),
-            gtpu: "warn".to_owned(),
-            x2ap: "warn".to_owned(),
-            xnap: "warn".to_owned(),
-            m2ap: "warn".to_owned(),
-            lppa: "warn".to_owned(),
-            nrppa: "warn".to_owned(),
-            trx: "warn".to_owned(),
-        }
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-struct LogGet {
-    timeout: u64,
-    min: u64,
-    max: u64,
-    layers: Layers,
-    message: String,
-    headers: bool,
-    message_id: u64,
-}
-
-impl LogGet {
-    pub fn new(id: u64, layers: Layers) -> Self {
-        Self {
-            timeout: 1,
-            min: 64,
-            max: 2048,
-            layers: layers,
-            message: "log_get".to_owned(),
-            headers: false,
-            message_id: id,
-        }
-    }
-}
-
 impl super::PanelView for SocketManager {
     fn ui(&mut self, ui: &mut egui::Ui) {
+        {
+            let borrowed = self.data.borrow();
+            let several = borrowed.connections.len() > 1;
+            for connection in borrowed.connections.iter() {
+                let session = &connection.session;
+                if several {
+                    ui.strong(connection.source.name());
+                }
+                match &session.state {
+                    SessionState::Connecting => ui.label("Waiting for the server…"),
+                    SessionState::Authenticating => ui.label("Authenticating…"),
+                    SessionState::Ready => match &session.server {
+                        Some(server) => ui.label(format!(
+                            "{} {} (version {})",
+                            server.kind, server.name, server.version
+                        )),
+                        None => ui.label("Ready"),
+                    },
+                    SessionState::Failed(e) => ui.colored_label(egui::Color32::RED, e),
+                };
+                if !session.pending().is_empty() {
+                    ui.label(format!("Pending requests: {}", session.pending().len()));
+                }
+                if let Some(error) = session.errors.last() {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            }
+        }
         ui.horizontal(|ui| {
             if ui.button("Previous").clicked() {
                 log::info!("Previous");
-                if self.data.borrow().current_index > 0 {
-                    self.data.borrow_mut().current_index -= 1;
+                let previous = self.data.borrow().previous_visible();
+                if let Some(previous) = previous {
+                    self.data.borrow_mut().current_index = previous;
                 }
             }
             if ui.button("Next").clicked() {
                 log::info!("Next");
-                if self.data.borrow().events.len() > self.data.borrow().current_index + 1 {
-                    self.data.borrow_mut().current_index += 1;
+                let next = self.data.borrow().next_visible();
+                if let Some(next) = next {
+                    self.data.borrow_mut().current_index = next;
                 } else {
                     self.get_more_logs();
                 }
@@ -158,34 +168,89 @@ impl super::PanelView for SocketManager {
                 self.get_more_logs();
             }
         });
+        ui.horizontal(|ui| {
+            let is_remote = self
+                .data
+                .borrow()
+                .connections
+                .iter()
+                .any(|connection| connection.source.is_remote());
+            let mut follow = self.is_following();
+            let checkbox = ui.add_enabled(is_remote, egui::Checkbox::new(&mut follow, "Follow"));
+            if checkbox.changed() {
+                let mut data = self.data.borrow_mut();
+                data.follow = follow;
+                if !follow {
+                    // also stops the connections followed by their profile
+                    for connection in data.connections.iter_mut() {
+                        connection.follow = false;
+                    }
+                }
+                drop(data);
+                self.resume();
+            }
+            if follow {
+                if self.is_paused() {
+                    ui.label("Paused");
+                    if ui.button("Resume").clicked() {
+                        self.resume();
+                    }
+                } else {
+                    ui.label("Following the last log");
+                }
+            }
+        });
+        let mut borrowed = self.data.borrow_mut();
+        let profile = &mut borrowed.profile;
+        ui.collapsing("log_get options", |ui| {
+            egui::Grid::new("log_get").show(ui, |ui| {
+                ui.label("Timeout (s)");
+                ui.add(egui::DragValue::new(&mut profile.timeout).clamp_range(0..=60));
+                ui.end_row();
+                ui.label("Min logs");
+                ui.add(egui::DragValue::new(&mut profile.min).clamp_range(1..=profile.max));
+                ui.end_row();
+                ui.label("Max logs");
+                ui.add(egui::DragValue::new(&mut profile.max).clamp_range(1..=100_000));
+                ui.end_row();
+                ui.label("Headers");
+                ui.checkbox(&mut profile.headers, "");
+                ui.end_row();
+            });
+            if ui.button("Reset").clicked() {
+                *profile = LogProfile::default();
+            }
+        });
         ui.collapsing("Layers options", |ui| {
-            checkbox(ui, &mut self.layers.phy, "PHY");
-            checkbox(ui, &mut self.layers.mac, "MAC");
-            checkbox(ui, &mut self.layers.rlc, "RLC");
-            checkbox(ui, &mut self.layers.pdcp, "PDCP");
-            checkbox(ui, &mut self.layers.rrc, "RRC");
-            checkbox(ui, &mut self.layers.nas, "NAS");
-            checkbox(ui, &mut self.layers.s72, "S72");
-            checkbox(ui, &mut self.layers.s1ap, "S1AP");
-            checkbox(ui, &mut self.layers.ngap, "NGAP");
-            checkbox(ui, &mut self.layers.gtpu, "GTPU");
-            checkbox(ui, &mut self.layers.x2ap, "X2AP");
-            checkbox(ui, &mut self.layers.xnap, "XnAP");
-            checkbox(ui, &mut self.layers.m2ap, "M2AP");
-            checkbox(ui, &mut self.layers.lppa, "LPPa");
-            checkbox(ui, &mut self.layers.nrppa, "NRPPa");
-            checkbox(ui, &mut self.layers.trx, "TRX");
+            egui::Grid::new("layers").striped(true).show(ui, |ui| {
+                for title in ["Layer", "Level", "Max size", "Payload"] {
+                    ui.strong(title);
+                }
+                ui.end_row();
+                for (name, layer) in profile.layers.all_mut() {
+                    ui.label(name);
+                    egui::ComboBox::from_id_source(name)
+                        .selected_text(layer.level.name())
+                        .show_ui(ui, |ui| {
+                            for level in Level::ALL {
+                                ui.selectable_value(&mut layer.level, level, level.name());
+                            }
+                        });
+                    ui.horizontal(|ui| {
+                        let mut limited = layer.max_size.is_some();
+                        if ui.checkbox(&mut limited, "").changed() {
+                            layer.max_size = limited.then_some(64);
+                        }
+                        if let Some(max_size) = &mut layer.max_size {
+                            ui.add(egui::DragValue::new(max_size).suffix(" B"));
+                        }
+                    });
+                    ui.checkbox(&mut layer.payload, "");
+                    ui.end_row();
+                }
+            });
+            ui.label("Max size and payload are set on the server with config_set");
+            ui.label("Connections opened from a profile keep the layers of the profile");
         });
     }
 }
-
-fn checkbox(ui: &mut egui::Ui, string: &mut String, text: &str) {
-    let mut checked = string == "debug";
-    if ui.checkbox(&mut checked, text).changed() {
-        if checked {
-            *string = "debug".to_owned();
-        } else {
-            *string = "warn".to_owned();
-        };
-    };
-}
@@ -0,0 +1,363 @@
+use crate::{format_timestamp, Data, OneLog};
+use eframe::egui::{self, Align2, Color32, FontId, PointerButton, Pos2, Rect, Sense, Stroke, Vec2};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Lanes shown first, in the order of the protocol stack, other layers follow
+const STACK: [&str; 11] = [
+    "PHY", "MAC", "RLC", "PDCP", "RRC", "NAS", "S1AP", "NGAP", "X2AP", "XNAP", "GTPU",
+];
+
+const LANE_HEIGHT: f32 = 22.0;
+const LABEL_WIDTH: f32 = 60.0;
+const AXIS_HEIGHT: f32 = 18.0;
+/// Smallest time span shown, in milliseconds
+const MIN_SPAN: f64 = 1.0;
+/// Largest distance in pixels between a click and the log it selects
+const CLICK_DISTANCE: f32 = 6.0;
+/// Steps of the time axis, in milliseconds
+const TICK_STEPS: [u64; 19] = [
+    1, 2, 5, 10, 20, 50, 100, 200, 500, 1_000, 2_000, 5_000, 10_000, 30_000, 60_000, 120_000,
+    300_000, 600_000, 3_600_000,
+];
+
+const UPLINK: u8 = 1;
+const DOWNLINK: u8 = 2;
+const OTHER: u8 = 4;
+
+pub struct Timeline {
+    data: Rc<RefCell<Data>>,
+    lanes: Vec<String>,
+    bounds: Option<(u64, u64)>, // first and last timestamps
+    checked: usize,             // number of events in `lanes` and `bounds`
+    view: Option<(f64, f64)>,   // shown time range, `None` to fit all the logs
+    selecting: Option<(f64, f64)>,
+}
+
+impl Timeline {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            lanes: Vec::new(),
+            bounds: None,
+            checked: 0,
+            view: None,
+            selecting: None,
+        }
+    }
+
+    /// Take the events received since last frame into account
+    fn update_lanes(&mut self, events: &[OneLog]) {
+        if self.checked > events.len() {
+            // events were replaced
+            self.lanes.clear();
+            self.bounds = None;
+            self.checked = 0;
+            self.view = None;
+        }
+        for one_log in &events[self.checked..] {
+            if !self.lanes.contains(&one_log.layer) {
+                self.lanes.push(one_log.layer.clone());
+            }
+            let (first, last) = self
+                .bounds
+                .unwrap_or((one_log.timestamp, one_log.timestamp));
+            self.bounds = Some((first.min(one_log.timestamp), last.max(one_log.timestamp)));
+        }
+        if self.checked < events.len() {
+            self.lanes.sort_by_key(|layer| lane_order(layer));
+        }
+        self.checked = events.len();
+    }
+}
+
+fn lane_order(layer: &str) -> (usize, String) {
+    let upper = layer.to_uppercase();
+    let position = STACK.iter().position(|name| *name == upper);
+    (position.unwrap_or(STACK.len()), upper)
+}
+
+/// Step of the time axis giving ticks at least `min_width` pixels apart
+fn tick_step(span: f64, width: f32, min_width: f32) -> u64 {
+    let step = TICK_STEPS
+        .iter()
+        .find(|step| **step as f64 / span * width as f64 >= min_width as f64);
+    *step.unwrap_or(&TICK_STEPS[TICK_STEPS.len() - 1])
+}
+
+impl super::PanelController for Timeline {
+    fn name(&self) -> &'static str {
+        "Timeline"
+    }
+    fn window_title(&self) -> &'static str {
+        "Timeline"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.window_title())
+            .default_width(800.0)
+            .default_height(300.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for Timeline {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let data = Rc::clone(&self.data);
+        self.update_lanes(&data.borrow().events);
+        let time_range = data.borrow().time_range;
+        ui.horizontal(|ui| {
+            if ui.button("Fit").clicked() {
+                self.view = None;
+            }
+            if let Some((start, end)) = time_range {
+                ui.label(format!(
+                    "Range: {} – {}",
+                    format_timestamp(start),
+                    format_timestamp(end)
+                ));
+                if ui.button("Clear range").clicked() {
+                    data.borrow_mut().set_time_range(None);
+                }
+            }
+        })
+        .response
+        .on_hover_text("Scroll to zoom, drag to pan, right-drag to select a range, click to jump");
+        let Some((first, last)) = self.bounds else {
+            ui.label("No logs");
+            return;
+        };
+
+        let (mut start, mut end) = self
+            .view
+            .unwrap_or((first as f64, (last as f64).max(first as f64 + MIN_SPAN)));
+        let height = AXIS_HEIGHT + self.lanes.len() as f32 * LANE_HEIGHT;
+        let (rect, response) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), height),
+            Sense::click_and_drag(),
+        );
+        let plot = Rect::from_min_max(
+            Pos2::new(rect.left() + LABEL_WIDTH, rect.top() + AXIS_HEIGHT),
+            rect.max,
+        );
+        let width = plot.width().max(1.0);
+        let time_at = |x: f32, start: f64, end: f64| {
+            start + (x - plot.left()) as f64 / width as f64 * (end - start)
+        };
+
+        // zoom around the pointer and pan
+        if let Some(pointer) = response.hover_pos() {
+            let (scroll, zoom) = ui.input(|i| (i.smooth_scroll_delta.y, i.zoom_delta()));
+            let factor = (-scroll as f64 * 0.005).exp() / zoom as f64;
+            if factor != 1.0 {
+                let anchor = time_at(pointer.x, start, end);
+                let span = ((end - start) * factor).max(MIN_SPAN);
+                let ratio = (anchor - start) / (end - start);
+                start = anchor - span * ratio;
+                end = start + span;
+            }
+        }
+        if response.dragged_by(PointerButton::Primary) {
+            let shift = -response.drag_delta().x as f64 / width as f64 * (end - start);
+            start += shift;
+            end += shift;
+        }
+        if self.view.is_some() || start != first as f64 || end < last as f64 {
+            self.view = Some((start, end));
+        }
+        let x_at = |time: f64| plot.left() + ((time - start) / (end - start)) as f32 * width;
+
+        // range selection
+        if let Some(pointer) = response.interact_pointer_pos() {
+            let time = time_at(pointer.x, start, end);
+            if response.drag_started_by(PointerButton::Secondary) {
+                self.selecting = Some((time, time));
+            } else if let Some((from, _)) = self.selecting {
+                self.selecting = Some((from, time));
+            }
+        }
+        if response.drag_released_by(PointerButton::Secondary) {
+            if let Some((from, to)) = self.selecting.take() {
+                let range = (from.min(to).max(0.0) as u64, from.max(to).ceil() as u64);
+                data.borrow_mut().set_time_range(Some(range));
+            }
+        }
+
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        let text_color = visuals.text_color();
+        let font = FontId::proportional(11.0);
+
+        // lanes
+        for (lane, layer) in self.lanes.iter().enumerate() {
+            let top = plot.top() + lane as f32 * LANE_HEIGHT;
+            if lane % 2 == 0 {
+                painter.rect_filled(
+                    Rect::from_min_max(
+                        Pos2::new(rect.left(), top),
+                        Pos2::new(rect.right(), top + LANE_HEIGHT),
+                    ),
+                    0.0,
+                    visuals.faint_bg_color,
+                );
+            }
+            painter.text(
+                Pos2::new(rect.left() + 4.0, top + LANE_HEIGHT / 2.0),
+                Align2::LEFT_CENTER,
+                layer,
+                font.clone(),
+                text_color,
+            );
+        }
+
+        // time axis
+        let step = tick_step(end - start, width, 90.0);
+        let mut tick = (start.max(0.0) as u64 / step) * step;
+        while (tick as f64) <= end {
+            let x = x_at(tick as f64);
+            if x >= plot.left() {
+                painter.line_segment(
+                    [
+                        Pos2::new(x, rect.top() + AXIS_HEIGHT - 4.0),
+                        Pos2::new(x, rect.bottom()),
+                    ],
+                    Stroke::new(1.0, visuals.widgets.noninteractive.bg_stroke.color),
+                );
+                painter.text(
+                    Pos2::new(x + 2.0, rect.top()),
+                    Align2::LEFT_TOP,
+                    format_timestamp(tick),
+                    font.clone(),
+                    text_color,
+                );
+            }
+            tick += step;
+        }
+
+        // selected ranges
+        let selection_color = visuals.selection.bg_fill.gamma_multiply(0.3);
+        let ranges = [
+            time_range.map(|(from, to)| (from as f64, to as f64)),
+            self.selecting,
+        ];
+        for (from, to) in ranges.into_iter().flatten() {
+            let left = x_at(from.min(to)).max(plot.left());
+            let right = x_at(from.max(to)).min(plot.right());
+            if left < right {
+                painter.rect_filled(
+                    Rect::from_min_max(
+                        Pos2::new(left, plot.top()),
+                        Pos2::new(right, plot.bottom()),
+                    ),
+                    0.0,
+                    selection_color,
+                );
+            }
+        }
+
+        // logs, merged per pixel column so that any number of them is cheap to draw
+        let borrowed = data.borrow();
+        let lane_of: HashMap<&str, usize> = self
+            .lanes
+            .iter()
+            .enumerate()
+            .map(|(lane, layer)| (layer.as_str(), lane))
+            .collect();
+        let columns = width as usize + 1;
+        let mut marks = vec![0u8; self.lanes.len() * columns];
+        // events come in time order
+        let from = borrowed
+            .events
+            .partition_point(|log| (log.timestamp as f64) < start);
+        let to = borrowed
+            .events
+            .partition_point(|log| (log.timestamp as f64) <= end);
+        for one_log in &borrowed.events[from..to.max(from)] {
+            let Some(lane) = lane_of.get(one_log.layer.as_str()) else {
+                continue;
+            };
+            let column = (x_at(one_log.timestamp as f64) - plot.left()) as usize;
+            marks[lane * columns + column.min(columns - 1)] |= match one_log.dir.as_deref() {
+                Some("UL") | Some("TO") => UPLINK,
+                Some("DL") | Some("FROM") => DOWNLINK,
+                _ => OTHER,
+            };
+        }
+        let uplink_color = Color32::from_rgb(90, 200, 100);
+        let downlink_color = Color32::from_rgb(68, 143, 255);
+        for (index, mark) in marks.iter().enumerate() {
+            if *mark == 0 {
+                continue;
+            }
+            let x = plot.left() + (index % columns) as f32 + 0.5;
+            let middle = plot.top() + (index / columns) as f32 * LANE_HEIGHT + LANE_HEIGHT / 2.0;
+            if mark & UPLINK != 0 {
+                painter.line_segment(
+                    [
+                        Pos2::new(x, middle),
+                        Pos2::new(x, middle - LANE_HEIGHT / 2.0 + 3.0),
+                    ],
+                    Stroke::new(1.0, uplink_color),
+                );
+            }
+            if mark & DOWNLINK != 0 {
+                painter.line_segment(
+                    [
+                        Pos2::new(x, middle),
+                        Pos2::new(x, middle + LANE_HEIGHT / 2.0 - 3.0),
+                    ],
+                    Stroke::new(1.0, downlink_color),
+                );
+            }
+            if mark & OTHER != 0 {
+                painter.circle_filled(Pos2::new(x, middle), 1.5, text_color);
+            }
+        }
+
+        // current log
+        if let Some(current) = borrowed.events.get(borrowed.current_index) {
+            let x = x_at(current.timestamp as f64);
+            if plot.x_range().contains(x) {
+                painter.line_segment(
+                    [Pos2::new(x, plot.top()), Pos2::new(x, plot.bottom())],
+                    Stroke::new(1.0, Color32::RED),
+                );
+            }
+        }
+
+        // jump to the log nearest to a click, in the lane clicked
+        let mut selected = None;
+        if response.clicked() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let lane = ((pointer.y - plot.top()) / LANE_HEIGHT) as usize;
+                let layer = self.lanes.get(lane).filter(|_| pointer.y >= plot.top());
+                selected = borrowed.events[from..to.max(from)]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, one_log)| Some(&one_log.layer) == layer)
+                    .map(|(index, one_log)| {
+                        (
+                            from + index,
+                            (x_at(one_log.timestamp as f64) - pointer.x).abs(),
+                        )
+                    })
+                    .filter(|(_, distance)| *distance <= CLICK_DISTANCE)
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(index, _)| index);
+            }
+        }
+        drop(borrowed);
+        if let Some(index) = selected {
+            data.borrow_mut().current_index = index;
+        }
+        if let Some(pointer) = response.hover_pos() {
+            response
+                .on_hover_text_at_pointer(format_timestamp(time_at(pointer.x, start, end) as u64));
+        }
+    }
+}
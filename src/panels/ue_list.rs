@@ -0,0 +1,154 @@
+use crate::ue::UeList;
+use crate::{format_timestamp, Data};
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct UePanel {
+    data: Rc<RefCell<Data>>,
+    ues: UeList,
+    checked: usize,     // number of events in `ues`
+    order_version: u64, // `Data::order_version` of the events in `ues`
+}
+
+impl UePanel {
+    pub fn new(ref_data: Rc<RefCell<Data>>) -> Self {
+        Self {
+            data: ref_data,
+            ues: UeList::default(),
+            checked: 0,
+            order_version: 0,
+        }
+    }
+
+    /// Add the logs received since last frame to the UE list
+    fn update_ues(&mut self) {
+        let data = self.data.borrow();
+        if self.checked > data.events.len() || self.order_version != data.order_version {
+            // events were replaced, or merged with the logs of another connection
+            self.ues = UeList::default();
+            self.checked = 0;
+            self.order_version = data.order_version;
+        }
+        for one_log in &data.events[self.checked..] {
+            self.ues.push(one_log);
+        }
+        self.checked = data.events.len();
+    }
+}
+
+impl super::PanelController for UePanel {
+    fn name(&self) -> &'static str {
+        "UEs"
+    }
+    fn window_title(&self) -> &'static str {
+        "UEs"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        self.update_ues();
+        egui::Window::new(self.window_title())
+            .default_width(700.0)
+            .default_height(300.0)
+            .open(open)
+            .show(ctx, |ui| {
+                use super::PanelView as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::PanelView for UePanel {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let selected_ue = self.data.borrow().ue_id;
+        let mut selection = selected_ue;
+        ui.horizontal(|ui| {
+            ui.label(format!("{} UEs", self.ues.ues.len()));
+            if let Some((source, ue_id)) = selected_ue {
+                let name = self.data.borrow().source_name(source).to_owned();
+                ui.label(format!("Showing the logs of UE {} of {}", ue_id, name));
+                if ui.button("All UEs").clicked() {
+                    selection = None;
+                }
+            }
+        });
+
+        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .sense(egui::Sense::click())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto().at_least(120.0))
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto().at_least(90.0))
+            .column(Column::auto().at_least(90.0))
+            .column(Column::auto())
+            .column(Column::remainder())
+            .header(20.0, |mut header| {
+                for title in [
+                    "UE",
+                    "RNTI",
+                    "IMSI",
+                    "TMSI",
+                    "Cell",
+                    "First seen",
+                    "Last seen",
+                    "State",
+                    "Logs",
+                ] {
+                    header.col(|ui| {
+                        ui.strong(title);
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, self.ues.ues.len(), |mut row| {
+                    let ue = &self.ues.ues[row.index()];
+                    row.set_selected(Some(ue.key()) == selected_ue);
+                    row.col(|ui| {
+                        ui.label(ue.ue_id.to_string())
+                            .on_hover_text(self.data.borrow().source_name(ue.source));
+                    });
+                    row.col(|ui| {
+                        if let Some(rnti) = ue.rnti {
+                            ui.monospace(format!("0x{:04x}", rnti));
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.monospace(ue.imsi.as_deref().unwrap_or_default());
+                    });
+                    row.col(|ui| {
+                        ui.monospace(ue.tmsi.as_deref().unwrap_or_default());
+                    });
+                    row.col(|ui| {
+                        if let Some(cell) = ue.cell {
+                            ui.label(cell.to_string());
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.monospace(format_timestamp(ue.first_seen));
+                    });
+                    row.col(|ui| {
+                        ui.monospace(format_timestamp(ue.last_seen));
+                    });
+                    row.col(|ui| {
+                        ui.label(ue.state.map_or("", |state| state.name()));
+                    });
+                    row.col(|ui| {
+                        ui.label(ue.messages.to_string());
+                    });
+                    if row.response().clicked() {
+                        // a second click shows all the UEs again
+                        selection = (selected_ue != Some(ue.key())).then_some(ue.key());
+                    }
+                });
+            });
+        if selection != selected_ue {
+            self.data.borrow_mut().set_ue_id(selection);
+        }
+    }
+}
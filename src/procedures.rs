@@ -0,0 +1,378 @@
+//! Detection of the standard procedures in the logs, from the names of their
+//! messages, with their outcome and duration
+
+use crate::decoders;
+use crate::OneLog;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    RrcSetup,
+    Reestablishment,
+    Attach,
+    Registration,
+    S1Handover,
+    N2Handover,
+    X2Handover,
+    XnHandover,
+    Paging,
+}
+
+impl Kind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::RrcSetup => "RRC setup",
+            Kind::Reestablishment => "RRC re-establishment",
+            Kind::Attach => "LTE attach",
+            Kind::Registration => "NR registration",
+            Kind::S1Handover => "S1 handover",
+            Kind::N2Handover => "N2 handover",
+            Kind::X2Handover => "X2 handover",
+            Kind::XnHandover => "Xn handover",
+            Kind::Paging => "Paging",
+        }
+    }
+}
+
+/// Messages are matched on the words of their names, `rrcConnectionRequest`
+/// and `RRC connection request` both being `rrc connection request`
+type Pattern = &'static [&'static str];
+
+struct Definition {
+    kind: Kind,
+    layers: &'static [&'static str],
+    start: &'static [Pattern],
+    success: &'static [Pattern],
+    failure: &'static [Pattern],
+    timeout: u64, // milliseconds
+    /// Whether the messages of the procedure belong to one UE, a paging is
+    /// answered by a UE that has no id yet
+    per_ue: bool,
+}
+
+const HANDOVER_START: &[Pattern] = &[&["handover", "required"], &["handover", "request"]];
+const HANDOVER_SUCCESS: &[Pattern] = &[
+    &["handover", "notify"],
+    &["ue", "context", "release", "command"],
+];
+const HANDOVER_FAILURE: &[Pattern] = &[
+    &["handover", "preparation", "failure"],
+    &["handover", "failure"],
+    &["handover", "cancel"],
+];
+const X2_HANDOVER_SUCCESS: &[Pattern] = &[&["ue", "context", "release"]];
+const X2_HANDOVER_FAILURE: &[Pattern] = &[
+    &["handover", "preparation", "failure"],
+    &["handover", "cancel"],
+];
+
+static DEFINITIONS: [Definition; 9] = [
+    Definition {
+        kind: Kind::RrcSetup,
+        layers: &["RRC"],
+        start: &[&["connection", "request"], &["setup", "request"]],
+        success: &[&["setup", "complete"]],
+        failure: &[&["connection", "reject"], &["rrc", "reject"]],
+        timeout: 2_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::Reestablishment,
+        layers: &["RRC"],
+        start: &[&["reestablishment", "request"]],
+        success: &[&["reestablishment", "complete"]],
+        failure: &[&["reestablishment", "reject"]],
+        timeout: 2_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::Attach,
+        layers: &["NAS", "RRC"],
+        start: &[&["attach", "request"]],
+        success: &[&["attach", "complete"]],
+        failure: &[&["attach", "reject"]],
+        timeout: 15_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::Registration,
+        layers: &["NAS", "RRC"],
+        start: &[&["registration", "request"]],
+        success: &[&["registration", "accept"]],
+        failure: &[&["registration", "reject"]],
+        timeout: 15_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::S1Handover,
+        layers: &["S1AP"],
+        start: HANDOVER_START,
+        success: HANDOVER_SUCCESS,
+        failure: HANDOVER_FAILURE,
+        timeout: 10_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::N2Handover,
+        layers: &["NGAP"],
+        start: HANDOVER_START,
+        success: HANDOVER_SUCCESS,
+        failure: HANDOVER_FAILURE,
+        timeout: 10_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::X2Handover,
+        layers: &["X2AP"],
+        start: &[&["handover", "request"]],
+        success: X2_HANDOVER_SUCCESS,
+        failure: X2_HANDOVER_FAILURE,
+        timeout: 10_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::XnHandover,
+        layers: &["XNAP"],
+        start: &[&["handover", "request"]],
+        success: X2_HANDOVER_SUCCESS,
+        failure: X2_HANDOVER_FAILURE,
+        timeout: 10_000,
+        per_ue: true,
+    },
+    Definition {
+        kind: Kind::Paging,
+        layers: &["RRC", "S1AP", "NGAP", "NAS"],
+        start: &[&["paging"]],
+        success: &[
+            &["connection", "request"],
+            &["setup", "request"],
+            &["service", "request"],
+        ],
+        failure: &[],
+        timeout: 5_000,
+        per_ue: false,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    InProgress,
+    Success,
+    Failure,
+    Timeout,
+}
+
+impl State {
+    pub fn name(self) -> &'static str {
+        match self {
+            State::InProgress => "in progress",
+            State::Success => "success",
+            State::Failure => "failure",
+            State::Timeout => "timeout",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    pub kind: Kind,
+    pub ue_id: Option<u64>,
+    pub state: State,
+    pub start: u64,
+    pub end: Option<u64>, // timestamp of the last message once finished
+    pub first_index: usize,
+    pub last_index: usize,
+    pub messages: usize,
+    pub outcome: Option<String>, // message that ended the procedure
+}
+
+impl Procedure {
+    pub fn duration(&self) -> Option<u64> {
+        Some(self.end?.saturating_sub(self.start))
+    }
+}
+
+/// Procedures found in the logs, in the order they started
+#[derive(Debug, Default)]
+pub struct Procedures {
+    pub list: Vec<Procedure>,
+    ongoing: HashMap<(Kind, usize, Option<u64>), usize>, // index in `list`
+}
+
+impl Procedures {
+    /// Take the log of index `index` into account
+    pub fn push(&mut self, index: usize, log: &OneLog) {
+        self.expire(log.timestamp);
+        let layer = log.layer.to_uppercase();
+        let mut names = Vec::new();
+        for definition in &DEFINITIONS {
+            if !definition.layers.contains(&layer.as_str()) {
+                continue;
+            }
+            if names.is_empty() {
+                names = message_names(log);
+            }
+            let is_any = |patterns: &[Pattern]| {
+                patterns
+                    .iter()
+                    .any(|pattern| names.iter().any(|words| contains(words, pattern)))
+            };
+            // the UE ids of two connections may be the same
+            let key = (
+                definition.kind,
+                log.source,
+                log.ue_id.filter(|_| definition.per_ue),
+            );
+            if let Some(&ongoing) = self.ongoing.get(&key) {
+                let procedure = &mut self.list[ongoing];
+                let outcome = if is_any(definition.failure) {
+                    Some(State::Failure)
+                } else if is_any(definition.success) {
+                    Some(State::Success)
+                } else if definition.per_ue {
+                    // a message of the same UE on the layers of the procedure
+                    None
+                } else {
+                    continue;
+                };
+                procedure.messages += 1;
+                procedure.last_index = index;
+                if let Some(state) = outcome {
+                    procedure.state = state;
+                    procedure.end = Some(log.timestamp);
+                    procedure.outcome = log.data.first().cloned();
+                    self.ongoing.remove(&key);
+                }
+            } else if is_any(definition.start) {
+                self.ongoing.insert(key, self.list.len());
+                self.list.push(Procedure {
+                    kind: definition.kind,
+                    ue_id: key.2,
+                    state: State::InProgress,
+                    start: log.timestamp,
+                    end: None,
+                    first_index: index,
+                    last_index: index,
+                    messages: 1,
+                    outcome: None,
+                });
+            }
+        }
+    }
+
+    /// End with a timeout the procedures that should be over at `now`
+    pub fn expire(&mut self, now: u64) {
+        let list = &mut self.list;
+        self.ongoing.retain(|(kind, _, _), index| {
+            let procedure = &mut list[*index];
+            let timeout = DEFINITIONS
+                .iter()
+                .find(|definition| definition.kind == *kind)
+                .map_or(0, |definition| definition.timeout);
+            if now <= procedure.start + timeout {
+                return true;
+            }
+            procedure.state = State::Timeout;
+            procedure.end = Some(procedure.start + timeout);
+            false
+        });
+    }
+}
+
+/// Names of the messages of a log, as lowercase words: its first line and the
+/// message types of the NAS messages it carries
+fn message_names(log: &OneLog) -> Vec<Vec<String>> {
+    let mut names: Vec<Vec<String>> = log
+        .data
+        .first()
+        .map(|name| words(name))
+        .into_iter()
+        .collect();
+    if let Some(info) = decoders::message_info(log) {
+        names.extend(info.message_types.iter().map(|name| words(name)));
+    }
+    names
+}
+
+/// Lowercase words of a name, camel case ones being split: `RRCSetupRequest`
+/// gives `rrc`, `setup` and `request`
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            });
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Whether `pattern` is a sequence of consecutive `words`
+fn contains(words: &[String], pattern: &[&str]) -> bool {
+    words.windows(pattern.len()).any(|window| {
+        window
+            .iter()
+            .zip(pattern)
+            .all(|(word, expected)| word == expected)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_log::parse_text_logs;
+
+    fn logs(text: &str) -> Vec<OneLog> {
+        parse_text_logs(text.as_bytes(), "enb")
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn rrc_setup() {
+        let mut procedures = Procedures::default();
+        let text = "\
+10:00:00.000 [RRC] UL 0001 01 CCCH: rrcConnectionRequest
+10:00:00.010 [RRC] DL 0001 01 CCCH: rrcConnectionSetup
+10:00:00.030 [RRC] UL 0001 01 DCCH: rrcConnectionSetupComplete";
+        for (index, log) in logs(text).iter().enumerate() {
+            procedures.push(index, log);
+        }
+        let setup = &procedures.list[0];
+        assert_eq!((setup.kind, setup.state), (Kind::RrcSetup, State::Success));
+        assert_eq!((setup.duration(), setup.messages), (Some(30), 3));
+    }
+
+    #[test]
+    fn trailing_timeout() {
+        let mut procedures = Procedures::default();
+        let logs = logs("10:00:00.000 [RRC] UL 0002 01 CCCH: rrcConnectionRequest");
+        procedures.push(0, &logs[0]);
+        let start = logs[0].timestamp;
+        // no later log: pending until expired
+        procedures.expire(start + 2_000);
+        assert_eq!(procedures.list[0].state, State::InProgress);
+        procedures.expire(start + 2_001);
+        assert_eq!(procedures.list[0].state, State::Timeout);
+        assert_eq!(procedures.list[0].end, Some(start + 2_000));
+    }
+}
@@ -0,0 +1,120 @@
+//! Saved connection profiles, exchanged as RON or JSON files
+
+use crate::connection::ConnectionOptions;
+use crate::protocol::Layers;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ConnectionProfile {
+    pub name: String,
+    pub url: String,
+    /// Kept with the app settings but never exported, empty when none
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub password: String,
+    /// Connect with `wss://` instead of `ws://`, there are no other TLS settings
+    #[serde(alias = "tls")]
+    pub secure: bool,
+    /// Layers requested by `log_get` once connected
+    pub layers: Layers,
+    pub options: ConnectionOptions,
+    /// Follow the logs as soon as connected
+    pub follow: bool,
+}
+
+impl Default for ConnectionProfile {
+    fn default() -> Self {
+        Self {
+            name: "New profile".to_owned(),
+            url: "ws://127.0.0.1:9001".to_owned(),
+            password: String::new(),
+            secure: false,
+            layers: Layers::default(),
+            options: ConnectionOptions::default(),
+            follow: false,
+        }
+    }
+}
+
+impl ConnectionProfile {
+    /// URL with the scheme of the `secure` setting
+    pub fn url(&self) -> String {
+        let address = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, address)| address);
+        let scheme = if self.secure { "wss" } else { "ws" };
+        format!("{}://{}", scheme, address)
+    }
+}
+
+/// Copy of the profiles to export, files are shared: the passwords stay here
+fn without_passwords(profiles: &[ConnectionProfile]) -> Vec<ConnectionProfile> {
+    profiles
+        .iter()
+        .map(|profile| ConnectionProfile {
+            password: String::new(),
+            ..profile.clone()
+        })
+        .collect()
+}
+
+pub fn to_ron(profiles: &[ConnectionProfile]) -> Result<String, String> {
+    let profiles = without_passwords(profiles);
+    ron::ser::to_string_pretty(&profiles, ron::ser::PrettyConfig::default())
+        .map_err(|e| e.to_string())
+}
+
+pub fn to_json(profiles: &[ConnectionProfile]) -> Result<String, String> {
+    serde_json::to_string_pretty(&without_passwords(profiles)).map_err(|e| e.to_string())
+}
+
+/// Profiles of a RON or JSON file, whatever the extension
+pub fn parse(content: &str) -> Result<Vec<ConnectionProfile>, String> {
+    let json_error = match serde_json::from_str(content) {
+        Ok(profiles) => return Ok(profiles),
+        Err(e) => e,
+    };
+    ron::from_str(content)
+        .map_err(|ron_error| format!("Neither JSON ({}) nor RON ({})", json_error, ron_error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_scheme() {
+        let mut profile = ConnectionProfile {
+            url: "ws://10.0.0.1:9001".to_owned(),
+            secure: true,
+            ..ConnectionProfile::default()
+        };
+        assert_eq!(profile.url(), "wss://10.0.0.1:9001");
+        profile.secure = false;
+        profile.url = "10.0.0.1:9001".to_owned();
+        assert_eq!(profile.url(), "ws://10.0.0.1:9001");
+    }
+
+    #[test]
+    fn previous_tls_field() {
+        let profiles = parse(r#"[{"name": "enb", "url": "10.0.0.1:9001", "tls": true}]"#).unwrap();
+        assert!(profiles[0].secure);
+        assert_eq!(profiles[0].url(), "wss://10.0.0.1:9001");
+    }
+
+    #[test]
+    fn passwords_not_exported() {
+        let profiles = [ConnectionProfile {
+            password: "secret".to_owned(),
+            ..ConnectionProfile::default()
+        }];
+        for exported in [to_ron(&profiles).unwrap(), to_json(&profiles).unwrap()] {
+            assert!(!exported.contains("secret"));
+            let imported = parse(&exported).unwrap();
+            assert_eq!(imported[0].password, "");
+            assert_eq!(imported[0].url, profiles[0].url);
+        }
+        assert_eq!(profiles[0].password, "secret");
+    }
+}
@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    None,
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl Level {
+    pub const ALL: [Level; 5] = [
+        Level::None,
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::None => "none",
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+/// Logging of one layer. `log_get` only takes the level, the dump options
+/// are set on the server with `config_set`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LayerConfig {
+    pub level: Level,
+    /// Bytes of the messages dumped in hex, the server default when `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u64>,
+    /// Whether the payload of the PDUs is dumped too
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub payload: bool,
+}
+
+impl LayerConfig {
+    fn new(level: Level) -> Self {
+        Self {
+            level,
+            max_size: None,
+            payload: false,
+        }
+    }
+}
+
+/// Log level requested for each layer
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Layers {
+    #[serde(rename = "PHY")]
+    pub phy: LayerConfig,
+    #[serde(rename = "MAC")]
+    pub mac: LayerConfig,
+    #[serde(rename = "RLC")]
+    pub rlc: LayerConfig,
+    #[serde(rename = "PDCP")]
+    pub pdcp: LayerConfig,
+    #[serde(rename = "RRC")]
+    pub rrc: LayerConfig,
+    #[serde(rename = "NAS")]
+    pub nas: LayerConfig,
+    #[serde(rename = "S72")]
+    pub s72: LayerConfig,
+    #[serde(rename = "S1AP")]
+    pub s1ap: LayerConfig,
+    #[serde(rename = "NGAP")]
+    pub ngap: LayerConfig,
+    #[serde(rename = "GTPU")]
+    pub gtpu: LayerConfig,
+    #[serde(rename = "X2AP")]
+    pub x2ap: LayerConfig,
+    #[serde(rename = "XnAP")]
+    pub xnap: LayerConfig,
+    #[serde(rename = "M2AP")]
+    pub m2ap: LayerConfig,
+    #[serde(rename = "LPPa")]
+    pub lppa: LayerConfig,
+    #[serde(rename = "NRPPa")]
+    pub nrppa: LayerConfig,
+    #[serde(rename = "TRX")]
+    pub trx: LayerConfig,
+}
+impl Layers {
+    pub fn new() -> Self {
+        Self {
+            phy: LayerConfig::new(Level::Debug),
+            mac: LayerConfig::new(Level::Warn),
+            rlc: LayerConfig::new(Level::Warn),
+            pdcp: LayerConfig::new(Level::Warn),
+            rrc: LayerConfig::new(Level::Debug),
+            nas: LayerConfig::new(Level::Debug),
+            s72: LayerConfig::new(Level::Warn),
+            s1ap: LayerConfig::new(Level::Warn),
+            ngap: LayerConfig::new(Level::Warn),
+            gtpu: LayerConfig::new(Level::Warn),
+            x2ap: LayerConfig::new(Level::Warn),
+            xnap: LayerConfig::new(Level::Warn),
+            m2ap: LayerConfig::new(Level::Warn),
+            lppa: LayerConfig::new(Level::Warn),
+            nrppa: LayerConfig::new(Level::Warn),
+            trx: LayerConfig::new(Level::Warn),
+        }
+    }
+
+    /// Every layer with its name in the remote API
+    pub fn all(&self) -> [(&'static str, &LayerConfig); 16] {
+        [
+            ("PHY", &self.phy),
+            ("MAC", &self.mac),
+            ("RLC", &self.rlc),
+            ("PDCP", &self.pdcp),
+            ("RRC", &self.rrc),
+            ("NAS", &self.nas),
+            ("S72", &self.s72),
+            ("S1AP", &self.s1ap),
+            ("NGAP", &self.ngap),
+            ("GTPU", &self.gtpu),
+            ("X2AP", &self.x2ap),
+            ("XnAP", &self.xnap),
+            ("M2AP", &self.m2ap),
+            ("LPPa", &self.lppa),
+            ("NRPPa", &self.nrppa),
+            ("TRX", &self.trx),
+        ]
+    }
+
+    /// Level of every layer, the `layers` of a `log_get` request
+    pub fn levels(&self) -> BTreeMap<String, Level> {
+        self.all()
+            .into_iter()
+            .map(|(name, layer)| (name.to_owned(), layer.level))
+            .collect()
+    }
+
+    /// Layers with dump options, the `logs.layers` of a `config_set` request
+    pub fn dump_options(&self) -> serde_json::Map<String, serde_json::Value> {
+        self.all()
+            .into_iter()
+            .filter(|(_, layer)| layer.max_size.is_some() || layer.payload)
+            .map(|(name, layer)| (name.to_owned(), serde_json::json!(layer)))
+            .collect()
+    }
+
+    pub fn all_mut(&mut self) -> [(&'static str, &mut LayerConfig); 16] {
+        [
+            ("PHY", &mut self.phy),
+            ("MAC", &mut self.mac),
+            ("RLC", &mut self.rlc),
+            ("PDCP", &mut self.pdcp),
+            ("RRC", &mut self.rrc),
+            ("NAS", &mut self.nas),
+            ("S72", &mut self.s72),
+            ("S1AP", &mut self.s1ap),
+            ("NGAP", &mut self.ngap),
+            ("GTPU", &mut self.gtpu),
+            ("X2AP", &mut self.x2ap),
+            ("XnAP", &mut self.xnap),
+            ("M2AP", &mut self.m2ap),
+            ("LPPa", &mut self.lppa),
+            ("NRPPa", &mut self.nrppa),
+            ("TRX", &mut self.trx),
+        ]
+    }
+}
+
+impl Default for Layers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parameters of the `log_get` requests, saved between runs
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct LogProfile {
+    /// Seconds the server may wait for `min` logs before answering
+    pub timeout: u64,
+    pub min: u64,
+    pub max: u64,
+    /// Whether the server sends the headers of its log file
+    pub headers: bool,
+    pub layers: Layers,
+}
+
+impl Default for LogProfile {
+    fn default() -> Self {
+        Self {
+            timeout: 1,
+            min: 64,
+            max: 2048,
+            headers: false,
+            layers: Layers::new(),
+        }
+    }
+}
+
+/// `log_get` request of the remote API
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LogGet {
+    pub timeout: u64,
+    pub min: u64,
+    pub max: u64,
+    pub layers: BTreeMap<String, Level>,
+    pub message: String,
+    pub headers: bool,
+    pub message_id: u64,
+}
+
+impl LogGet {
+    pub fn new(id: u64, profile: &LogProfile) -> Self {
+        Self {
+            timeout: profile.timeout,
+            min: profile.min,
+            max: profile.max,
+            layers: profile.layers.levels(),
+            message: "log_get".to_owned(),
+            headers: profile.headers,
+            message_id: id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn log_get_layers_are_levels() {
+        let mut profile = LogProfile::default();
+        profile.layers.phy.max_size = Some(64);
+        profile.layers.rrc.payload = true;
+        let request = serde_json::to_value(LogGet::new(3, &profile)).unwrap();
+        assert_eq!(request["message"], "log_get");
+        assert_eq!(request["message_id"], 3);
+        assert_eq!(request["layers"]["PHY"], "debug");
+        assert_eq!(request["layers"]["RRC"], "debug");
+        assert_eq!(request["layers"]["S1AP"], "warn");
+        assert_eq!(request["layers"].as_object().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn dump_options() {
+        let mut layers = Layers::new();
+        assert!(layers.dump_options().is_empty());
+        layers.phy.max_size = Some(64);
+        layers.rrc.payload = true;
+        let options = serde_json::Value::Object(layers.dump_options());
+        assert_eq!(
+            options,
+            json!({
+                "PHY": { "level": "debug", "max_size": 64 },
+                "RRC": { "level": "debug", "payload": true },
+            })
+        );
+    }
+}
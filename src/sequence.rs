@@ -0,0 +1,224 @@
+//! Message sequence chart of the RRC, NAS, S1AP and NGAP logs between the
+//! UE, the cells and the core network, and its export to SVG, PlantUML and
+//! Mermaid
+
+use crate::decoders::rrc::Technology;
+use crate::{channels, format_timestamp, OneLog};
+use std::fmt::Write as _;
+
+/// Columns of the chart, in their order from left to right
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Entity {
+    Ue,
+    Cell(u64),
+    /// eNB or gNB of messages without a cell
+    BaseStation(Technology),
+    /// MME or AMF
+    Core(Technology),
+}
+
+impl Entity {
+    pub fn name(&self) -> String {
+        match self {
+            Entity::Ue => "UE".to_owned(),
+            Entity::Cell(cell) => format!("Cell {}", cell),
+            Entity::BaseStation(Technology::Lte) => "eNB".to_owned(),
+            Entity::BaseStation(Technology::Nr) => "gNB".to_owned(),
+            Entity::Core(Technology::Lte) => "MME".to_owned(),
+            Entity::Core(Technology::Nr) => "AMF".to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Arrow {
+    pub from: Entity,
+    pub to: Entity,
+    pub label: String,
+    pub timestamp: u64,
+    pub index: usize, // of the log in `Data::events`
+}
+
+#[derive(Debug, Default)]
+pub struct Chart {
+    pub entities: Vec<Entity>, // sorted
+    pub arrows: Vec<Arrow>,
+    cell: Option<u64>,              // of the last message with a cell
+    technology: Option<Technology>, // of the last message telling it
+}
+
+/// Whether the logs of `src` were written by the core network, whose
+/// `FROM` and `TO` are seen from the other end
+fn is_core(src: &str) -> bool {
+    let src = src.to_lowercase();
+    src.contains("mme") || src.contains("amf")
+}
+
+impl Chart {
+    /// Add the message of a log, other logs are ignored
+    pub fn push(&mut self, index: usize, log: &OneLog) {
+        let layer = log.layer.to_uppercase();
+        if !matches!(layer.as_str(), "RRC" | "NAS" | "S1AP" | "NGAP") {
+            return;
+        }
+        if let Some(technology) = channels::technology(log) {
+            self.technology = Some(technology);
+        }
+        if log.cell.is_some() {
+            self.cell = log.cell;
+        }
+        let technology = self.technology.unwrap_or(Technology::Lte);
+        let cell = match log.cell.or(self.cell) {
+            Some(cell) => Entity::Cell(cell),
+            None => Entity::BaseStation(technology),
+        };
+        let core = Entity::Core(technology);
+        let dir = log.dir.as_deref().unwrap_or_default();
+        let (from, to) = match (layer.as_str(), dir) {
+            ("RRC", "UL") => (Entity::Ue, cell),
+            ("RRC", "DL") => (cell, Entity::Ue),
+            ("NAS", "UL") => (Entity::Ue, core),
+            ("NAS", "DL") => (core, Entity::Ue),
+            (_, "TO") if is_core(&log.src) => (core, cell),
+            (_, "FROM") if is_core(&log.src) => (cell, core),
+            (_, "TO") => (cell, core),
+            (_, "FROM") => (core, cell),
+            _ => return,
+        };
+        for entity in [from, to] {
+            if let Err(position) = self.entities.binary_search(&entity) {
+                self.entities.insert(position, entity);
+            }
+        }
+        self.arrows.push(Arrow {
+            from,
+            to,
+            label: log.data.first().cloned().unwrap_or_default(),
+            timestamp: log.timestamp,
+            index,
+        });
+    }
+
+    pub fn column(&self, entity: Entity) -> usize {
+        self.entities.binary_search(&entity).unwrap_or_default()
+    }
+
+    pub fn to_plantuml(&self) -> String {
+        let mut text = String::from("@startuml\n");
+        for (column, entity) in self.entities.iter().enumerate() {
+            let _ = writeln!(text, "participant \"{}\" as P{}", entity.name(), column);
+        }
+        for arrow in &self.arrows {
+            let _ = writeln!(
+                text,
+                "P{} -> P{} : {} {}",
+                self.column(arrow.from),
+                self.column(arrow.to),
+                format_timestamp(arrow.timestamp),
+                arrow.label
+            );
+        }
+        text.push_str("@enduml\n");
+        text
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let mut text = String::from("sequenceDiagram\n");
+        for (column, entity) in self.entities.iter().enumerate() {
+            let _ = writeln!(text, "    participant P{} as {}", column, entity.name());
+        }
+        for arrow in &self.arrows {
+            // `#` starts an entity code and `;` ends a statement
+            let label: String = arrow
+                .label
+                .chars()
+                .map(|c| match c {
+                    '#' => "#35;".to_owned(),
+                    ';' => "#59;".to_owned(),
+                    c => c.to_string(),
+                })
+                .collect();
+            let _ = writeln!(
+                text,
+                "    P{}->>P{}: {} {}",
+                self.column(arrow.from),
+                self.column(arrow.to),
+                format_timestamp(arrow.timestamp),
+                label
+            );
+        }
+        text
+    }
+
+    pub fn to_svg(&self) -> String {
+        const TIME_WIDTH: usize = 100;
+        const COLUMN_WIDTH: usize = 180;
+        const HEADER_HEIGHT: usize = 40;
+        const ROW_HEIGHT: usize = 28;
+        let column_x = |column: usize| TIME_WIDTH + column * COLUMN_WIDTH + COLUMN_WIDTH / 2;
+        let width = TIME_WIDTH + self.entities.len() * COLUMN_WIDTH;
+        let height = HEADER_HEIGHT + (self.arrows.len() + 1) * ROW_HEIGHT;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="sans-serif" font-size="12">"#
+        );
+        svg.push_str(
+            r#"<defs><marker id="head" markerWidth="10" markerHeight="8" refX="10" refY="4" orient="auto"><path d="M0,0 L10,4 L0,8 z"/></marker></defs>"#,
+        );
+        svg.push('\n');
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{width}" height="{height}" fill="white"/>"#
+        );
+        for (column, entity) in self.entities.iter().enumerate() {
+            let x = column_x(column);
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{}" y="8" width="{}" height="24" fill="#eee" stroke="black"/>"##,
+                x - COLUMN_WIDTH / 2 + 20,
+                COLUMN_WIDTH - 40
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{x}" y="24" text-anchor="middle">{}</text>"#,
+                escape_xml(&entity.name())
+            );
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{x}" y1="32" x2="{x}" y2="{height}" stroke="gray" stroke-dasharray="4"/>"#
+            );
+        }
+        for (row, arrow) in self.arrows.iter().enumerate() {
+            let y = HEADER_HEIGHT + (row + 1) * ROW_HEIGHT;
+            let x1 = column_x(self.column(arrow.from));
+            let x2 = column_x(self.column(arrow.to));
+            let _ = writeln!(
+                svg,
+                r#"<text x="4" y="{y}" fill="gray">{}</text>"#,
+                format_timestamp(arrow.timestamp)
+            );
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{x1}" y1="{y}" x2="{x2}" y2="{y}" stroke="black" marker-end="url(#head)"/>"#
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                (x1 + x2) / 2,
+                y - 4,
+                escape_xml(&arrow.label)
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
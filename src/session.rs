@@ -0,0 +1,508 @@
+use crate::diagnostics::{self, Diagnostics};
+use crate::protocol::{Layers, LogGet, LogProfile};
+use crate::sources::LogSource;
+use crate::{hexdump, OneLog};
+use hmac::{Hmac, Mac};
+use serde::de::{SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::value::RawValue;
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Number of errors kept in the session history
+const MAX_ERRORS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionState {
+    /// Waiting for the `ready` or `authenticate` message of the server
+    Connecting,
+    /// Authentication response sent, waiting for the server
+    Authenticating,
+    Ready,
+    Failed(String),
+}
+
+/// Identity sent by the server in its `ready` message
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServerInfo {
+    pub kind: String, // ENB, MME, ...
+    pub name: String,
+    pub version: String,
+}
+
+/// Any message of the remote API, only the fields we use are decoded
+#[derive(Deserialize, Debug)]
+struct RemoteMessage {
+    message: Option<String>,
+    message_id: Option<u64>,
+    error: Option<String>,
+    logs: Option<LogList>,
+    headers: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+    challenge: Option<String>,
+    ready: Option<bool>,
+}
+
+/// `logs` of a message, decoded one by one: a malformed log is skipped
+/// instead of the whole message
+#[derive(Debug, Default)]
+struct LogList {
+    logs: Vec<OneLog>,
+    skipped: usize,
+    first_error: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for LogList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LogListVisitor;
+
+        impl<'de> Visitor<'de> for LogListVisitor {
+            type Value = LogList;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an array of logs")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<LogList, A::Error> {
+                let mut list = LogList::default();
+                while let Some(raw) = seq.next_element::<Box<RawValue>>()? {
+                    match serde_json::from_str(raw.get()) {
+                        Ok(log) => list.logs.push(log),
+                        Err(e) => {
+                            let index = list.logs.len() + list.skipped;
+                            list.skipped += 1;
+                            list.first_error.get_or_insert_with(|| {
+                                let prefix = format!("$.logs[{}]", index);
+                                diagnostics::describe(&e, raw.get()).replacen('$', &prefix, 1)
+                            });
+                        }
+                    }
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(LogListVisitor)
+    }
+}
+
+/// Remote API session: handshake, authentication and request/response matching
+pub struct Session {
+    pub state: SessionState,
+    pub server: Option<ServerInfo>,
+    password: Option<String>,
+    next_message_id: u64,
+    pending: BTreeMap<u64, String>, // message_id -> message name
+    pub errors: Vec<String>,
+    /// Header lines of the server log: version, cells, logging options, ...
+    pub headers: Vec<String>,
+    headers_requested: bool,
+    /// idx and timestamp of the last log received before a reconnection
+    resume_after: Option<(u64, u64)>,
+    previous_server: Option<ServerInfo>, // server of the session before the reconnection
+    dump_options: serde_json::Map<String, serde_json::Value>, // last sent with `config_set`
+    pub diagnostics: Diagnostics,
+}
+
+impl Session {
+    /// Session with a server that starts with the `ready` handshake
+    pub fn new(password: Option<String>) -> Self {
+        Self {
+            state: SessionState::Connecting,
+            server: None,
+            password,
+            next_message_id: 1,
+            pending: BTreeMap::new(),
+            errors: Vec::new(),
+            headers: Vec::new(),
+            headers_requested: false,
+            resume_after: None,
+            previous_server: None,
+            dump_options: serde_json::Map::new(),
+            diagnostics: Diagnostics::default(),
+        }
+    }
+
+    /// Session on a new connection to the server of this one, the errors and
+    /// diagnostics are kept. The logs up to `last_log` (idx and timestamp) the
+    /// server sends again are dropped, unless it is another server or it restarted
+    pub fn resume(self, last_log: Option<(u64, u64)>) -> Self {
+        let mut session = Self::new(self.password);
+        session.errors = self.errors;
+        session.diagnostics = self.diagnostics;
+        session.resume_after = last_log;
+        session.previous_server = self.server;
+        session
+    }
+
+    /// Session over a source without any server (file, memory, ...)
+    pub fn offline() -> Self {
+        let mut session = Self::new(None);
+        session.state = SessionState::Ready;
+        session
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.state == SessionState::Ready
+    }
+
+    /// Requests sent and not answered yet, by message_id
+    pub fn pending(&self) -> &BTreeMap<u64, String> {
+        &self.pending
+    }
+
+    fn push_error(&mut self, error: String) {
+        log::error!("{}", error);
+        if self.errors.len() >= MAX_ERRORS {
+            self.errors.remove(0);
+        }
+        self.errors.push(error);
+    }
+
+    fn next_id(&mut self, message: &str) -> u64 {
+        let id = self.next_message_id;
+        self.next_message_id += 1;
+        self.pending.insert(id, message.to_owned());
+        id
+    }
+
+    /// Send a `log_get` request, returns its message_id
+    pub fn request_logs(
+        &mut self,
+        source: &mut dyn LogSource,
+        profile: &LogProfile,
+    ) -> Result<u64, String> {
+        if !self.is_ready() {
+            return Err("Server is not ready".to_owned());
+        }
+        self.configure_layers(source, &profile.layers);
+        let request = self.log_get(profile);
+        source.request_logs(&request);
+        Ok(request.message_id)
+    }
+
+    /// Send a `log_get` request the server answers as soon as it has new logs
+    /// or after `timeout` seconds, with at most `max` logs
+    pub fn poll_logs(
+        &mut self,
+        source: &mut dyn LogSource,
+        profile: &LogProfile,
+        timeout: u64,
+        max: u64,
+    ) -> Result<u64, String> {
+        if !self.is_ready() {
+            return Err("Server is not ready".to_owned());
+        }
+        self.configure_layers(source, &profile.layers);
+        let request = LogGet {
+            timeout,
+            min: 1,
+            max,
+            ..self.log_get(profile)
+        };
+        source.request_logs(&request);
+        Ok(request.message_id)
+    }
+
+    /// Send the dump options of the layers with `config_set` when they changed
+    fn configure_layers(&mut self, source: &mut dyn LogSource, layers: &Layers) {
+        let dump_options = layers.dump_options();
+        if !source.is_remote() || dump_options.is_empty() || dump_options == self.dump_options {
+            return;
+        }
+        let request = serde_json::json!({
+            "message": "config_set",
+            "logs": { "layers": dump_options },
+        });
+        match self.send_request(source, request) {
+            Ok(_) => self.dump_options = dump_options,
+            Err(e) => self.push_error(format!("config_set: {}", e)),
+        }
+    }
+
+    /// `log_get` request, the first one of the session asks for the headers
+    fn log_get(&mut self, profile: &LogProfile) -> LogGet {
+        let id = self.next_id("log_get");
+        let mut request = LogGet::new(id, profile);
+        request.headers |= !self.headers_requested;
+        self.headers_requested = true;
+        request
+    }
+
+    /// Send any request, `message_id` is added to it and returned
+    pub fn send_request(
+        &mut self,
+        source: &mut dyn LogSource,
+        mut request: serde_json::Value,
+    ) -> Result<u64, String> {
+        let Some(message) = request["message"].as_str().map(str::to_owned) else {
+            return Err("Request without message".to_owned());
+        };
+        if !self.is_ready() && message != "authenticate" {
+            return Err("Server is not ready".to_owned());
+        }
+        let id = self.next_id(&message);
+        request["message_id"] = id.into();
+        source.send(request.to_string());
+        Ok(id)
+    }
+
+    /// Handle a text from the server, returns the logs it carries. A text is
+    /// a whole websocket message, it may hold several messages of the remote API
+    pub fn handle_message(&mut self, source: &mut dyn LogSource, text: &str) -> Vec<OneLog> {
+        let mut logs = Vec::new();
+        let mut stream = serde_json::Deserializer::from_str(text).into_iter::<RemoteMessage>();
+        loop {
+            let offset = stream.byte_offset();
+            match stream.next() {
+                None => break,
+                Some(Ok(msg)) => {
+                    let raw = text[offset..stream.byte_offset()].trim_start();
+                    let name = msg.message.as_deref().unwrap_or("?");
+                    let has_logs = msg.logs.is_some();
+                    self.diagnostics
+                        .message(name, msg.message_id, raw, has_logs);
+                    logs.extend(self.handle_one(source, msg));
+                }
+                Some(Err(e)) => {
+                    // the stream stops at the first error
+                    let rest = text[offset..].trim_start();
+                    if e.is_eof() {
+                        self.push_error(format!(
+                            "Message dropped: truncated after {} bytes",
+                            rest.len()
+                        ));
+                    } else {
+                        self.push_error(format!("Invalid message: {}", e));
+                    }
+                    self.diagnostics.decode_error(&e, text);
+                    break;
+                }
+            }
+        }
+        logs
+    }
+
+    fn handle_one(&mut self, source: &mut dyn LogSource, msg: RemoteMessage) -> Vec<OneLog> {
+        let request = msg.message_id.and_then(|id| self.pending.remove(&id));
+        if let Some(error) = &msg.error {
+            let name = msg.message.as_deref().or(request.as_deref()).unwrap_or("?");
+            self.push_error(format!("{}: {}", name, error));
+        }
+
+        match msg.message.as_deref() {
+            Some("ready") => {
+                let server = ServerInfo {
+                    kind: msg.kind.unwrap_or_default(),
+                    name: msg.name.unwrap_or_default(),
+                    version: msg.version.unwrap_or_default(),
+                };
+                if self
+                    .previous_server
+                    .take()
+                    .is_some_and(|previous| previous != server)
+                {
+                    // another server: its logs are all new
+                    self.resume_after = None;
+                }
+                self.server = Some(server);
+                self.state = SessionState::Ready;
+            }
+            Some("authenticate") if request.is_some() => {
+                // answer to our authentication
+                if let Some(error) = msg.error {
+                    self.state = SessionState::Failed(error);
+                } else if msg.ready == Some(true) {
+                    self.state = SessionState::Ready;
+                }
+            }
+            Some("authenticate") if self.state == SessionState::Authenticating => {
+                // challenged again: the password was refused
+                self.state = SessionState::Failed("Authentication refused".to_owned());
+            }
+            Some("authenticate") => self.authenticate(source, &msg),
+            _ => {}
+        }
+        if let Some(headers) = msg.headers.filter(|headers| !headers.is_empty()) {
+            self.headers = headers;
+        }
+        let list = msg.logs.unwrap_or_default();
+        if let Some(error) = list.first_error {
+            let name = msg.message.as_deref().unwrap_or("?");
+            self.push_error(format!(
+                "{}: {} malformed logs skipped, first: {}",
+                name, list.skipped, error
+            ));
+            self.diagnostics
+                .skipped_logs(msg.message_id, list.skipped, &error);
+        }
+        let mut logs = list.logs;
+        if let Some((last_idx, last_timestamp)) = self.resume_after {
+            if logs
+                .first()
+                .is_some_and(|log| log.idx <= last_idx && log.timestamp > last_timestamp)
+            {
+                // a log sent again is not newer: the server restarted and counts from 0
+                log::info!("Server restarted, its idx start again from {}", logs[0].idx);
+                self.resume_after = None;
+            } else {
+                logs.retain(|log| log.idx > last_idx);
+                if !logs.is_empty() {
+                    // caught up with the logs received before the reconnection
+                    self.resume_after = None;
+                }
+            }
+        }
+        logs
+    }
+
+    fn authenticate(&mut self, source: &mut dyn LogSource, msg: &RemoteMessage) {
+        let Some(password) = &self.password else {
+            self.state = SessionState::Failed("Server requires a password".to_owned());
+            return;
+        };
+        let res = authentication_response(
+            msg.kind.as_deref().unwrap_or_default(),
+            password,
+            msg.name.as_deref().unwrap_or_default(),
+            msg.challenge.as_deref().unwrap_or_default(),
+        );
+        self.state = SessionState::Authenticating;
+        let request = serde_json::json!({ "message": "authenticate", "res": res });
+        if let Err(e) = self.send_request(source, request) {
+            self.state = SessionState::Failed(e);
+        }
+    }
+}
+
+/// HMAC-SHA256 of the challenge keyed with `type:password:name`, hex encoded
+fn authentication_response(kind: &str, password: &str, name: &str, challenge: &str) -> String {
+    let key = format!("{}:{}:{}", kind, password, name);
+    let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("any key size");
+    mac.update(challenge.as_bytes());
+    hexdump::to_hex(&mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::memory::MemorySource;
+
+    const LOG: &str = r#"{"timestamp":1000,"layer":"RRC","level":3,"dir":"UL","src":"ENB","idx":7,"data":["ccch"]}"#;
+
+    const READY: &str = r#"{"message":"ready","type":"ENB","name":"enb"}"#;
+
+    fn session() -> (Session, MemorySource) {
+        let mut session = Session::new(None);
+        let mut source = MemorySource::new("test".to_owned(), Vec::new());
+        session.handle_message(&mut source, READY);
+        (session, source)
+    }
+
+    #[test]
+    fn ready() {
+        let (session, _) = session();
+        assert!(session.is_ready());
+        assert_eq!(session.server.as_ref().unwrap().kind, "ENB");
+        assert_eq!(session.diagnostics.counts["ready"], 1);
+    }
+
+    #[test]
+    fn several_messages_in_a_text() {
+        let (mut session, mut source) = session();
+        let text = format!(
+            r#"{{"message":"log_get","logs":[{}]}} {{"message":"log_get","logs":[{}]}}"#,
+            LOG, LOG
+        );
+        let logs = session.handle_message(&mut source, &text);
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].idx, 7);
+        assert_eq!(session.diagnostics.counts["log_get"], 2);
+        assert!(session.errors.is_empty());
+    }
+
+    #[test]
+    fn truncated_message() {
+        let (mut session, mut source) = session();
+        let text = format!(r#"{{"message":"log_get","logs":[{}"#, LOG);
+        assert!(session.handle_message(&mut source, &text).is_empty());
+        assert_eq!(session.errors.len(), 1);
+        assert_eq!(session.diagnostics.decode_errors, 1);
+        // the next message is not corrupted by the truncated one
+        let text = format!(r#"{{"message":"log_get","logs":[{}]}}"#, LOG);
+        assert_eq!(session.handle_message(&mut source, &text).len(), 1);
+        assert_eq!(session.errors.len(), 1);
+    }
+
+    #[test]
+    fn malformed_log_skipped() {
+        let (mut session, mut source) = session();
+        let text = format!(r#"{{"message":"log_get","logs":[{{"idx":1}},{}]}}"#, LOG);
+        assert_eq!(session.handle_message(&mut source, &text).len(), 1);
+        assert!(session.errors[0].contains("1 malformed logs skipped"));
+    }
+
+    fn log_get(logs: &[(u64, u64)]) -> String {
+        let logs: Vec<String> = logs
+            .iter()
+            .map(|(idx, timestamp)| {
+                format!(
+                    r#"{{"timestamp":{},"layer":"RRC","level":3,"src":"ENB","idx":{},"data":["m"]}}"#,
+                    timestamp, idx
+                )
+            })
+            .collect();
+        format!(r#"{{"message":"log_get","logs":[{}]}}"#, logs.join(","))
+    }
+
+    fn reconnect(session: Session, source: &mut MemorySource, ready: &str) -> Session {
+        let mut session = session.resume(Some((11, 5000)));
+        session.handle_message(source, ready);
+        session
+    }
+
+    #[test]
+    fn resume_drops_logs_sent_again() {
+        let (session, mut source) = session();
+        let mut session = reconnect(session, &mut source, READY);
+        let logs =
+            session.handle_message(&mut source, &log_get(&[(10, 4000), (11, 5000), (12, 6000)]));
+        let idx: Vec<u64> = logs.iter().map(|log| log.idx).collect();
+        assert_eq!(idx, [12]);
+        // caught up: the next logs are all kept
+        assert_eq!(
+            session
+                .handle_message(&mut source, &log_get(&[(13, 7000)]))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn resume_after_server_restart() {
+        let (session, mut source) = session();
+        let mut session = reconnect(session, &mut source, READY);
+        let logs = session.handle_message(&mut source, &log_get(&[(0, 9000), (1, 9001)]));
+        assert_eq!(logs.len(), 2);
+        assert_eq!(
+            session
+                .handle_message(&mut source, &log_get(&[(2, 9002)]))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn resume_with_another_server() {
+        let (session, mut source) = session();
+        let ready = r#"{"message":"ready","type":"ENB","name":"enb","version":"2024-06-14"}"#;
+        let mut session = reconnect(session, &mut source, ready);
+        // older logs of another server are not dropped either
+        let logs = session.handle_message(&mut source, &log_get(&[(3, 1000)]));
+        assert_eq!(logs.len(), 1);
+    }
+}
@@ -0,0 +1,50 @@
+use super::{LogSource, SourceEvent};
+use crate::loader;
+use crate::protocol::LogGet;
+use std::collections::VecDeque;
+
+/// Logs loaded from a saved file, all delivered at once
+pub struct FileSource {
+    file_name: String,
+    events: VecDeque<SourceEvent>,
+}
+
+impl FileSource {
+    pub fn open(content: &str, file_name: String) -> Result<Self, String> {
+        let (logs, errors) = loader::parse_log_file(content, &file_name)?;
+        log::info!("Loaded {} logs from {}", logs.len(), file_name);
+        let mut events = VecDeque::from([SourceEvent::Opened, SourceEvent::Logs(logs)]);
+        if let Some(first) = errors.first() {
+            events.push_back(SourceEvent::Invalid(format!(
+                "{}: {} malformed entries skipped, first at {}",
+                file_name,
+                errors.len(),
+                first
+            )));
+        }
+        Ok(Self { file_name, events })
+    }
+}
+
+impl LogSource for FileSource {
+    fn kind(&self) -> &'static str {
+        "File"
+    }
+
+    fn name(&self) -> &str {
+        &self.file_name
+    }
+
+    fn try_recv(&mut self) -> Option<SourceEvent> {
+        self.events.pop_front()
+    }
+
+    fn request_logs(&mut self, _request: &LogGet) {
+        // everything has already been delivered
+    }
+
+    fn close(&mut self) {
+        self.events.clear();
+        self.events.push_back(SourceEvent::Closed);
+    }
+}
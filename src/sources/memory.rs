@@ -0,0 +1,109 @@
+use super::{LogSource, SourceEvent};
+use crate::protocol::LogGet;
+use crate::OneLog;
+use std::collections::VecDeque;
+
+/// Replay of an in-memory vector of logs, delivered `max` logs per request
+/// like a server would do
+pub struct MemorySource {
+    name: String,
+    logs: VecDeque<OneLog>,
+    events: VecDeque<SourceEvent>,
+}
+
+impl MemorySource {
+    pub fn new(name: String, logs: Vec<OneLog>) -> Self {
+        Self {
+            name,
+            logs: logs.into(),
+            events: VecDeque::from([SourceEvent::Opened]),
+        }
+    }
+}
+
+impl LogSource for MemorySource {
+    fn kind(&self) -> &'static str {
+        "Memory"
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn has_more(&self) -> bool {
+        !self.logs.is_empty()
+    }
+
+    fn try_recv(&mut self) -> Option<SourceEvent> {
+        self.events.pop_front()
+    }
+
+    fn request_logs(&mut self, request: &LogGet) {
+        let count = (request.max as usize).min(self.logs.len());
+        if count > 0 {
+            let logs = self.logs.drain(..count).collect();
+            self.events.push_back(SourceEvent::Logs(logs));
+        }
+    }
+
+    fn close(&mut self) {
+        self.logs.clear();
+        self.events.clear();
+        self.events.push_back(SourceEvent::Closed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::LogProfile;
+    use crate::text_log::parse_text_logs;
+
+    fn logs(count: usize) -> Vec<OneLog> {
+        let lines: Vec<String> = (0..count)
+            .map(|i| format!("10:00:00.{:03} [RRC] UL 0001 01 CCCH: message {}", i, i))
+            .collect();
+        let text = lines.join("\n");
+        parse_text_logs(text.as_bytes(), "enb0")
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn request(max: u64) -> LogGet {
+        LogGet::new(
+            1,
+            &LogProfile {
+                max,
+                ..LogProfile::default()
+            },
+        )
+    }
+
+    #[test]
+    fn max_logs_per_request() {
+        let mut source = MemorySource::new("memory".to_owned(), logs(5));
+        assert!(matches!(source.try_recv(), Some(SourceEvent::Opened)));
+        assert!(source.try_recv().is_none());
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            source.request_logs(&request(2));
+            match source.try_recv() {
+                Some(SourceEvent::Logs(logs)) => received.push(logs.len()),
+                _ => panic!("no logs"),
+            }
+        }
+        assert_eq!(received, [2, 2, 1]);
+        source.request_logs(&request(2));
+        assert!(source.try_recv().is_none());
+    }
+
+    #[test]
+    fn close() {
+        let mut source = MemorySource::new("memory".to_owned(), logs(5));
+        source.request_logs(&request(2));
+        source.close();
+        assert!(matches!(source.try_recv(), Some(SourceEvent::Closed)));
+        source.request_logs(&request(2));
+        assert!(source.try_recv().is_none());
+    }
+}
@@ -0,0 +1,61 @@
+pub mod file;
+pub mod memory;
+pub mod websocket;
+
+pub use file::FileSource;
+pub use memory::MemorySource;
+pub use websocket::WebSocketSource;
+
+use crate::protocol::LogGet;
+use crate::OneLog;
+
+/// Something that happened on a log source
+pub enum SourceEvent {
+    /// The source is ready to deliver logs
+    Opened,
+    /// New logs received
+    Logs(Vec<OneLog>),
+    /// A message of the remote API, logs included
+    Message(String),
+    /// Something unexpected, the source is still usable
+    Invalid(String),
+    /// The source failed and is no longer usable
+    Error(String),
+    Closed,
+}
+
+/// Where logs come from: a live server, a file, a vector, ...
+pub trait LogSource {
+    /// Short name of the kind of source, e.g. "URL" or "File"
+    fn kind(&self) -> &'static str;
+
+    /// Name of this source: url, file name, ...
+    fn name(&self) -> &str;
+
+    /// Whether the source is a server speaking the remote API
+    fn is_remote(&self) -> bool {
+        false
+    }
+
+    /// Whether logs may still come: always from a server, never once a file is read
+    fn has_more(&self) -> bool {
+        self.is_remote()
+    }
+
+    /// Next event of the source, if any
+    fn try_recv(&mut self) -> Option<SourceEvent>;
+
+    /// Ask the source for more logs
+    fn request_logs(&mut self, request: &LogGet);
+
+    /// Send a message to the server, ignored by offline sources
+    fn send(&mut self, _message: String) {}
+
+    /// Stop receiving logs
+    fn close(&mut self);
+
+    /// Open the source again after it was closed or lost
+    fn reconnect(&mut self) -> Result<(), String> {
+        Err(format!("{} cannot be reopened", self.kind()))
+    }
+}
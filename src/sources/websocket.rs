@@ -0,0 +1,101 @@
+use super::{LogSource, SourceEvent};
+use crate::connection::ConnectionOptions;
+use crate::protocol::LogGet;
+use ewebsock::{WsEvent, WsMessage, WsReceiver, WsSender};
+
+/// Live connection to the remote API of a server
+pub struct WebSocketSource {
+    url: String,
+    ctx: egui::Context,
+    options: ConnectionOptions,
+    ws_sender: WsSender,
+    ws_receiver: WsReceiver,
+}
+
+impl WebSocketSource {
+    pub fn connect(
+        url: &str,
+        ctx: egui::Context,
+        options: ConnectionOptions,
+    ) -> Result<Self, String> {
+        let (ws_sender, ws_receiver) = open(url, ctx.clone(), &options)?;
+        Ok(Self {
+            url: url.to_owned(),
+            ctx,
+            options,
+            ws_sender,
+            ws_receiver,
+        })
+    }
+}
+
+fn open(
+    url: &str,
+    ctx: egui::Context,
+    options: &ConnectionOptions,
+) -> Result<(WsSender, WsReceiver), String> {
+    let wakeup = move || ctx.request_repaint(); // wake up UI thread on new message
+    let options = ewebsock::Options {
+        max_incoming_frame_size: options.max_frame_size,
+    };
+    ewebsock::connect_with_wakeup(url, options, wakeup)
+}
+
+impl LogSource for WebSocketSource {
+    fn kind(&self) -> &'static str {
+        "URL"
+    }
+
+    fn name(&self) -> &str {
+        &self.url
+    }
+
+    fn is_remote(&self) -> bool {
+        true
+    }
+
+    fn try_recv(&mut self) -> Option<SourceEvent> {
+        let event = match self.ws_receiver.try_recv()? {
+            WsEvent::Message(msg) => match msg {
+                WsMessage::Text(event_text) => SourceEvent::Message(event_text),
+                WsMessage::Unknown(str_error) => SourceEvent::Invalid(str_error),
+                WsMessage::Binary(bin) => SourceEvent::Invalid(format!("{:?}", bin)),
+                _ => SourceEvent::Invalid("Received Ping-Pong".to_string()),
+            },
+            WsEvent::Opened => SourceEvent::Opened,
+            WsEvent::Closed => SourceEvent::Closed,
+            // tungstenite: "Space limit exceeded: Message too long: 600 > 500"
+            WsEvent::Error(str_err) if str_err.contains("Message too long") => {
+                SourceEvent::Error(format!(
+                    "Frame dropped, larger than the maximum frame size of {} bytes ({})",
+                    self.options.max_frame_size, str_err
+                ))
+            }
+            WsEvent::Error(str_err) => SourceEvent::Error(str_err),
+        };
+        Some(event)
+    }
+
+    fn request_logs(&mut self, request: &LogGet) {
+        if let Ok(msg_stringed) = serde_json::to_string(request) {
+            log::info!("{}", msg_stringed);
+            self.send(msg_stringed);
+        }
+    }
+
+    fn send(&mut self, message: String) {
+        self.ws_sender.send(WsMessage::Text(message));
+    }
+
+    fn close(&mut self) {
+        if let Err(e) = self.ws_sender.close() {
+            log::warn!("Failed to close {:?}: {}", self.url, e);
+        }
+    }
+
+    fn reconnect(&mut self) -> Result<(), String> {
+        // the previous socket is closed when its sender is dropped
+        (self.ws_sender, self.ws_receiver) = open(&self.url, self.ctx.clone(), &self.options)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,436 @@
+//! Parser of the Amarisoft plain-text log format (`enb0.log`, `gnb0.log`, `mme.log`, ...)
+//!
+//! Each log starts with a header line:
+//!
+//! ```text
+//! 10:27:22.358 [RRC] UL 0001 01 CCCH: rrcConnectionRequest
+//! 10:27:22.360 [S1AP] TO 127.0.1.100:36412 Initial UE message
+//! ```
+//!
+//! followed by indented continuation lines (hex dumps, ASN.1 dumps, ...).
+
+use crate::OneLog;
+use std::fmt;
+use std::io::BufRead;
+
+/// Layers where the message is prefixed by the channel, e.g. `CCCH: ...`
+const CHANNEL_LAYERS: [&str; 5] = ["PHY", "MAC", "RLC", "PDCP", "RRC"];
+
+const MS_PER_DAY: u64 = 24 * 3600 * 1000;
+
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub line: usize, // 1-based line number
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Streaming parser: feed it lines, get logs as soon as they are complete
+pub struct TextLogParser {
+    src: String,
+    line_number: usize,
+    next_idx: u64,
+    day_offset: u64, // milliseconds of the day the log started, since 1970
+    last_time: u64,
+    current: Option<OneLog>,
+    skipping: bool, // inside a malformed log
+}
+
+impl TextLogParser {
+    pub fn new(src: &str) -> Self {
+        Self {
+            src: src.to_owned(),
+            line_number: 0,
+            next_idx: 0,
+            day_offset: 0,
+            last_time: 0,
+            current: None,
+            skipping: false,
+        }
+    }
+
+    /// Parse the next line, returns the previous log once it is complete
+    pub fn push_line(&mut self, line: &str) -> Result<Option<OneLog>, ParseError> {
+        self.line_number += 1;
+        let line = line.trim_end();
+        if line.trim_start().is_empty() {
+            return Ok(None);
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if !comment.contains("Started") {
+                return Ok(None);
+            }
+            if let Some(days) = comment.split_whitespace().find_map(parse_date) {
+                self.day_offset = days * MS_PER_DAY;
+            }
+            return Ok(None);
+        }
+        if line.starts_with(char::is_whitespace) {
+            if self.skipping {
+                return Ok(None);
+            }
+            return match &mut self.current {
+                Some(current) => {
+                    current.data.push(line.trim_start().to_owned());
+                    Ok(None)
+                }
+                None => Err(self.error("continuation line without a log")),
+            };
+        }
+        match self.parse_header(line) {
+            Ok(log) => {
+                self.skipping = false;
+                Ok(self.current.replace(log))
+            }
+            Err(e) => {
+                // the continuation lines of a malformed log are dropped with it
+                self.skipping = true;
+                Err(self.error(e))
+            }
+        }
+    }
+
+    /// Last log of the file
+    pub fn finish(&mut self) -> Option<OneLog> {
+        self.current.take()
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            line: self.line_number,
+            message: message.to_owned(),
+        }
+    }
+
+    fn parse_header(&mut self, line: &str) -> Result<OneLog, &'static str> {
+        let (time, rest) = line.split_once(' ').ok_or("missing layer")?;
+        let mut time = parse_time(time).ok_or("invalid timestamp")?;
+        if time + MS_PER_DAY / 2 < self.last_time {
+            // past midnight
+            self.day_offset += MS_PER_DAY;
+        }
+        self.last_time = time;
+        time += self.day_offset;
+
+        let rest = rest.trim_start().strip_prefix('[').ok_or("missing layer")?;
+        let (layer, rest) = rest.split_once(']').ok_or("unterminated layer")?;
+        let layer = layer.trim();
+        if layer.is_empty() {
+            return Err("empty layer");
+        }
+        let mut fields = Fields(rest.trim_start());
+
+        let dir = match fields.peek() {
+            Some("UL" | "DL" | "FROM" | "TO") => fields.next().map(str::to_owned),
+            Some("-") => {
+                fields.next();
+                None
+            }
+            _ => None,
+        };
+        let mut cell = None;
+        let mut ue_id = None;
+        let mut rnti = None;
+        let hex = |field: &str| u64::from_str_radix(field, 16).ok();
+        match dir.as_deref() {
+            Some("UL" | "DL") => {
+                // UE id, then cell and RNTI when known
+                ue_id = fields.next_if(|field| is_hex(field, 4)).and_then(hex);
+                if let Some(cell_id) = fields.next_if(|field| is_hex(field, 2)) {
+                    cell = hex(cell_id);
+                    rnti = fields.next_if(|field| is_hex(field, 4)).and_then(hex);
+                }
+            }
+            Some(_) => {
+                // address of the peer
+                fields.next();
+            }
+            None => {}
+        }
+
+        let mut message = fields.0.to_owned();
+        let mut channel = None;
+        if CHANNEL_LAYERS.contains(&layer) {
+            // PHY and MAC logs start with the frame.subframe (or slot)
+            let slot = fields.next_if(is_slot);
+            if let Some((prefix, rest)) = fields.0.split_once(": ") {
+                if is_channel(prefix) {
+                    channel = Some(prefix.to_owned());
+                    message = match slot {
+                        Some(slot) => format!("{} {}", slot, rest),
+                        None => rest.to_owned(),
+                    };
+                }
+            }
+        }
+
+        let idx = self.next_idx;
+        self.next_idx += 1;
+        Ok(OneLog {
+            data: vec![message],
+            timestamp: time,
+            layer: layer.to_owned(),
+            level: 0, // not written in text logs
+            dir,
+            cell,
+            ue_id,
+            rnti,
+            channel,
+            src: self.src.clone(),
+            idx,
+            source: 0, // set when added to `Data`
+            info: None,
+        })
+    }
+}
+
+/// Space separated fields of a header line
+struct Fields<'a>(&'a str);
+
+impl<'a> Fields<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.0.split(' ').next().filter(|field| !field.is_empty())
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let field = self.peek()?;
+        self.0 = self.0[field.len()..].trim_start();
+        Some(field)
+    }
+
+    fn next_if(&mut self, condition: impl Fn(&str) -> bool) -> Option<&'a str> {
+        if condition(self.peek()?) {
+            return self.next();
+        }
+        None
+    }
+}
+
+/// Parse a whole text log, malformed entries are reported and skipped
+pub fn parse_text_logs(
+    reader: impl BufRead,
+    src: &str,
+) -> impl Iterator<Item = Result<OneLog, ParseError>> {
+    let mut parser = TextLogParser::new(src);
+    let mut lines = reader.lines();
+    std::iter::from_fn(move || loop {
+        match lines.next() {
+            Some(Ok(line)) => match parser.push_line(&line) {
+                Ok(Some(log)) => return Some(Ok(log)),
+                Ok(None) => {}
+                Err(e) => return Some(Err(e)),
+            },
+            Some(Err(e)) => {
+                return Some(Err(ParseError {
+                    line: parser.line_number + 1,
+                    message: e.to_string(),
+                }))
+            }
+            None => return parser.finish().map(Ok),
+        }
+    })
+}
+
+fn is_hex(field: &str, len: usize) -> bool {
+    field.len() == len && field.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_slot(field: &str) -> bool {
+    match field.split_once('.') {
+        Some((frame, slot)) => {
+            !frame.is_empty()
+                && !slot.is_empty()
+                && frame
+                    .chars()
+                    .chain(slot.chars())
+                    .all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+fn is_channel(prefix: &str) -> bool {
+    prefix.starts_with(|c: char| c.is_ascii_uppercase())
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Parse `HH:MM:SS.mmm` into milliseconds
+fn parse_time(time: &str) -> Option<u64> {
+    let (hms, millis) = time.split_once('.')?;
+    let mut parts = hms.split(':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: u64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || minutes >= 60 || seconds >= 60 || millis.len() != 3 {
+        return None;
+    }
+    let millis: u64 = millis.parse().ok()?;
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// Parse `YYYY-MM-DD` into days since January 1st 1970
+fn parse_date(date: &str) -> Option<u64> {
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.trim_end_matches(',').parse().ok()?;
+    if parts.next().is_some() || !(1970..10000).contains(&year) {
+        return None;
+    }
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some((era * 146097 + day_of_era - 719468) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Vec<Result<OneLog, ParseError>> {
+        parse_text_logs(text.as_bytes(), "enb0").collect()
+    }
+
+    fn parse_ok(text: &str) -> Vec<OneLog> {
+        parse(text).into_iter().map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn uplink_with_cell_and_rnti() {
+        let logs = parse_ok("10:27:22.358 [MAC] UL 0001 01 4601 120.3 PUSCH: harq=0\n");
+        let log = &logs[0];
+        assert_eq!(log.timestamp, 37_642_358);
+        assert_eq!(log.layer, "MAC");
+        assert_eq!(log.dir.as_deref(), Some("UL"));
+        assert_eq!(log.ue_id, Some(1));
+        assert_eq!(log.cell, Some(1));
+        assert_eq!(log.rnti, Some(0x4601));
+        assert_eq!(log.channel.as_deref(), Some("PUSCH"));
+        assert_eq!(log.data, ["120.3 harq=0"]);
+        assert_eq!(log.src, "enb0");
+    }
+
+    #[test]
+    fn downlink_without_rnti() {
+        let logs = parse_ok("10:27:22.360 [RRC] DL 000a 02 CCCH: rrcConnectionSetup\n");
+        let log = &logs[0];
+        assert_eq!(log.dir.as_deref(), Some("DL"));
+        assert_eq!(log.ue_id, Some(10));
+        assert_eq!(log.cell, Some(2));
+        assert_eq!(log.rnti, None);
+        assert_eq!(log.channel.as_deref(), Some("CCCH"));
+        assert_eq!(log.data, ["rrcConnectionSetup"]);
+    }
+
+    #[test]
+    fn without_cell() {
+        let logs = parse_ok("10:27:23.005 [NAS] UL 0002 EMM: Attach request\n");
+        let log = &logs[0];
+        assert_eq!(log.ue_id, Some(2));
+        assert_eq!(log.cell, None);
+        assert_eq!(log.channel, None);
+        assert_eq!(log.data, ["EMM: Attach request"]);
+    }
+
+    #[test]
+    fn peer_address() {
+        let logs = parse_ok(
+            "10:27:22.372 [S1AP] TO 127.0.1.100:36412 Initial UE message\n\
+             10:27:22.380 [S1AP] FROM 127.0.1.100:36412 Downlink NAS transport\n\
+             10:27:22.390 [GTPU] - Tunnel created\n",
+        );
+        assert_eq!(logs[0].dir.as_deref(), Some("TO"));
+        assert_eq!(logs[0].data, ["Initial UE message"]);
+        assert_eq!(logs[1].dir.as_deref(), Some("FROM"));
+        assert_eq!(logs[1].data, ["Downlink NAS transport"]);
+        assert_eq!(logs[2].dir, None);
+        assert_eq!(logs[2].data, ["Tunnel created"]);
+        assert_eq!(logs[2].ue_id, None);
+    }
+
+    #[test]
+    fn continuation_lines() {
+        let logs = parse_ok(
+            "10:27:22.358 [RRC] UL 0001 01 CCCH: rrcConnectionRequest\n\
+             \x20 0000:  41 23 45 67 89 a6\n\
+             \n\
+             \x20 {\n\
+             10:27:22.360 [RRC] DL 0001 01 CCCH: rrcConnectionSetup\n",
+        );
+        assert_eq!(logs.len(), 2);
+        assert_eq!(
+            logs[0].data,
+            ["rrcConnectionRequest", "0000:  41 23 45 67 89 a6", "{"]
+        );
+        assert_eq!((logs[0].idx, logs[1].idx), (0, 1));
+    }
+
+    #[test]
+    fn malformed_logs_skipped() {
+        let results = parse(
+            "  orphan continuation\n\
+             10:27:22.358 [RRC] UL 0001 01 CCCH: first\n\
+             not a header\n\
+             \x20 dropped with it\n\
+             99:99:99.999 [RRC] bad time\n\
+             10:27:22.359 RRC missing brackets\n\
+             10:27:22.360 [RRC] DL 0001 01 CCCH: second\n\
+             \x20 kept\n",
+        );
+        let lines: Vec<usize> = results
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+            .map(|e| e.line)
+            .collect();
+        assert_eq!(lines, [1, 3, 5, 6]);
+        let logs: Vec<&OneLog> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].data, ["first"]);
+        assert_eq!(logs[1].data, ["second", "kept"]);
+    }
+
+    #[test]
+    fn started_date() {
+        let logs = parse_ok(
+            "# Started on 2024-03-01 10:27:22\n\
+             # another comment 1999-01-01\n\
+             10:27:22.358 [RRC] UL 0001 01 CCCH: rrcConnectionRequest\n",
+        );
+        // 2024-03-01 is day 19783 since 1970-01-01
+        assert_eq!(logs[0].timestamp, 19783 * MS_PER_DAY + 37_642_358);
+    }
+
+    #[test]
+    fn midnight_rollover() {
+        let logs = parse_ok(
+            "23:59:59.900 [RRC] UL 0001 01 CCCH: before\n\
+             23:59:59.800 [RRC] UL 0001 01 CCCH: slightly out of order\n\
+             00:00:00.100 [RRC] UL 0001 01 CCCH: after\n",
+        );
+        assert_eq!(logs[0].timestamp, MS_PER_DAY - 100);
+        assert_eq!(logs[1].timestamp, MS_PER_DAY - 200);
+        assert_eq!(logs[2].timestamp, MS_PER_DAY + 100);
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2000-03-01,"), Some(11017));
+        assert_eq!(parse_date("2024-02-30x"), None);
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("10:27:22"), None);
+    }
+}
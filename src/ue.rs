@@ -0,0 +1,173 @@
+//! UEs seen in the logs, with their identities and RRC state
+
+use crate::decoders::{self, MobileIdentity};
+use crate::OneLog;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UeState {
+    Connecting,
+    Connected,
+    Released,
+}
+
+impl UeState {
+    pub fn name(self) -> &'static str {
+        match self {
+            UeState::Connecting => "connecting",
+            UeState::Connected => "connected",
+            UeState::Released => "released",
+        }
+    }
+
+    /// State after an RRC message, `None` when it does not change it
+    fn after(message: &str) -> Option<UeState> {
+        let message: String = message
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase();
+        let is_any = |names: &[&str]| names.iter().any(|name| message.contains(name));
+        if is_any(&["setupcomplete", "reestablishmentcomplete", "resumecomplete"]) {
+            Some(UeState::Connected)
+        } else if is_any(&[
+            "connectionrequest",
+            "setuprequest",
+            "reestablishmentrequest",
+            "resumerequest",
+        ]) {
+            Some(UeState::Connecting)
+        } else if is_any(&["connectionrelease", "rrcrelease"]) {
+            Some(UeState::Released)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ue {
+    pub source: usize, // connection of the logs, the ids of two servers may be the same
+    pub ue_id: u64,
+    pub rnti: Option<u64>,
+    pub imsi: Option<String>,
+    pub tmsi: Option<String>,
+    pub cell: Option<u64>,
+    pub first_seen: u64,
+    pub last_seen: u64,
+    pub state: Option<UeState>,
+    pub messages: usize,
+}
+
+impl Ue {
+    fn new(source: usize, ue_id: u64, timestamp: u64) -> Self {
+        Self {
+            source,
+            ue_id,
+            rnti: None,
+            imsi: None,
+            tmsi: None,
+            cell: None,
+            first_seen: timestamp,
+            last_seen: timestamp,
+            state: None,
+            messages: 0,
+        }
+    }
+
+    /// Connection and id, what identifies a UE among all the logs
+    pub fn key(&self) -> (usize, u64) {
+        (self.source, self.ue_id)
+    }
+}
+
+/// UEs sorted by connection and id
+#[derive(Debug, Default)]
+pub struct UeList {
+    pub ues: Vec<Ue>,
+}
+
+impl UeList {
+    /// Update the UE of a log, logs without a UE id are ignored
+    pub fn push(&mut self, log: &OneLog) {
+        let Some(ue_id) = log.ue_id else {
+            return;
+        };
+        let key = (log.source, ue_id);
+        let position = match self.ues.binary_search_by_key(&key, Ue::key) {
+            Ok(position) => position,
+            Err(position) => {
+                self.ues
+                    .insert(position, Ue::new(log.source, ue_id, log.timestamp));
+                position
+            }
+        };
+        let ue = &mut self.ues[position];
+        ue.messages += 1;
+        ue.first_seen = ue.first_seen.min(log.timestamp);
+        ue.last_seen = ue.last_seen.max(log.timestamp);
+        ue.rnti = log.rnti.or(ue.rnti);
+        ue.cell = log.cell.or(ue.cell);
+        let layer = log.layer.to_uppercase();
+        if layer == "RRC" {
+            if let Some(state) = log.data.first().and_then(|message| UeState::after(message)) {
+                ue.state = Some(state);
+            }
+        }
+        // the NAS messages of the eNB and gNB logs are inside the RRC ones
+        if let Some(info) = decoders::message_info(log) {
+            for identity in &info.identities {
+                match identity {
+                    MobileIdentity::Imsi(imsi) => ue.imsi = Some(imsi.clone()),
+                    MobileIdentity::Tmsi(tmsi) => ue.tmsi = Some(tmsi.clone()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_log::parse_text_logs;
+
+    /// NAS log of a UE with its message in a hex dump
+    fn nas_log(ue_id: u64, message: &str, hex: &str) -> OneLog {
+        let bytes: Vec<&str> = (0..hex.len()).step_by(2).map(|i| &hex[i..i + 2]).collect();
+        let mut lines = vec![format!("10:00:00.000 [NAS] UL {:04x} {}", ue_id, message)];
+        for (row, chunk) in bytes.chunks(16).enumerate() {
+            lines.push(format!("    {:04x}:  {}", row * 16, chunk.join(" ")));
+        }
+        let text = lines.join("\n");
+        let mut logs: Vec<OneLog> = parse_text_logs(text.as_bytes(), "ue")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        logs.remove(0)
+    }
+
+    #[test]
+    fn attach_request_with_imsi() {
+        let mut ues = UeList::default();
+        let hex = "07417108091010103254769802e0e000040201d011";
+        ues.push(&nas_log(1, "Attach request", hex));
+        assert_eq!(ues.ues[0].imsi.as_deref(), Some("001010123456789"));
+        assert_eq!(ues.ues[0].tmsi, None);
+    }
+
+    #[test]
+    fn attach_request_with_guti() {
+        let mut ues = UeList::default();
+        let hex = "0741710bf600f110800101c0000a3b02e0e000040201d011";
+        ues.push(&nas_log(2, "Attach request", hex));
+        assert_eq!(ues.ues[0].tmsi.as_deref(), Some("c0000a3b"));
+        assert_eq!(ues.ues[0].imsi, None);
+    }
+
+    #[test]
+    fn registration_request_with_suci() {
+        let mut ues = UeList::default();
+        let hex = "7e004171000d0100f110f0ff00001032547698";
+        ues.push(&nas_log(3, "Registration request", hex));
+        assert_eq!(ues.ues[0].ue_id, 3);
+        assert_eq!(ues.ues[0].imsi.as_deref(), Some("001010123456789"));
+    }
+}